@@ -0,0 +1,10 @@
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    #[error("failed to read config: {config_path:?}")]
+    ConfigFileReadFailed { config_path: PathBuf },
+
+    #[error("failed to parse config: {config_path:?}")]
+    ConfigFileParseFailed { config_path: PathBuf },
+}
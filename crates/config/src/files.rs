@@ -0,0 +1,54 @@
+/// # Files options.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[derive(Debug, Default, Clone)]
+pub struct FilesOptions {
+    /// # The file match patterns to include.
+    ///
+    /// Supports glob pattern in the `.gitignore` style, relative to the directory of the config file.
+    /// Used when no file is given to the command line.
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "FilesOptions::default_include")
+    )]
+    pub include: Option<Vec<String>>,
+
+    /// # The file match patterns to exclude.
+    ///
+    /// Supports glob pattern in the `.gitignore` style, relative to the directory of the config file.
+    /// The files ignored by `.gitignore` are always excluded.
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "FilesOptions::default_exclude")
+    )]
+    pub exclude: Option<Vec<String>>,
+}
+
+impl FilesOptions {
+    pub const fn default() -> Self {
+        Self {
+            include: None,
+            exclude: None,
+        }
+    }
+
+    pub fn default_include() -> Vec<String> {
+        vec!["**/*.toml".to_string()]
+    }
+
+    pub fn default_exclude() -> Vec<String> {
+        vec!["target/".to_string(), "node_modules/".to_string()]
+    }
+
+    #[inline]
+    pub fn include(&self) -> Vec<String> {
+        self.include.clone().unwrap_or_else(Self::default_include)
+    }
+
+    #[inline]
+    pub fn exclude(&self) -> Vec<String> {
+        self.exclude.clone().unwrap_or_else(Self::default_exclude)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{DateTimeDelimiter, IdentStyle, IdentWidth, LineEnding, LineWidth};
+use crate::{
+    BlankLinesBetweenTables, DateTimeDelimiter, FractionalSeconds, HexDigitCase, IdentStyle,
+    IdentWidth, KeyOrder, LineEnding, LineWidth, MultiLineString, NumberUnderscores, StringEscape,
+    StringQuote, TrailingComma, TrailingCommentSpace, UtcOffset,
+};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
@@ -43,6 +47,180 @@ pub struct FormatOptions {
         schemars(default = "DateTimeDelimiter::default")
     )]
     pub date_time_delimiter: Option<DateTimeDelimiter>,
+
+    /// # The digits of the fractional seconds.
+    ///
+    /// The fractional seconds of the date-times and times, which are never truncated.
+    ///
+    /// - `preserve`: Preserve the fractional seconds of the source.
+    /// - `trim`: Remove the trailing zeros, e.g. `07:32:00.500` becomes `07:32:00.5`.
+    /// - `milliseconds`: Example: `07:32:00.500`
+    /// - `microseconds`: Example: `07:32:00.500000`
+    /// - `nanoseconds`: Example: `07:32:00.500000000`
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "FractionalSeconds::default")
+    )]
+    pub fractional_seconds: Option<FractionalSeconds>,
+
+    /// # The UTC offset of the offset date-times.
+    ///
+    /// - `preserve`: Preserve the offset of the source.
+    /// - `Z`: Example: `1979-05-27T07:32:00Z`
+    /// - `+00:00`: Example: `1979-05-27T07:32:00+00:00`
+    #[cfg_attr(feature = "jsonschema", schemars(default = "UtcOffset::default"))]
+    pub utc_offset: Option<UtcOffset>,
+
+    /// # The order of the keys in a table.
+    ///
+    /// Leading and tailing comments move with the key-value they annotate.
+    ///
+    /// - `preserve`: Preserve the source order.
+    /// - `alphabetical`: Sort the keys alphabetically.
+    /// - `schema`: Sort the keys in the order of the schema properties (e.g. `package`, `dependencies` of `Cargo.toml`),
+    ///   and the other keys, such as the crate names of `[dependencies]`, alphabetically after them.
+    ///   The top-level tables are sorted in the same order.
+    ///   Without a schema, the source order is preserved.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "KeyOrder::default"))]
+    pub key_order: Option<KeyOrder>,
+
+    /// # Sort the arrays of strings.
+    ///
+    /// Sort the values of the arrays whose values are all single-line strings,
+    /// such as `features = ["std", "derive"]`.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "bool::default"))]
+    pub sort_string_arrays: Option<bool>,
+
+    /// # Align the equals signs of the key-values.
+    ///
+    /// The equals signs of the consecutive single-line key-values are aligned into a column.
+    /// The blocks of the aligned key-values are delimited by blank lines, comments and multi-line key-values,
+    /// and a block is not aligned if it makes a line exceed `line-width`.
+    /// A blank line between the key-values is kept when the key-values are aligned.
+    ///
+    /// ```toml
+    /// name    = "tombi"
+    /// version = "0.1.0"
+    /// ```
+    #[cfg_attr(feature = "jsonschema", schemars(default = "bool::default"))]
+    pub align_equals: Option<bool>,
+
+    /// # Align the tailing comments of the key-values.
+    ///
+    /// The tailing comments of the consecutive single-line key-values are aligned into a column,
+    /// in the same blocks as `align-equals`.
+    ///
+    /// ```toml
+    /// name = "tombi"     # the name
+    /// version = "0.1.0"  # the version
+    /// ```
+    #[cfg_attr(feature = "jsonschema", schemars(default = "bool::default"))]
+    pub align_comments: Option<bool>,
+
+    /// # Put a space inside the brackets of a single-line array.
+    ///
+    /// - `true`: Example: `key = [ 1, 2, 3 ]`
+    /// - `false`: Example: `key = [1, 2, 3]`
+    #[cfg_attr(feature = "jsonschema", schemars(default = "bool::default"))]
+    pub array_bracket_space: Option<bool>,
+
+    /// # Put a space inside the braces of a single-line inline table.
+    ///
+    /// - `true`: Example: `key = { a = 1, b = 2 }`
+    /// - `false`: Example: `key = {a = 1, b = 2}`
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "FormatOptions::default_inline_table_brace_space")
+    )]
+    pub inline_table_brace_space: Option<bool>,
+
+    /// # The number of spaces before a tailing comment.
+    ///
+    /// Example: `key = "value"  # tailing comment` for the default `2`.
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "TrailingCommentSpace::default")
+    )]
+    pub trailing_comment_space: Option<TrailingCommentSpace>,
+
+    /// # The comma after the last value of a multi-line array.
+    ///
+    /// - `always`: Always add the comma.
+    /// - `never`: Never add the comma, unless it has comments.
+    ///   As the comma keeps an array multi-line, an array fitting in `line-width` becomes single-line.
+    /// - `preserve`: Preserve the comma of the source.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "TrailingComma::default"))]
+    pub trailing_comma: Option<TrailingComma>,
+
+    /// # The number of blank lines between tables.
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "BlankLinesBetweenTables::default")
+    )]
+    pub blank_lines_between_tables: Option<BlankLinesBetweenTables>,
+
+    /// # The quotes of the strings and the quoted keys.
+    ///
+    /// - `preserve`: Preserve the quotes of the source.
+    /// - `basic`: Prefer the basic strings, e.g. `"value"`, if the value needs no escaping.
+    /// - `literal`: Prefer the literal strings, e.g. `'value'`, if the value can be written without escaping.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "StringQuote::default"))]
+    pub string_quote: Option<StringQuote>,
+
+    /// # The conversion between the single-line and multi-line strings.
+    ///
+    /// - `preserve`: Preserve the strings of the source.
+    /// - `collapse`: Convert the multi-line strings without newlines into single-line strings.
+    /// - `expand`: Convert the basic strings with newlines, e.g. `"a\nb"`, into multi-line basic strings.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "MultiLineString::default"))]
+    pub multi_line_string: Option<MultiLineString>,
+
+    /// # The escape sequences of the basic strings and the quoted keys.
+    ///
+    /// - `preserve`: Preserve the escape sequences of the source.
+    /// - `unicode`: Escape only the characters which must be escaped, e.g. `"\u00e9"` becomes `"é"`.
+    /// - `ascii`: Escape also the non-ASCII characters, e.g. `"é"` becomes `"\u00e9"`.
+    ///   The literal strings with non-ASCII characters become basic strings.
+    ///
+    /// The multi-line basic strings with line ending backslashes are kept as they are.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "StringEscape::default"))]
+    pub string_escape: Option<StringEscape>,
+
+    /// # Unquote the keys which can be bare keys.
+    ///
+    /// Example: `"key" = 1` becomes `key = 1`.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "bool::default"))]
+    pub unquote_keys: Option<bool>,
+
+    /// # The underscores between the digits of the numbers.
+    ///
+    /// - `preserve`: Preserve the underscores of the source.
+    /// - `remove`: Remove the underscores, e.g. `1_000_000` becomes `1000000`.
+    /// - `group`: Group the digits of the numbers with 5 or more digits, e.g. `1000000` becomes `1_000_000`.
+    ///   The decimal and octal digits are grouped by 3, and the hexadecimal and binary digits by 4.
+    ///   The fractional parts and exponents of the floats are kept as they are.
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(default = "NumberUnderscores::default")
+    )]
+    pub number_underscores: Option<NumberUnderscores>,
+
+    /// # The case of the hexadecimal digits.
+    ///
+    /// The prefixes `0x`, `0o` and `0b` are always lowercase in TOML.
+    ///
+    /// - `preserve`: Preserve the case of the source.
+    /// - `upper`: Example: `0xDEADBEEF`
+    /// - `lower`: Example: `0xdeadbeef`
+    #[cfg_attr(feature = "jsonschema", schemars(default = "HexDigitCase::default"))]
+    pub hex_digit_case: Option<HexDigitCase>,
+
+    /// # Normalize the exponents of the floats.
+    ///
+    /// Lowercase the `e`, and remove the `+` sign and the leading zeros of the exponents,
+    /// e.g. `1E+06` becomes `1e6`.
+    #[cfg_attr(feature = "jsonschema", schemars(default = "bool::default"))]
+    pub normalize_float_exponent: Option<bool>,
 }
 
 impl FormatOptions {
@@ -53,19 +231,97 @@ impl FormatOptions {
             line_width: None,
             line_ending: None,
             date_time_delimiter: None,
+            fractional_seconds: None,
+            utc_offset: None,
+            key_order: None,
+            sort_string_arrays: None,
+            align_equals: None,
+            align_comments: None,
+            array_bracket_space: None,
+            inline_table_brace_space: None,
+            trailing_comment_space: None,
+            trailing_comma: None,
+            blank_lines_between_tables: None,
+            string_quote: None,
+            multi_line_string: None,
+            string_escape: None,
+            unquote_keys: None,
+            number_underscores: None,
+            hex_digit_case: None,
+            normalize_float_exponent: None,
         }
     }
 
     pub fn merge(&mut self, other: &FormatOptions) -> &mut Self {
+        if let Some(indent_style) = other.indent_style {
+            self.indent_style = Some(indent_style);
+        }
+        if let Some(indent_width) = other.indent_width {
+            self.indent_width = Some(indent_width);
+        }
         if let Some(line_ending) = other.line_ending {
             self.line_ending = Some(line_ending);
         }
         if let Some(date_time_delimiter) = other.date_time_delimiter {
             self.date_time_delimiter = Some(date_time_delimiter);
         }
+        if let Some(fractional_seconds) = other.fractional_seconds {
+            self.fractional_seconds = Some(fractional_seconds);
+        }
+        if let Some(utc_offset) = other.utc_offset {
+            self.utc_offset = Some(utc_offset);
+        }
         if let Some(line_width) = other.line_width {
             self.line_width = Some(line_width);
         }
+        if let Some(key_order) = other.key_order {
+            self.key_order = Some(key_order);
+        }
+        if let Some(sort_string_arrays) = other.sort_string_arrays {
+            self.sort_string_arrays = Some(sort_string_arrays);
+        }
+        if let Some(align_equals) = other.align_equals {
+            self.align_equals = Some(align_equals);
+        }
+        if let Some(align_comments) = other.align_comments {
+            self.align_comments = Some(align_comments);
+        }
+        if let Some(array_bracket_space) = other.array_bracket_space {
+            self.array_bracket_space = Some(array_bracket_space);
+        }
+        if let Some(inline_table_brace_space) = other.inline_table_brace_space {
+            self.inline_table_brace_space = Some(inline_table_brace_space);
+        }
+        if let Some(trailing_comment_space) = other.trailing_comment_space {
+            self.trailing_comment_space = Some(trailing_comment_space);
+        }
+        if let Some(trailing_comma) = other.trailing_comma {
+            self.trailing_comma = Some(trailing_comma);
+        }
+        if let Some(blank_lines_between_tables) = other.blank_lines_between_tables {
+            self.blank_lines_between_tables = Some(blank_lines_between_tables);
+        }
+        if let Some(string_quote) = other.string_quote {
+            self.string_quote = Some(string_quote);
+        }
+        if let Some(multi_line_string) = other.multi_line_string {
+            self.multi_line_string = Some(multi_line_string);
+        }
+        if let Some(string_escape) = other.string_escape {
+            self.string_escape = Some(string_escape);
+        }
+        if let Some(unquote_keys) = other.unquote_keys {
+            self.unquote_keys = Some(unquote_keys);
+        }
+        if let Some(number_underscores) = other.number_underscores {
+            self.number_underscores = Some(number_underscores);
+        }
+        if let Some(hex_digit_case) = other.hex_digit_case {
+            self.hex_digit_case = Some(hex_digit_case);
+        }
+        if let Some(normalize_float_exponent) = other.normalize_float_exponent {
+            self.normalize_float_exponent = Some(normalize_float_exponent);
+        }
 
         self
     }
@@ -75,6 +331,101 @@ impl FormatOptions {
         self.date_time_delimiter.unwrap_or_default()
     }
 
+    #[inline]
+    pub fn fractional_seconds(&self) -> FractionalSeconds {
+        self.fractional_seconds.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn utc_offset(&self) -> UtcOffset {
+        self.utc_offset.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn key_order(&self) -> KeyOrder {
+        self.key_order.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn sort_string_arrays(&self) -> bool {
+        self.sort_string_arrays.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn align_equals(&self) -> bool {
+        self.align_equals.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn align_comments(&self) -> bool {
+        self.align_comments.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn array_bracket_space(&self) -> bool {
+        self.array_bracket_space.unwrap_or_default()
+    }
+
+    pub const fn default_inline_table_brace_space() -> bool {
+        true
+    }
+
+    #[inline]
+    pub fn inline_table_brace_space(&self) -> bool {
+        self.inline_table_brace_space
+            .unwrap_or(Self::default_inline_table_brace_space())
+    }
+
+    #[inline]
+    pub fn trailing_comment_space(&self) -> TrailingCommentSpace {
+        self.trailing_comment_space.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn trailing_comma(&self) -> TrailingComma {
+        self.trailing_comma.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn blank_lines_between_tables(&self) -> BlankLinesBetweenTables {
+        self.blank_lines_between_tables.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn string_quote(&self) -> StringQuote {
+        self.string_quote.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn multi_line_string(&self) -> MultiLineString {
+        self.multi_line_string.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn string_escape(&self) -> StringEscape {
+        self.string_escape.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn unquote_keys(&self) -> bool {
+        self.unquote_keys.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn number_underscores(&self) -> NumberUnderscores {
+        self.number_underscores.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn hex_digit_case(&self) -> HexDigitCase {
+        self.hex_digit_case.unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn normalize_float_exponent(&self) -> bool {
+        self.normalize_float_exponent.unwrap_or_default()
+    }
+
     #[inline]
     pub fn line_width(&self) -> LineWidth {
         self.line_width.unwrap_or_default()
@@ -1,10 +1,16 @@
+mod error;
+mod files;
 pub mod format;
 mod lint;
+mod overrides;
 mod schema;
 mod types;
 
+pub use error::Error;
+pub use files::FilesOptions;
 pub use format::FormatOptions;
-pub use lint::LintOptions;
+pub use lint::{LintOptions, LintRules};
+pub use overrides::OverrideOptions;
 pub use schema::SchemaInfo;
 pub use schema::SchemaOptions;
 use std::path::PathBuf;
@@ -30,6 +36,9 @@ pub struct Config {
     #[cfg_attr(feature = "jsonschema", schemars(default = "TomlVersion::default"))]
     pub toml_version: Option<TomlVersion>,
 
+    /// # Files options.
+    pub files: Option<FilesOptions>,
+
     /// # Formatter options.
     pub format: Option<FormatOptions>,
 
@@ -41,6 +50,61 @@ pub struct Config {
 
     /// # Schema Catalog list.
     pub schemas: Option<Vec<SchemaInfo>>,
+
+    /// # Override options for specific files.
+    ///
+    /// The matching overrides are applied in order.
+    pub overrides: Option<Vec<OverrideOptions>>,
+}
+
+impl Config {
+    /// Get the config for the file, with the matching `[[overrides]]` applied.
+    ///
+    /// The patterns of the overrides are relative to the directory of `config_path`,
+    /// or the current directory if no config file is found.
+    pub fn for_source(
+        &self,
+        source_path: &std::path::Path,
+        config_path: Option<&std::path::Path>,
+    ) -> Config {
+        let mut config = self.clone();
+        let Some(overrides) = config.overrides.take() else {
+            return config;
+        };
+
+        let config_dir = match config_path.and_then(|config_path| config_path.parent()) {
+            Some(config_dir) => std::path::absolute(config_dir).ok(),
+            None => std::env::current_dir().ok(),
+        };
+        let source_path = std::path::absolute(source_path).unwrap_or(source_path.to_owned());
+        let relative_path = config_dir
+            .as_ref()
+            .and_then(|config_dir| source_path.strip_prefix(config_dir).ok())
+            .unwrap_or(&source_path);
+
+        for override_options in overrides
+            .iter()
+            .filter(|override_options| override_options.is_match(relative_path))
+        {
+            if let Some(toml_version) = override_options.toml_version {
+                config.toml_version = Some(toml_version);
+            }
+            if let Some(format) = &override_options.format {
+                config
+                    .format
+                    .get_or_insert_with(FormatOptions::default)
+                    .merge(format);
+            }
+            if let Some(lint) = &override_options.lint {
+                config
+                    .lint
+                    .get_or_insert_with(LintOptions::default)
+                    .merge(lint);
+            }
+        }
+
+        config
+    }
 }
 
 #[doc(hidden)]
@@ -59,51 +123,46 @@ struct Tool {
     tombi: Option<Config>,
 }
 
+pub const CONFIG_FILENAME: &str = "tombi.toml";
+pub const PYPROJECT_FILENAME: &str = "pyproject.toml";
+
 /// Load the config from the current directory.
 #[cfg(feature = "serde")]
 pub fn load_with_path() -> (Config, Option<PathBuf>) {
-    const CONFIG_FILENAME: &str = "tombi.toml";
-    const PYPROJECT_FILENAME: &str = "pyproject.toml";
-
-    let mut current_dir = std::env::current_dir().unwrap();
-    loop {
-        let config_path = current_dir.join(CONFIG_FILENAME);
-        if config_path.exists() {
-            tracing::debug!("\"{}\" found at {:?}", CONFIG_FILENAME, &config_path);
-
-            let Ok(config_str) = std::fs::read_to_string(&config_path) else {
-                tracing::error!("Failed to read {:?}", &config_path);
-                std::process::exit(1);
-            };
-            let Ok(config) = toml::from_str::<Config>(&config_str) else {
-                tracing::error!("Failed to parse {:?}", &config_path);
-                std::process::exit(1);
-            };
-            return (config, Some(config_path));
+    let current_dir = std::env::current_dir().unwrap();
+    match try_load_with_path_from(&current_dir) {
+        Ok(config_with_path) => config_with_path,
+        Err(err) => {
+            tracing::error!("{}", err);
+            std::process::exit(1);
         }
+    }
+}
 
-        let pyproject_toml_path = current_dir.join(PYPROJECT_FILENAME);
-        if pyproject_toml_path.exists() {
-            tracing::debug!(
-                "\"{}\" found at {:?}",
-                PYPROJECT_FILENAME,
-                pyproject_toml_path
-            );
-
-            let Ok(pyproject_toml_str) = std::fs::read_to_string(&pyproject_toml_path) else {
-                tracing::error!("Failed to read {:?}", &pyproject_toml_path);
-                std::process::exit(1);
-            };
-            let Ok(config) = toml::from_str::<PyProjectToml>(&pyproject_toml_str) else {
-                tracing::error!("Failed to parse {:?}", &config_path);
-                std::process::exit(1);
-            };
-            if let Some(Tool { tombi: Some(tombi) }) = config.tool {
-                return (tombi, Some(pyproject_toml_path));
-            } else {
-                tracing::debug!("No [tool.tombi] found in {:?}", &config_path);
+#[cfg(feature = "serde")]
+pub fn load() -> Config {
+    let (config, _) = load_with_path();
+    config
+}
+
+/// Search the config file from the directory to its ancestors, and load it.
+///
+/// `pyproject.toml` without `[tool.tombi]` is skipped.
+#[cfg(feature = "serde")]
+pub fn try_load_with_path_from(dir: &std::path::Path) -> Result<(Config, Option<PathBuf>), Error> {
+    let mut current_dir = dir.to_owned();
+    loop {
+        for filename in [CONFIG_FILENAME, PYPROJECT_FILENAME] {
+            let config_path = current_dir.join(filename);
+            if !config_path.exists() {
                 continue;
             }
+            tracing::debug!("\"{}\" found at {:?}", filename, &config_path);
+
+            match try_load(&config_path)? {
+                Some(config) => return Ok((config, Some(config_path))),
+                None => tracing::debug!("No [tool.tombi] found in {:?}", &config_path),
+            }
         }
 
         if !current_dir.pop() {
@@ -114,11 +173,125 @@ pub fn load_with_path() -> (Config, Option<PathBuf>) {
     tracing::debug!("No config file found.");
     tracing::debug!("Using default config.");
 
-    (Config::default(), None)
+    Ok((Config::default(), None))
 }
 
+/// Load the config from `tombi.toml` or `[tool.tombi]` of `pyproject.toml`.
+///
+/// Returns `None` if `pyproject.toml` has no `[tool.tombi]`.
 #[cfg(feature = "serde")]
-pub fn load() -> Config {
-    let (config, _) = load_with_path();
-    config
+pub fn try_load(config_path: &std::path::Path) -> Result<Option<Config>, Error> {
+    let Ok(config_str) = std::fs::read_to_string(config_path) else {
+        return Err(Error::ConfigFileReadFailed {
+            config_path: config_path.to_owned(),
+        });
+    };
+
+    if config_path.file_name() == Some(std::ffi::OsStr::new(PYPROJECT_FILENAME)) {
+        let Ok(pyproject_toml) = toml::from_str::<PyProjectToml>(&config_str) else {
+            return Err(Error::ConfigFileParseFailed {
+                config_path: config_path.to_owned(),
+            });
+        };
+        Ok(pyproject_toml.tool.and_then(|tool| tool.tombi))
+    } else {
+        let Ok(config) = toml::from_str::<Config>(&config_str) else {
+            return Err(Error::ConfigFileParseFailed {
+                config_path: config_path.to_owned(),
+            });
+        };
+        Ok(Some(config))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn load_skips_pyproject_without_tool_tombi() {
+        let root_dir = std::env::temp_dir().join(format!("config-test-{}", std::process::id()));
+        let project_dir = root_dir.join("project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(
+            root_dir.join(CONFIG_FILENAME),
+            "toml-version = \"v1.1.0-preview\"",
+        )
+        .unwrap();
+        std::fs::write(
+            project_dir.join(PYPROJECT_FILENAME),
+            "[project]\nname = \"a\"",
+        )
+        .unwrap();
+
+        let (config, config_path) = try_load_with_path_from(&project_dir).unwrap();
+        std::fs::remove_dir_all(&root_dir).unwrap();
+
+        assert_eq!(config.toml_version, Some(TomlVersion::V1_1_0_Preview));
+        assert_eq!(config_path, Some(root_dir.join(CONFIG_FILENAME)));
+    }
+
+    #[test]
+    fn for_source_applies_matching_overrides() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [format]
+            indent-width = 4
+
+            [[overrides]]
+            include = ["**/Cargo.toml"]
+            exclude = ["vendor/**"]
+            toml-version = "v1.1.0-preview"
+            format.indent-width = 2
+            "#,
+        )
+        .unwrap();
+        let config_path = std::path::Path::new("/project/tombi.toml");
+
+        let cargo_toml = config.for_source(
+            std::path::Path::new("/project/crates/a/Cargo.toml"),
+            Some(config_path),
+        );
+        assert_eq!(cargo_toml.toml_version, Some(TomlVersion::V1_1_0_Preview));
+        assert_eq!(cargo_toml.format.unwrap().ident(1), "  ");
+
+        let vendor_cargo_toml = config.for_source(
+            std::path::Path::new("/project/vendor/a/Cargo.toml"),
+            Some(config_path),
+        );
+        assert_eq!(vendor_cargo_toml.toml_version, None);
+        assert_eq!(vendor_cargo_toml.format.unwrap().ident(1), "    ");
+
+        let other_toml = config.for_source(
+            std::path::Path::new("/project/other.toml"),
+            Some(config_path),
+        );
+        assert_eq!(other_toml.format.unwrap().ident(1), "    ");
+    }
+
+    #[test]
+    fn format_options_merge_spacing() {
+        let mut options = toml::from_str::<FormatOptions>(
+            r#"
+            array-bracket-space = true
+            trailing-comma = "never"
+            "#,
+        )
+        .unwrap();
+        options.merge(
+            &toml::from_str::<FormatOptions>(
+                r#"
+                trailing-comma = "preserve"
+                blank-lines-between-tables = 2
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert!(options.array_bracket_space());
+        assert!(options.inline_table_brace_space());
+        assert_eq!(options.trailing_comma(), TrailingComma::Preserve);
+        assert_eq!(options.trailing_comment_space().value(), 2);
+        assert_eq!(options.blank_lines_between_tables().value(), 2);
+    }
 }
@@ -1,12 +1,92 @@
+use crate::SeverityLevel;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 #[derive(Debug, Default, Clone)]
-pub struct LintOptions {}
+pub struct LintOptions {
+    /// # Lint rules.
+    ///
+    /// The severity level of each rule: `off`, `warn` or `error`.
+    pub rules: Option<LintRules>,
+}
 
 impl LintOptions {
     pub const fn default() -> Self {
-        Self {}
+        Self { rules: None }
+    }
+
+    pub fn merge(&mut self, other: &LintOptions) -> &mut Self {
+        if let Some(other_rules) = &other.rules {
+            self.rules
+                .get_or_insert_with(LintRules::default)
+                .merge(other_rules);
+        }
+
+        self
+    }
+
+    /// Get the severity level of the rule configured by `[lint.rules]`.
+    #[inline]
+    pub fn rule_level(&self, rule_name: &str) -> Option<SeverityLevel> {
+        self.rules.as_ref().and_then(|rules| rules.get(rule_name))
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[derive(Debug, Default, Clone)]
+pub struct LintRules {
+    /// # Key empty.
+    ///
+    /// Check if the key is empty.
+    ///
+    /// ```toml
+    /// "" = true
+    /// ```
+    #[cfg_attr(feature = "jsonschema", schemars(default = "SeverityLevel::warn"))]
+    pub key_empty: Option<SeverityLevel>,
+
+    /// # Unused suppression.
+    ///
+    /// Check if a suppression comment does not suppress any problem.
+    ///
+    /// ```toml
+    /// # tombi: lint-ignore key-empty
+    /// key = true
+    /// ```
+    #[cfg_attr(feature = "jsonschema", schemars(default = "SeverityLevel::warn"))]
+    pub unused_suppression: Option<SeverityLevel>,
+}
+
+impl LintRules {
+    pub const fn default() -> Self {
+        Self {
+            key_empty: None,
+            unused_suppression: None,
+        }
+    }
+
+    pub fn merge(&mut self, other: &LintRules) -> &mut Self {
+        if let Some(key_empty) = other.key_empty {
+            self.key_empty = Some(key_empty);
+        }
+        if let Some(unused_suppression) = other.unused_suppression {
+            self.unused_suppression = Some(unused_suppression);
+        }
+
+        self
+    }
+
+    /// Get the severity level of the rule by its name.
+    pub fn get(&self, rule_name: &str) -> Option<SeverityLevel> {
+        match rule_name {
+            "key-empty" => self.key_empty,
+            "unused-suppression" => self.unused_suppression,
+            _ => None,
+        }
     }
 }
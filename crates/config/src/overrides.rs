@@ -0,0 +1,51 @@
+use toml_version::TomlVersion;
+
+use crate::{FormatOptions, LintOptions};
+
+/// # Override options.
+///
+/// The options applied to the files matching the patterns,
+/// in addition to the options of the top level.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[derive(Debug, Default, Clone)]
+pub struct OverrideOptions {
+    /// # The file match patterns to apply the options.
+    ///
+    /// Supports glob pattern, relative to the directory of the config file.
+    #[cfg_attr(feature = "jsonschema", schemars(length(min = 1)))]
+    pub include: Vec<String>,
+
+    /// # The file match patterns not to apply the options.
+    ///
+    /// Supports glob pattern, relative to the directory of the config file.
+    pub exclude: Option<Vec<String>>,
+
+    /// # TOML version.
+    pub toml_version: Option<TomlVersion>,
+
+    /// # Formatter options.
+    pub format: Option<FormatOptions>,
+
+    /// # Linter options.
+    pub lint: Option<LintOptions>,
+}
+
+impl OverrideOptions {
+    /// Whether the options are applied to the file.
+    ///
+    /// `source_path` is relative to the directory of the config file.
+    pub fn is_match(&self, source_path: &std::path::Path) -> bool {
+        let matches = |patterns: &[String]| {
+            patterns.iter().any(|pattern| {
+                glob::Pattern::new(pattern)
+                    .map(|pattern| pattern.matches_path(source_path))
+                    .unwrap_or(false)
+            })
+        };
+
+        matches(&self.include) && !self.exclude.as_deref().is_some_and(matches)
+    }
+}
@@ -1,17 +1,41 @@
+mod blank_lines_between_tables;
 mod date_time_delimiter;
+mod fractional_seconds;
+mod hex_digit_case;
 mod indent_style;
 mod indent_width;
+mod key_order;
 mod line_ending;
 mod line_width;
+mod multi_line_string;
+mod number_underscores;
 mod one_or_many;
 mod schema_catalog_enabled;
 mod schema_catalog_path;
+mod severity_level;
+mod string_escape;
+mod string_quote;
+mod trailing_comma;
+mod trailing_comment_space;
+mod utc_offset;
 
+pub use blank_lines_between_tables::BlankLinesBetweenTables;
 pub use date_time_delimiter::DateTimeDelimiter;
+pub use fractional_seconds::FractionalSeconds;
+pub use hex_digit_case::HexDigitCase;
 pub use indent_style::IdentStyle;
 pub use indent_width::IdentWidth;
+pub use key_order::KeyOrder;
 pub use line_ending::LineEnding;
 pub use line_width::LineWidth;
+pub use multi_line_string::MultiLineString;
+pub use number_underscores::NumberUnderscores;
 pub use one_or_many::OneOrMany;
 pub use schema_catalog_enabled::SchemaCatalogEnabled;
 pub use schema_catalog_path::SchemaCatalogPath;
+pub use severity_level::SeverityLevel;
+pub use string_escape::StringEscape;
+pub use string_quote::StringQuote;
+pub use trailing_comma::TrailingComma;
+pub use trailing_comment_space::TrailingCommentSpace;
+pub use utc_offset::UtcOffset;
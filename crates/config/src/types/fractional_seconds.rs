@@ -0,0 +1,34 @@
+/// The digits of the fractional seconds of the date-times and times.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum FractionalSeconds {
+    /// Preserve the fractional seconds of the source.
+    #[default]
+    Preserve,
+
+    /// Remove the trailing zeros, e.g. `07:32:00.500` becomes `07:32:00.5`.
+    Trim,
+
+    /// Example: `07:32:00.500`
+    Milliseconds,
+
+    /// Example: `07:32:00.500000`
+    Microseconds,
+
+    /// Example: `07:32:00.500000000`
+    Nanoseconds,
+}
+
+impl FractionalSeconds {
+    /// The number of the digits of the fractional seconds, if it is fixed.
+    pub const fn digits(&self) -> Option<usize> {
+        match self {
+            Self::Preserve | Self::Trim => None,
+            Self::Milliseconds => Some(3),
+            Self::Microseconds => Some(6),
+            Self::Nanoseconds => Some(9),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+/// The case of the hexadecimal digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum HexDigitCase {
+    /// Preserve the case of the source.
+    #[default]
+    Preserve,
+
+    /// Example: `0xDEADBEEF`
+    Upper,
+
+    /// Example: `0xdeadbeef`
+    Lower,
+}
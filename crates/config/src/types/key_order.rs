@@ -0,0 +1,17 @@
+/// The order of the keys in a table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum KeyOrder {
+    /// Preserve the source order.
+    #[default]
+    Preserve,
+
+    /// Sort the keys alphabetically.
+    Alphabetical,
+
+    /// Sort the keys in the order of the properties of the schema,
+    /// and the keys not defined by the schema alphabetically after them.
+    Schema,
+}
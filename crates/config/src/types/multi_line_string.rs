@@ -0,0 +1,16 @@
+/// The conversion between the single-line and multi-line strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum MultiLineString {
+    /// Preserve the strings of the source.
+    #[default]
+    Preserve,
+
+    /// Convert the multi-line strings without newlines into single-line strings.
+    Collapse,
+
+    /// Convert the basic strings with newlines into multi-line basic strings.
+    Expand,
+}
@@ -0,0 +1,16 @@
+/// The underscores between the digits of the numbers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum NumberUnderscores {
+    /// Preserve the underscores of the source.
+    #[default]
+    Preserve,
+
+    /// Remove the underscores, e.g. `1_000_000` becomes `1000000`.
+    Remove,
+
+    /// Group the digits of the numbers with 5 or more digits, e.g. `1000000` becomes `1_000_000`.
+    Group,
+}
@@ -0,0 +1,35 @@
+/// Severity level of a lint rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum SeverityLevel {
+    /// Disable the rule.
+    Off,
+
+    /// Report the problems as warnings.
+    Warn,
+
+    /// Report the problems as errors.
+    Error,
+}
+
+impl SeverityLevel {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+
+    pub const fn warn() -> Self {
+        Self::Warn
+    }
+}
+
+impl std::fmt::Display for SeverityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
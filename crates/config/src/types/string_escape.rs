@@ -0,0 +1,16 @@
+/// The escape sequences of the basic strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum StringEscape {
+    /// Preserve the escape sequences of the source.
+    #[default]
+    Preserve,
+
+    /// Escape only the characters which must be escaped, e.g. `\u00e9` becomes `é`.
+    Unicode,
+
+    /// Escape also the non-ASCII characters, e.g. `é` becomes `\u00e9`.
+    Ascii,
+}
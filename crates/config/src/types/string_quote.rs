@@ -0,0 +1,16 @@
+/// The quotes of the strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum StringQuote {
+    /// Preserve the quotes of the source.
+    #[default]
+    Preserve,
+
+    /// Prefer the basic strings, e.g. `"value"`, if the value needs no escaping.
+    Basic,
+
+    /// Prefer the literal strings, e.g. `'value'`, if the value can be written without escaping.
+    Literal,
+}
@@ -0,0 +1,16 @@
+/// The trailing comma of a multi-line array.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TrailingComma {
+    /// Always add a comma after the last value.
+    #[default]
+    Always,
+
+    /// Never add a comma after the last value.
+    Never,
+
+    /// Preserve the comma after the last value of the source.
+    Preserve,
+}
@@ -0,0 +1,18 @@
+/// The UTC offset of the offset date-times.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum UtcOffset {
+    /// Preserve the offset of the source.
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "preserve"))]
+    Preserve,
+
+    /// Example: `1979-05-27T07:32:00Z`
+    #[cfg_attr(feature = "serde", serde(rename = "Z"))]
+    Z,
+
+    /// Example: `1979-05-27T07:32:00+00:00`
+    #[cfg_attr(feature = "serde", serde(rename = "+00:00"))]
+    Numeric,
+}
@@ -0,0 +1,57 @@
+/// The page documenting all the diagnostic codes.
+const DIAGNOSTIC_CODES_URL: &str =
+    "https://github.com/tombi-toml/tombi/blob/main/docs/diagnostics.md";
+
+/// A stable code of a diagnostic, such as `T0101 duplicate-key`.
+///
+/// The codes are never reused, even if the problem is no longer reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode {
+    code: &'static str,
+    name: &'static str,
+    explanation: &'static str,
+}
+
+impl DiagnosticCode {
+    #[inline]
+    pub const fn new(code: &'static str, name: &'static str, explanation: &'static str) -> Self {
+        Self {
+            code,
+            name,
+            explanation,
+        }
+    }
+
+    /// The code, such as `T0101`.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The name, such as `duplicate-key`.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// A longer explanation of the problem, with examples of invalid and fixed TOML.
+    #[inline]
+    pub const fn explanation(&self) -> &'static str {
+        self.explanation
+    }
+
+    /// The URL of the documentation of the code.
+    pub fn url(&self) -> String {
+        format!(
+            "{DIAGNOSTIC_CODES_URL}#{}-{}",
+            self.code.to_lowercase(),
+            self.name
+        )
+    }
+}
+
+impl std::fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
@@ -0,0 +1,59 @@
+/// A fix of the problem reported by a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    title: String,
+    edits: Vec<TextEdit>,
+}
+
+impl Fix {
+    #[inline]
+    pub fn new(title: impl Into<String>, edits: Vec<TextEdit>) -> Self {
+        Self {
+            title: title.into(),
+            edits,
+        }
+    }
+
+    /// A short description of the fix, shown as the title of a code action.
+    #[inline]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    #[inline]
+    pub fn edits(&self) -> &[TextEdit] {
+        &self.edits
+    }
+}
+
+/// A replacement of the text in the range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    range: text::Range,
+    new_text: String,
+}
+
+impl TextEdit {
+    #[inline]
+    pub fn new(range: text::Range, new_text: impl Into<String>) -> Self {
+        Self {
+            range,
+            new_text: new_text.into(),
+        }
+    }
+
+    #[inline]
+    pub fn delete(range: text::Range) -> Self {
+        Self::new(range, "")
+    }
+
+    #[inline]
+    pub fn range(&self) -> text::Range {
+        self.range
+    }
+
+    #[inline]
+    pub fn new_text(&self) -> &str {
+        &self.new_text
+    }
+}
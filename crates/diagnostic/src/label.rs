@@ -0,0 +1,26 @@
+/// A secondary range related to the problem of a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    range: text::Range,
+    message: String,
+}
+
+impl Label {
+    #[inline]
+    pub fn new(range: text::Range, message: impl Into<String>) -> Self {
+        Self {
+            range,
+            message: message.into(),
+        }
+    }
+
+    #[inline]
+    pub fn range(&self) -> text::Range {
+        self.range
+    }
+
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
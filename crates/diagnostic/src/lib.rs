@@ -1,15 +1,26 @@
+mod code;
+mod fix;
+mod label;
 mod level;
 pub mod printer;
 
+pub use code::DiagnosticCode;
+pub use fix::{Fix, TextEdit};
+pub use label::Label;
 pub use level::Level;
-pub use printer::Print;
+pub use printer::{Output, Print};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Diagnostic {
     level: level::Level,
+    code: Option<DiagnosticCode>,
     message: String,
     range: text::Range,
     source_file: Option<std::path::PathBuf>,
+    labels: Vec<Label>,
+    notes: Vec<String>,
+    help: Option<String>,
+    fix: Option<Fix>,
 }
 
 impl Diagnostic {
@@ -17,9 +28,14 @@ impl Diagnostic {
     pub fn new_warning(message: impl Into<String>, range: impl Into<text::Range>) -> Self {
         Self {
             level: level::Level::WARNING,
+            code: None,
             message: message.into(),
             range: range.into(),
             source_file: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+            fix: None,
         }
     }
 
@@ -27,9 +43,14 @@ impl Diagnostic {
     pub fn new_error(message: impl Into<String>, range: impl Into<text::Range>) -> Self {
         Self {
             level: level::Level::ERROR,
+            code: None,
             message: message.into(),
             range: range.into(),
             source_file: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+            fix: None,
         }
     }
 
@@ -38,11 +59,44 @@ impl Diagnostic {
         self
     }
 
+    pub fn with_code(mut self, code: DiagnosticCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Add a secondary range related to the problem.
+    pub fn with_label(mut self, range: impl Into<text::Range>, message: impl Into<String>) -> Self {
+        self.labels.push(Label::new(range.into(), message));
+        self
+    }
+
+    /// Add a note explaining the problem.
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Set a help text suggesting how to solve the problem.
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
     #[inline]
     pub fn level(&self) -> level::Level {
         self.level
     }
 
+    #[inline]
+    pub fn code(&self) -> Option<DiagnosticCode> {
+        self.code
+    }
+
     #[inline]
     pub fn message(&self) -> &str {
         &self.message
@@ -62,6 +116,26 @@ impl Diagnostic {
     pub fn source_file(&self) -> Option<&std::path::Path> {
         self.source_file.as_deref()
     }
+
+    #[inline]
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    #[inline]
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    #[inline]
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    #[inline]
+    pub fn fix(&self) -> Option<&Fix> {
+        self.fix.as_ref()
+    }
 }
 
 pub trait SetDiagnostics {
@@ -1,12 +1,32 @@
+mod checkstyle;
+mod github;
+mod gitlab;
+mod json;
 mod pretty;
+mod sarif;
 mod simple;
 
+use std::sync::{Mutex, PoisonError};
+
+pub use checkstyle::Checkstyle;
+pub use github::Github;
+pub use gitlab::Gitlab;
+pub use json::Json;
 pub use pretty::Pretty;
+pub use sarif::Sarif;
 pub use simple::Simple;
 
 pub trait Print<Printer> {
     /// Formats the object using the given formatter.
     fn print(&self, printer: Printer);
+
+    /// Formats the object found in the source text.
+    ///
+    /// The printers showing the source lines override this.
+    fn print_with_source(&self, printer: Printer, source: &str) {
+        let _ = source;
+        self.print(printer);
+    }
 }
 
 impl<T, P> Print<P> for Vec<T>
@@ -19,4 +39,56 @@ where
             item.print(printer);
         }
     }
+
+    fn print_with_source(&self, printer: P, source: &str) {
+        for item in self {
+            item.print_with_source(printer, source);
+        }
+    }
+}
+
+/// The whole output of a printer.
+///
+/// The machine-readable printers wrap the printed items in a document,
+/// such as a JSON array, so `begin` and `end` must be called around the items.
+pub trait Output: Copy {
+    /// Print the beginning of the output, before all the items.
+    fn begin(self) {}
+
+    /// Print the end of the output, after all the items.
+    fn end(self) {}
+}
+
+impl Output for Simple {}
+impl Output for Pretty {}
+
+/// Whether no item of the document is printed yet.
+static IS_FIRST_ITEM: Mutex<bool> = Mutex::new(true);
+
+/// Begin a document of the items separated by commas.
+fn begin_items(header: &str) {
+    *IS_FIRST_ITEM.lock().unwrap_or_else(PoisonError::into_inner) = true;
+    print!("{header}");
+}
+
+/// Print an item of the document, separated by a comma from the previous one.
+///
+/// The items are printed from the tasks of the files in parallel,
+/// so the separator and the item are printed under the same lock.
+fn print_item(item: &serde_json::Value) {
+    let mut is_first_item = IS_FIRST_ITEM.lock().unwrap_or_else(PoisonError::into_inner);
+    print!("{}\n  {item}", if *is_first_item { "" } else { "," });
+    *is_first_item = false;
+}
+
+/// End a document of the items separated by commas.
+fn end_items(footer: &str) {
+    println!("\n{footer}");
+}
+
+/// The path of the source file, or `None` for the standard input.
+fn source_path(diagnostic: &crate::Diagnostic) -> Option<String> {
+    diagnostic
+        .source_file()
+        .map(|source_file| source_file.to_string_lossy().replace('\\', "/"))
 }
@@ -0,0 +1,76 @@
+use crate::{printer::Output, Diagnostic, Level, Print};
+
+/// Print the diagnostics as a Checkstyle XML report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkstyle;
+
+impl Output for Checkstyle {
+    fn begin(self) {
+        println!(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        println!(r#"<checkstyle version="4.3">"#);
+    }
+
+    fn end(self) {
+        println!("</checkstyle>");
+    }
+}
+
+impl Print<Checkstyle> for Diagnostic {
+    fn print(&self, _printer: Checkstyle) {
+        println!("{}", to_file_element(self));
+    }
+}
+
+/// The `<file>` element of the diagnostic.
+///
+/// Checkstyle allows the same file to appear in several elements,
+/// so each diagnostic is printed in its own element.
+fn to_file_element(diagnostic: &Diagnostic) -> String {
+    format!(
+        r#"  <file name="{}"><error line="{}" column="{}" severity="{}" message="{}" source="{}"/></file>"#,
+        escape(&super::source_path(diagnostic).unwrap_or_default()),
+        diagnostic.position().line() + 1,
+        diagnostic.position().column() + 1,
+        match diagnostic.level() {
+            Level::ERROR => "error",
+            Level::WARNING => "warning",
+        },
+        escape(diagnostic.message()),
+        match diagnostic.code() {
+            Some(code) => format!("tombi/{code}"),
+            None => "tombi".to_owned(),
+        },
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diagnostic_to_file_element() {
+        let diagnostic = Diagnostic::new_error(r#""<key>" is required"#, ((2, 0), (2, 0)))
+            .with_source_file("tombi.toml");
+
+        assert_eq!(
+            to_file_element(&diagnostic),
+            r#"  <file name="tombi.toml"><error line="3" column="1" severity="error" message="&quot;&lt;key&gt;&quot; is required" source="tombi"/></file>"#
+        );
+    }
+}
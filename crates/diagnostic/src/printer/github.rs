@@ -0,0 +1,67 @@
+use crate::{printer::Output, Diagnostic, Level, Print};
+
+/// Print the diagnostics as GitHub Actions [workflow commands](https://docs.github.com/en/actions/writing-workflows/choosing-what-your-workflow-does/workflow-commands-for-github-actions),
+/// which are shown as annotations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Github;
+
+impl Output for Github {}
+
+impl Print<Github> for Diagnostic {
+    fn print(&self, _printer: Github) {
+        println!("{}", to_command(self));
+    }
+}
+
+fn to_command(diagnostic: &Diagnostic) -> String {
+    let range = diagnostic.range();
+    let mut properties = vec![];
+    if let Some(source_path) = super::source_path(diagnostic) {
+        properties.push(format!("file={}", escape_property(&source_path)));
+    }
+    properties.push(format!("line={}", range.start().line() + 1));
+    properties.push(format!("col={}", range.start().column() + 1));
+    properties.push(format!("endLine={}", range.end().line() + 1));
+    properties.push(format!("endColumn={}", range.end().column() + 1));
+    if let Some(code) = diagnostic.code() {
+        properties.push(format!("title={}", escape_property(code.code())));
+    }
+
+    format!(
+        "::{} {}::{}",
+        match diagnostic.level() {
+            Level::ERROR => "error",
+            Level::WARNING => "warning",
+        },
+        properties.join(","),
+        escape_data(diagnostic.message())
+    )
+}
+
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(property: &str) -> String {
+    escape_data(property)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diagnostic_to_command() {
+        let diagnostic = Diagnostic::new_warning("100% sure\nreally", ((0, 0), (1, 2)))
+            .with_source_file("a,b.toml");
+
+        assert_eq!(
+            to_command(&diagnostic),
+            "::warning file=a%2Cb.toml,line=1,col=1,endLine=2,endColumn=3::100%25 sure%0Areally"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{printer::Output, Diagnostic, Level, Print};
+
+/// Print the diagnostics as a GitLab [Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gitlab;
+
+impl Output for Gitlab {
+    fn begin(self) {
+        super::begin_items("[");
+    }
+
+    fn end(self) {
+        super::end_items("]");
+    }
+}
+
+impl Print<Gitlab> for Diagnostic {
+    fn print(&self, _printer: Gitlab) {
+        super::print_item(&to_issue(self, None));
+    }
+
+    fn print_with_source(&self, _printer: Gitlab, source: &str) {
+        super::print_item(&to_issue(self, Some(source)));
+    }
+}
+
+fn to_issue(diagnostic: &Diagnostic, source: Option<&str>) -> serde_json::Value {
+    let source_path = super::source_path(diagnostic).unwrap_or_default();
+
+    // NOTE: GitLab compares the fingerprints of the issues
+    //       to find the issues introduced by a merge request,
+    //       so the fingerprint must not change between the builds of tombi,
+    //       nor when the lines before the problem are edited.
+    //       The text of the line is used instead of the position.
+    let line = source
+        .and_then(|source| source.lines().nth(diagnostic.position().line() as usize))
+        .unwrap_or_default()
+        .trim();
+    let fingerprint = fingerprint(&[
+        source_path.as_str(),
+        diagnostic.code().map_or("", |code| code.code()),
+        diagnostic.message(),
+        line,
+    ]);
+
+    serde_json::json!({
+        "description": diagnostic.message(),
+        "check_name": diagnostic.code().map_or("tombi", |code| code.code()),
+        "fingerprint": format!("{fingerprint:016x}"),
+        "severity": match diagnostic.level() {
+            Level::ERROR => "major",
+            Level::WARNING => "minor",
+        },
+        "location": {
+            "path": source_path,
+            "lines": { "begin": diagnostic.position().line() + 1 },
+        },
+    })
+}
+
+/// The 64-bit FNV-1a hash of the fields, separated by NUL characters.
+fn fingerprint(fields: &[&str]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            hash = hash.wrapping_mul(PRIME);
+        }
+        for byte in field.bytes() {
+            hash = (hash ^ byte as u64).wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DiagnosticCode;
+
+    const CODE: DiagnosticCode = DiagnosticCode::new("T9999", "test", "");
+
+    #[test]
+    fn diagnostic_to_issue() {
+        let diagnostic = Diagnostic::new_warning("message", ((1, 2), (1, 4)))
+            .with_code(CODE)
+            .with_source_file("tombi.toml");
+
+        assert_eq!(
+            to_issue(&diagnostic, Some("a = 1\nb = 2\n")),
+            serde_json::json!({
+                "description": "message",
+                "check_name": "T9999",
+                "fingerprint": "e06379e47ad21ad0",
+                "severity": "minor",
+                "location": {
+                    "path": "tombi.toml",
+                    "lines": { "begin": 2 },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn fingerprint_independent_of_position() {
+        let issue = to_issue(
+            &Diagnostic::new_warning("message", ((0, 0), (0, 1))).with_code(CODE),
+            Some("a = 1\n"),
+        );
+        let moved_issue = to_issue(
+            &Diagnostic::new_warning("message", ((2, 2), (2, 3))).with_code(CODE),
+            Some("b = 2\n\n  a = 1\n"),
+        );
+        let other_issue = to_issue(
+            &Diagnostic::new_warning("message", ((1, 0), (1, 1))).with_code(CODE),
+            Some("a = 1\nb = 2\n"),
+        );
+
+        assert_eq!(issue["fingerprint"], moved_issue["fingerprint"]);
+        assert_ne!(issue["fingerprint"], other_issue["fingerprint"]);
+    }
+
+    #[test]
+    fn fnv1a_fingerprint() {
+        assert_eq!(fingerprint(&[""]), 0xcbf29ce484222325);
+        assert_eq!(fingerprint(&["a"]), 0xaf63dc4c8601ec8c);
+        assert_ne!(fingerprint(&["ab", "c"]), fingerprint(&["a", "bc"]));
+    }
+}
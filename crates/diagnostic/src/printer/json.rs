@@ -0,0 +1,72 @@
+use crate::{printer::Output, Diagnostic, Level, Print};
+
+/// Print the diagnostics as a JSON array.
+///
+/// The lines and columns are 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Json;
+
+impl Output for Json {
+    fn begin(self) {
+        super::begin_items("[");
+    }
+
+    fn end(self) {
+        super::end_items("]");
+    }
+}
+
+impl Print<Json> for Diagnostic {
+    fn print(&self, _printer: Json) {
+        super::print_item(&to_json(self));
+    }
+}
+
+fn to_json(diagnostic: &Diagnostic) -> serde_json::Value {
+    let range = diagnostic.range();
+
+    serde_json::json!({
+        "level": match diagnostic.level() {
+            Level::ERROR => "error",
+            Level::WARNING => "warning",
+        },
+        "code": diagnostic.code().map(|code| code.code()),
+        "message": diagnostic.message(),
+        "file": super::source_path(diagnostic),
+        "range": {
+            "start": {
+                "line": range.start().line() + 1,
+                "column": range.start().column() + 1,
+            },
+            "end": {
+                "line": range.end().line() + 1,
+                "column": range.end().column() + 1,
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diagnostic_to_json() {
+        let diagnostic = Diagnostic::new_warning("message", ((1, 2), (1, 4)))
+            .with_source_file("path/to/tombi.toml");
+
+        assert_eq!(
+            to_json(&diagnostic),
+            serde_json::json!({
+                "level": "warning",
+                "code": null,
+                "message": "message",
+                "file": "path/to/tombi.toml",
+                "range": {
+                    "start": { "line": 2, "column": 3 },
+                    "end": { "line": 2, "column": 5 },
+                },
+            })
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::BTreeSet, fmt::Write};
+
 use nu_ansi_term::{Color, Style};
 
 use crate::{printer::Simple, Diagnostic, Level, Print};
@@ -14,6 +16,9 @@ impl Print<Pretty> for Level {
 impl Print<Pretty> for Diagnostic {
     fn print(&self, printer: Pretty) {
         self.level().print(printer);
+        if let Some(code) = self.code() {
+            print!("{}", self.level().color().bold().paint(format!("[{code}]")));
+        }
         println!(": {}", Style::new().bold().paint(self.message()));
 
         let at_style: Style = Style::new().fg(Color::DarkGray);
@@ -40,4 +45,259 @@ impl Print<Pretty> for Diagnostic {
             );
         }
     }
+
+    fn print_with_source(&self, printer: Pretty, source: &str) {
+        // NOTE: The diagnostics of the files are printed in parallel,
+        //       so the lines of a diagnostic are printed under the same lock.
+        let _stdout = std::io::stdout().lock();
+
+        self.print(printer);
+        print!(
+            "{}",
+            source_frame(
+                self,
+                source,
+                &FrameStyles {
+                    gutter: Style::new().bold().fg(Color::Blue),
+                    primary: Style::from(self.level()),
+                    secondary: Style::new().bold().fg(Color::Blue),
+                    note: Style::new().bold(),
+                }
+            )
+        );
+    }
+}
+
+/// The number of the lines shown before and after the annotated lines.
+const CONTEXT_LINES: u32 = 1;
+
+/// The maximum number of the lines shown for a multi-line range.
+const MAX_RANGE_LINES: u32 = 6;
+
+struct FrameStyles {
+    gutter: Style,
+    primary: Style,
+    secondary: Style,
+    note: Style,
+}
+
+struct Annotation<'a> {
+    range: text::Range,
+    label: Option<&'a str>,
+    is_primary: bool,
+}
+
+/// Render the source lines of the diagnostic, with the ranges underlined.
+///
+/// ```text
+///    |
+///  1 | [package]
+///    | --------- the table is defined here
+///  2 | name = "tombi"
+///  3 | [package]
+///    | ^^^^^^^^^
+///    |
+///    = help: ...
+/// ```
+fn source_frame(diagnostic: &Diagnostic, source: &str, styles: &FrameStyles) -> String {
+    let lines = source.lines().collect::<Vec<_>>();
+    let last_line = lines.len().saturating_sub(1) as u32;
+
+    let annotations = std::iter::once(Annotation {
+        range: diagnostic.range(),
+        label: None,
+        is_primary: true,
+    })
+    .chain(diagnostic.labels().iter().map(|label| Annotation {
+        range: label.range(),
+        label: Some(label.message()),
+        is_primary: false,
+    }))
+    .collect::<Vec<_>>();
+
+    let mut shown_lines = BTreeSet::new();
+    for annotation in &annotations {
+        let start = annotation.range.start().line().min(last_line);
+        let end = annotation.range.end().line().min(last_line);
+        if end - start < MAX_RANGE_LINES {
+            shown_lines.extend(start..=end);
+        } else {
+            shown_lines.extend(start..start + MAX_RANGE_LINES / 2);
+            shown_lines.extend(end + 1 - MAX_RANGE_LINES / 2..=end);
+        }
+        shown_lines.extend(start.saturating_sub(CONTEXT_LINES)..start);
+        shown_lines.extend(end + 1..=(end + CONTEXT_LINES).min(last_line));
+    }
+
+    let width = shown_lines
+        .last()
+        .map(|line| (line + 1).to_string().len())
+        .unwrap_or_default();
+    let padding = " ".repeat(width);
+    let gutter = |text: &str| styles.gutter.paint(text.to_string()).to_string();
+
+    let mut frame = String::new();
+    let _ = writeln!(frame, "    {padding} {}", gutter("|"));
+
+    let mut prev_line = None;
+    for line in shown_lines {
+        if prev_line.is_some_and(|prev_line| line > prev_line + 1) {
+            let _ = writeln!(frame, "    {}", gutter("..."));
+        }
+        prev_line = Some(line);
+
+        let text = lines.get(line as usize).copied().unwrap_or_default();
+        let text = text.replace('\t', " ");
+        let source_line = format!(
+            "    {} {} {}",
+            gutter(&format!("{:>width$}", line + 1)),
+            gutter("|"),
+            text
+        );
+        let _ = writeln!(frame, "{}", source_line.trim_end());
+
+        let mut line_annotations = annotations
+            .iter()
+            .filter(|annotation| {
+                annotation.range.start().line() <= line && line <= annotation.range.end().line()
+            })
+            .collect::<Vec<_>>();
+        line_annotations.sort_by_key(|annotation| {
+            if annotation.range.start().line() == line {
+                annotation.range.start().column()
+            } else {
+                0
+            }
+        });
+
+        for annotation in line_annotations {
+            let start = if annotation.range.start().line() == line {
+                annotation.range.start().column()
+            } else {
+                0
+            };
+            let end = if annotation.range.end().line() == line {
+                annotation.range.end().column()
+            } else {
+                text.chars().count() as u32
+            };
+            let (marker, style) = if annotation.is_primary {
+                ("^", &styles.primary)
+            } else {
+                ("-", &styles.secondary)
+            };
+            let underline = marker.repeat(end.saturating_sub(start).max(1) as usize);
+            let label = match annotation.label {
+                Some(label) if annotation.range.end().line() == line => format!(" {label}"),
+                _ => String::new(),
+            };
+
+            let _ = writeln!(
+                frame,
+                "    {padding} {} {}{}",
+                gutter("|"),
+                " ".repeat(start as usize),
+                style.paint(format!("{underline}{label}"))
+            );
+        }
+    }
+
+    let notes = diagnostic
+        .notes()
+        .iter()
+        .map(|note| ("note", note.as_str()))
+        .chain(diagnostic.help().map(|help| ("help", help)))
+        .chain(diagnostic.fix().map(|fix| ("help", fix.title())))
+        .collect::<Vec<_>>();
+    if !notes.is_empty() {
+        let _ = writeln!(frame, "    {padding} {}", gutter("|"));
+    }
+    for (kind, note) in notes {
+        let _ = writeln!(
+            frame,
+            "    {padding} {} {}: {note}",
+            gutter("="),
+            styles.note.paint(kind)
+        );
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn plain_source_frame(diagnostic: &Diagnostic, source: &str) -> String {
+        source_frame(
+            diagnostic,
+            source,
+            &FrameStyles {
+                gutter: Style::new(),
+                primary: Style::new(),
+                secondary: Style::new(),
+                note: Style::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn frame_with_label_and_help() {
+        let source = "[a]\nkey = 1\n\n[a]\n";
+        let diagnostic = Diagnostic::new_error("conflicting table", ((3, 1), (3, 2)))
+            .with_label(((0, 1), (0, 2)), "the table is defined here")
+            .with_help("merge the tables");
+
+        assert_eq!(
+            plain_source_frame(&diagnostic, source),
+            [
+                "      |",
+                "    1 | [a]",
+                "      |  - the table is defined here",
+                "    2 | key = 1",
+                "    3 |",
+                "    4 | [a]",
+                "      |  ^",
+                "      |",
+                "      = help: merge the tables",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn frame_of_multi_line_range() {
+        let source = "a = [\n  1,\n  2,\n]\nb = 1";
+        let diagnostic = Diagnostic::new_warning("array", ((0, 4), (3, 1)));
+
+        assert_eq!(
+            plain_source_frame(&diagnostic, source),
+            [
+                "      |",
+                "    1 | a = [",
+                "      |     ^",
+                "    2 |   1,",
+                "      | ^^^^",
+                "    3 |   2,",
+                "      | ^^^^",
+                "    4 | ]",
+                "      | ^",
+                "    5 | b = 1",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn frame_elides_long_range() {
+        let source = (0..10).map(|i| format!("a{i} = {i}")).collect::<Vec<_>>();
+        let diagnostic = Diagnostic::new_error("long", ((1, 0), (8, 6)));
+        let frame = plain_source_frame(&diagnostic, &source.join("\n"));
+
+        assert!(frame.contains("    ...\n"));
+        assert!(!frame.contains("a4 = 4"));
+        assert!(frame.contains("   10 | a9 = 9"));
+    }
 }
@@ -0,0 +1,90 @@
+use crate::{printer::Output, Diagnostic, Level, Print};
+
+/// Print the diagnostics as a [SARIF](https://sarifweb.azurewebsites.net/) 2.1.0 log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sarif;
+
+impl Output for Sarif {
+    // NOTE: The columns of the positions are counted in characters.
+    fn begin(self) {
+        super::begin_items(concat!(
+            r#"{"$schema":"https://json.schemastore.org/sarif-2.1.0.json","version":"2.1.0","#,
+            r#""runs":[{"tool":{"driver":{"name":"tombi","informationUri":"https://github.com/tombi-toml/tombi"}},"#,
+            r#""columnKind":"unicodeCodePoints","results":["#,
+        ));
+    }
+
+    fn end(self) {
+        super::end_items("]}]}");
+    }
+}
+
+impl Print<Sarif> for Diagnostic {
+    fn print(&self, _printer: Sarif) {
+        super::print_item(&to_result(self));
+    }
+}
+
+fn to_result(diagnostic: &Diagnostic) -> serde_json::Value {
+    let range = diagnostic.range();
+    let mut physical_location = serde_json::json!({
+        "region": {
+            "startLine": range.start().line() + 1,
+            "startColumn": range.start().column() + 1,
+            "endLine": range.end().line() + 1,
+            "endColumn": range.end().column() + 1,
+        },
+    });
+    if let Some(source_path) = super::source_path(diagnostic) {
+        physical_location["artifactLocation"] = serde_json::json!({ "uri": source_path });
+    }
+
+    let mut result = serde_json::json!({
+        "level": match diagnostic.level() {
+            Level::ERROR => "error",
+            Level::WARNING => "warning",
+        },
+        "message": { "text": diagnostic.message() },
+        "locations": [{ "physicalLocation": physical_location }],
+    });
+    if let Some(code) = diagnostic.code() {
+        result["ruleId"] = serde_json::json!(code.code());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DiagnosticCode;
+
+    const CODE: DiagnosticCode = DiagnosticCode::new("T9999", "test", "");
+
+    #[test]
+    fn diagnostic_to_result() {
+        let diagnostic = Diagnostic::new_error("message", ((0, 0), (0, 3)))
+            .with_code(CODE)
+            .with_source_file("tombi.toml");
+
+        assert_eq!(
+            to_result(&diagnostic),
+            serde_json::json!({
+                "level": "error",
+                "ruleId": "T9999",
+                "message": { "text": "message" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "tombi.toml" },
+                        "region": {
+                            "startLine": 1,
+                            "startColumn": 1,
+                            "endLine": 1,
+                            "endColumn": 4,
+                        },
+                    },
+                }],
+            })
+        );
+    }
+}
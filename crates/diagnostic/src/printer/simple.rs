@@ -15,6 +15,9 @@ impl Print<Simple> for Level {
 impl Print<Simple> for Diagnostic {
     fn print(&self, printer: Simple) {
         self.level().print(printer);
+        if let Some(code) = self.code() {
+            print!("{}", self.level().color().bold().paint(format!("[{code}]")));
+        }
         println!(": {}", Style::new().bold().paint(self.message()));
     }
 }
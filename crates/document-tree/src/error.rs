@@ -1,3 +1,9 @@
+#[cfg(feature = "diagnostic")]
+mod code;
+
+#[cfg(feature = "diagnostic")]
+pub use code::CODES;
+
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     #[error("duplicate key: {key}")]
@@ -90,21 +96,29 @@ impl Error {
 impl diagnostic::SetDiagnostics for Error {
     fn set_diagnostic(&self, diagnostics: &mut Vec<diagnostic::Diagnostic>) {
         match self {
+            Self::ConflictTable { range1, range2 } => {
+                diagnostics.push(
+                    diagnostic::Diagnostic::new_error(self.to_message(), *range2)
+                        .with_code(self.code())
+                        .with_label(*range1, "the table is defined here"),
+                );
+            }
             Self::ConflictArray { range1, range2 } => {
-                let diagnostic1 = diagnostic::Diagnostic::new_error(self.to_message(), *range1);
+                let diagnostic1 = diagnostic::Diagnostic::new_error(self.to_message(), *range1)
+                    .with_code(self.code());
                 if !diagnostics.contains(&diagnostic1) {
                     diagnostics.push(diagnostic1);
                 }
-                diagnostics.push(diagnostic::Diagnostic::new_error(
-                    self.to_message(),
-                    *range2,
-                ));
+                diagnostics.push(
+                    diagnostic::Diagnostic::new_error(self.to_message(), *range2)
+                        .with_code(self.code()),
+                );
             }
             _ => {
-                diagnostics.push(diagnostic::Diagnostic::new_error(
-                    self.to_message(),
-                    self.range(),
-                ));
+                diagnostics.push(
+                    diagnostic::Diagnostic::new_error(self.to_message(), self.range())
+                        .with_code(self.code()),
+                );
             }
         }
     }
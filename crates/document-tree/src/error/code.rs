@@ -0,0 +1,265 @@
+use diagnostic::DiagnosticCode;
+
+use super::Error;
+
+pub const DUPLICATE_KEY: DiagnosticCode = DiagnosticCode::new(
+    "T0101",
+    "duplicate-key",
+    r#"The key is defined more than once.
+
+Invalid:
+
+```toml
+name = "tombi"
+name = "toml"
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+```
+"#,
+);
+
+pub const CONFLICTING_TABLE: DiagnosticCode = DiagnosticCode::new(
+    "T0102",
+    "conflicting-table",
+    r#"The table is defined more than once, or conflicts with a value of the same key.
+
+A table can be defined only once, whether by a table header, a dotted key or an inline table.
+
+Invalid:
+
+```toml
+[package]
+name = "tombi"
+
+[package]
+version = "0.1.0"
+```
+
+Fixed:
+
+```toml
+[package]
+name = "tombi"
+version = "0.1.0"
+```
+"#,
+);
+
+pub const CONFLICTING_ARRAY: DiagnosticCode = DiagnosticCode::new(
+    "T0103",
+    "conflicting-array",
+    r#"The array of tables conflicts with a static array of the same key.
+
+A static array cannot be extended by an array of tables header.
+
+Invalid:
+
+```toml
+bin = [{ name = "tombi" }]
+
+[[bin]]
+name = "toml"
+```
+
+Fixed:
+
+```toml
+[[bin]]
+name = "tombi"
+
+[[bin]]
+name = "toml"
+```
+"#,
+);
+
+pub const INVALID_INTEGER: DiagnosticCode = DiagnosticCode::new(
+    "T0104",
+    "invalid-integer",
+    r#"The integer cannot be represented as a 64-bit signed integer.
+
+Invalid:
+
+```toml
+size = 9_223_372_036_854_775_808
+```
+
+Fixed:
+
+```toml
+size = 9_223_372_036_854_775_807
+```
+"#,
+);
+
+pub const INVALID_FLOAT: DiagnosticCode = DiagnosticCode::new(
+    "T0105",
+    "invalid-float",
+    r#"The float cannot be parsed.
+
+Invalid:
+
+```toml
+ratio = 1e
+```
+
+Fixed:
+
+```toml
+ratio = 1e0
+```
+"#,
+);
+
+pub const INVALID_STRING: DiagnosticCode = DiagnosticCode::new(
+    "T0106",
+    "invalid-string",
+    r#"The string or the quoted key contains an invalid escape sequence or character,
+or the bare key is not allowed in the TOML version.
+
+Invalid:
+
+```toml
+path = "C:\Users"
+```
+
+Fixed:
+
+```toml
+path = 'C:\Users'
+```
+"#,
+);
+
+pub const INVALID_OFFSET_DATE_TIME: DiagnosticCode = DiagnosticCode::new(
+    "T0107",
+    "invalid-offset-date-time-value",
+    r#"The offset date-time is not a valid date and time.
+
+Invalid:
+
+```toml
+released = 2024-02-30T00:00:00Z
+```
+
+Fixed:
+
+```toml
+released = 2024-02-29T00:00:00Z
+```
+"#,
+);
+
+pub const INVALID_LOCAL_DATE_TIME: DiagnosticCode = DiagnosticCode::new(
+    "T0108",
+    "invalid-local-date-time-value",
+    r#"The local date-time is not a valid date and time.
+
+Invalid:
+
+```toml
+started = 2024-01-01T25:00:00
+```
+
+Fixed:
+
+```toml
+started = 2024-01-02T01:00:00
+```
+"#,
+);
+
+pub const INVALID_LOCAL_DATE: DiagnosticCode = DiagnosticCode::new(
+    "T0109",
+    "invalid-local-date-value",
+    r#"The local date is not a valid date.
+
+Invalid:
+
+```toml
+birthday = 2023-02-29
+```
+
+Fixed:
+
+```toml
+birthday = 2023-02-28
+```
+"#,
+);
+
+pub const INVALID_LOCAL_TIME: DiagnosticCode = DiagnosticCode::new(
+    "T0110",
+    "invalid-local-time-value",
+    r#"The local time is not a valid time.
+
+Invalid:
+
+```toml
+alarm = 24:00:00
+```
+
+Fixed:
+
+```toml
+alarm = 00:00:00
+```
+"#,
+);
+
+pub const INVALID_COMMENT: DiagnosticCode = DiagnosticCode::new(
+    "T0111",
+    "invalid-comment",
+    r#"The comment contains a control character other than tab.
+
+Invalid:
+
+```toml
+# The bell␇ is not allowed
+```
+
+Fixed:
+
+```toml
+# The bell is not allowed
+```
+"#,
+);
+
+/// All the diagnostic codes of the document tree.
+pub const CODES: &[DiagnosticCode] = &[
+    DUPLICATE_KEY,
+    CONFLICTING_TABLE,
+    CONFLICTING_ARRAY,
+    INVALID_INTEGER,
+    INVALID_FLOAT,
+    INVALID_STRING,
+    INVALID_OFFSET_DATE_TIME,
+    INVALID_LOCAL_DATE_TIME,
+    INVALID_LOCAL_DATE,
+    INVALID_LOCAL_TIME,
+    INVALID_COMMENT,
+];
+
+impl Error {
+    /// The stable code of the error.
+    pub const fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::DuplicateKey { .. } => DUPLICATE_KEY,
+            Self::ConflictTable { .. } => CONFLICTING_TABLE,
+            Self::ConflictArray { .. } => CONFLICTING_ARRAY,
+            Self::ParseIntError { .. } => INVALID_INTEGER,
+            Self::ParseFloatError { .. } => INVALID_FLOAT,
+            Self::ParseStringError { .. } => INVALID_STRING,
+            Self::ParseOffsetDateTimeError { .. } => INVALID_OFFSET_DATE_TIME,
+            Self::ParseLocalDateTimeError { .. } => INVALID_LOCAL_DATE_TIME,
+            Self::ParseLocalDateError { .. } => INVALID_LOCAL_DATE,
+            Self::ParseLocalTimeError { .. } => INVALID_LOCAL_TIME,
+            Self::ParseCommentError { .. } => INVALID_COMMENT,
+        }
+    }
+}
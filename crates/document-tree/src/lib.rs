@@ -4,6 +4,8 @@ pub mod support;
 mod value;
 
 pub use error::Error;
+#[cfg(feature = "diagnostic")]
+pub use error::CODES;
 pub use key::{Key, KeyKind};
 use std::ops::Deref;
 use support::comment::try_new_comment;
@@ -1,7 +1,7 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Boolean {
     value: bool,
-    node: ast::Boolean,
+    range: text::Range,
 }
 
 impl Boolean {
@@ -10,14 +10,9 @@ impl Boolean {
         self.value
     }
 
-    #[inline]
-    pub fn node(&self) -> &ast::Boolean {
-        &self.node
-    }
-
     #[inline]
     pub fn range(&self) -> text::Range {
-        self.node.token().unwrap().range()
+        self.range
     }
 
     #[inline]
@@ -37,7 +32,7 @@ impl TryFrom<ast::Boolean> for Boolean {
                 "false" => false,
                 _ => unreachable!(),
             },
-            node,
+            range: token.range(),
         })
     }
 }
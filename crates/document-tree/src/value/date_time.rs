@@ -8,7 +8,8 @@ use crate::{
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OffsetDateTime {
     value: chrono::DateTime<chrono::FixedOffset>,
-    node: ast::OffsetDateTime,
+    text: String,
+    range: text::Range,
 }
 
 impl OffsetDateTime {
@@ -17,14 +18,15 @@ impl OffsetDateTime {
         &self.value
     }
 
+    /// The date-time as written in the source.
     #[inline]
-    pub fn node(&self) -> &ast::OffsetDateTime {
-        &self.node
+    pub fn text(&self) -> &str {
+        &self.text
     }
 
     #[inline]
     pub fn range(&self) -> text::Range {
-        self.node.token().unwrap().range()
+        self.range
     }
 
     #[inline]
@@ -38,7 +40,8 @@ pub struct LocalDateTime {
     // NOTE: `chrono::DateTime<chrono::Local>` is not enough to represent local date time.
     //       `chrono::Local.from_local_datetime(native_date_time)` cannot uniquely determine the time zone in some cases, so we handle NativeDateTime.
     value: chrono::NaiveDateTime,
-    node: ast::LocalDateTime,
+    text: String,
+    range: text::Range,
 }
 
 impl LocalDateTime {
@@ -47,14 +50,15 @@ impl LocalDateTime {
         &self.value
     }
 
+    /// The date-time as written in the source.
     #[inline]
-    pub fn node(&self) -> &ast::LocalDateTime {
-        &self.node
+    pub fn text(&self) -> &str {
+        &self.text
     }
 
     #[inline]
     pub fn range(&self) -> text::Range {
-        self.node.token().unwrap().range()
+        self.range
     }
 
     #[inline]
@@ -66,7 +70,8 @@ impl LocalDateTime {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LocalDate {
     value: chrono::NaiveDate,
-    node: ast::LocalDate,
+    text: String,
+    range: text::Range,
 }
 
 impl LocalDate {
@@ -75,14 +80,15 @@ impl LocalDate {
         &self.value
     }
 
+    /// The date-time as written in the source.
     #[inline]
-    pub fn node(&self) -> &ast::LocalDate {
-        &self.node
+    pub fn text(&self) -> &str {
+        &self.text
     }
 
     #[inline]
     pub fn range(&self) -> text::Range {
-        self.node.token().unwrap().range()
+        self.range
     }
 
     #[inline]
@@ -94,7 +100,8 @@ impl LocalDate {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LocalTime {
     value: chrono::NaiveTime,
-    node: ast::LocalTime,
+    text: String,
+    range: text::Range,
 }
 
 impl LocalTime {
@@ -103,14 +110,15 @@ impl LocalTime {
         &self.value
     }
 
+    /// The date-time as written in the source.
     #[inline]
-    pub fn node(&self) -> &ast::LocalTime {
-        &self.node
+    pub fn text(&self) -> &str {
+        &self.text
     }
 
     #[inline]
     pub fn range(&self) -> text::Range {
-        self.node.token().unwrap().range()
+        self.range
     }
 
     #[inline]
@@ -125,7 +133,14 @@ impl TryIntoDocumentTree<OffsetDateTime> for ast::OffsetDateTime {
         toml_version: toml_version::TomlVersion,
     ) -> Result<OffsetDateTime, Vec<crate::Error>> {
         match try_new_offset_date_time(&self, toml_version) {
-            Ok(value) => Ok(OffsetDateTime { value, node: self }),
+            Ok(value) => {
+                let token = self.token().unwrap();
+                Ok(OffsetDateTime {
+                    value,
+                    text: token.text().to_string(),
+                    range: token.range(),
+                })
+            }
             Err(error) => Err(vec![error]),
         }
     }
@@ -137,7 +152,14 @@ impl TryIntoDocumentTree<LocalDateTime> for ast::LocalDateTime {
         toml_version: toml_version::TomlVersion,
     ) -> Result<LocalDateTime, Vec<crate::Error>> {
         match try_new_local_date_time(&self, toml_version) {
-            Ok(value) => Ok(LocalDateTime { value, node: self }),
+            Ok(value) => {
+                let token = self.token().unwrap();
+                Ok(LocalDateTime {
+                    value,
+                    text: token.text().to_string(),
+                    range: token.range(),
+                })
+            }
             Err(error) => Err(vec![error]),
         }
     }
@@ -149,7 +171,14 @@ impl TryIntoDocumentTree<LocalDate> for ast::LocalDate {
         toml_version: toml_version::TomlVersion,
     ) -> Result<LocalDate, Vec<crate::Error>> {
         match try_new_local_date(&self, toml_version) {
-            Ok(value) => Ok(LocalDate { value, node: self }),
+            Ok(value) => {
+                let token = self.token().unwrap();
+                Ok(LocalDate {
+                    value,
+                    text: token.text().to_string(),
+                    range: token.range(),
+                })
+            }
             Err(error) => Err(vec![error]),
         }
     }
@@ -161,7 +190,14 @@ impl TryIntoDocumentTree<LocalTime> for ast::LocalTime {
         toml_version: toml_version::TomlVersion,
     ) -> Result<LocalTime, Vec<crate::Error>> {
         match try_new_local_time(&self, toml_version) {
-            Ok(value) => Ok(LocalTime { value, node: self }),
+            Ok(value) => {
+                let token = self.token().unwrap();
+                Ok(LocalTime {
+                    value,
+                    text: token.text().to_string(),
+                    range: token.range(),
+                })
+            }
             Err(error) => Err(vec![error]),
         }
     }
@@ -3,7 +3,7 @@ use crate::support::float::try_from_float;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Float {
     value: f64,
-    node: ast::Float,
+    range: text::Range,
 }
 
 impl Float {
@@ -12,14 +12,9 @@ impl Float {
         self.value
     }
 
-    #[inline]
-    pub fn node(&self) -> &ast::Float {
-        &self.node
-    }
-
     #[inline]
     pub fn range(&self) -> text::Range {
-        self.node.token().unwrap().range()
+        self.range
     }
 
     #[inline]
@@ -35,7 +30,7 @@ impl TryFrom<ast::Float> for Float {
         let token = node.token().unwrap();
         let range = token.range();
         match try_from_float(token.text()) {
-            Ok(value) => Ok(Self { value, node }),
+            Ok(value) => Ok(Self { value, range }),
             Err(error) => Err(vec![crate::Error::ParseFloatError { error, range }]),
         }
     }
@@ -2,24 +2,25 @@ use crate::support::integer::{
     try_from_binary, try_from_decimal, try_from_hexadecimal, try_from_octal,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IntegerKind {
-    Binary(ast::IntegerBin),
-    Decimal(ast::IntegerDec),
-    Octal(ast::IntegerOct),
-    Hexadecimal(ast::IntegerHex),
+    Binary,
+    Decimal,
+    Octal,
+    Hexadecimal,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Integer {
     kind: IntegerKind,
     value: i64,
+    range: text::Range,
 }
 
 impl Integer {
     #[inline]
-    pub fn kind(&self) -> &IntegerKind {
-        &self.kind
+    pub fn kind(&self) -> IntegerKind {
+        self.kind
     }
 
     #[inline]
@@ -29,14 +30,7 @@ impl Integer {
 
     #[inline]
     pub fn range(&self) -> text::Range {
-        match self.kind() {
-            IntegerKind::Binary(node) => node.token(),
-            IntegerKind::Decimal(node) => node.token(),
-            IntegerKind::Octal(node) => node.token(),
-            IntegerKind::Hexadecimal(node) => node.token(),
-        }
-        .unwrap()
-        .range()
+        self.range
     }
 
     #[inline]
@@ -50,12 +44,13 @@ impl TryFrom<ast::IntegerBin> for Integer {
 
     fn try_from(node: ast::IntegerBin) -> Result<Self, Self::Error> {
         let token = node.token().unwrap();
-        let range: text::Range = token.range();
+        let range = token.range();
 
         match try_from_binary(token.text()) {
             Ok(value) => Ok(Self {
-                kind: IntegerKind::Binary(node),
+                kind: IntegerKind::Binary,
                 value,
+                range,
             }),
             Err(error) => Err(vec![crate::Error::ParseIntError { error, range }]),
         }
@@ -71,8 +66,9 @@ impl TryFrom<ast::IntegerOct> for Integer {
 
         match try_from_octal(token.text()) {
             Ok(value) => Ok(Self {
-                kind: IntegerKind::Octal(node),
+                kind: IntegerKind::Octal,
                 value,
+                range,
             }),
             Err(error) => Err(vec![crate::Error::ParseIntError { error, range }]),
         }
@@ -88,8 +84,9 @@ impl TryFrom<ast::IntegerDec> for Integer {
 
         match try_from_decimal(token.text()) {
             Ok(value) => Ok(Self {
-                kind: IntegerKind::Decimal(node),
+                kind: IntegerKind::Decimal,
                 value,
+                range,
             }),
             Err(error) => Err(vec![crate::Error::ParseIntError { error, range }]),
         }
@@ -105,8 +102,9 @@ impl TryFrom<ast::IntegerHex> for Integer {
 
         match try_from_hexadecimal(token.text()) {
             Ok(value) => Ok(Self {
-                kind: IntegerKind::Hexadecimal(node),
+                kind: IntegerKind::Hexadecimal,
                 value,
+                range,
             }),
             Err(error) => Err(vec![crate::Error::ParseIntError { error, range }]),
         }
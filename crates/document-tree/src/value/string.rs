@@ -2,35 +2,38 @@ use toml_version::TomlVersion;
 
 use crate::TryIntoDocumentTree;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StringKind {
-    BasicString(ast::BasicString),
-    LiteralString(ast::LiteralString),
-    MultiLineBasicString(ast::MultiLineBasicString),
-    MultiLineLiteralString(ast::MultiLineLiteralString),
+    BasicString,
+    LiteralString,
+    MultiLineBasicString,
+    MultiLineLiteralString,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct String {
     kind: StringKind,
     value: std::string::String,
+    range: text::Range,
 }
 
 impl String {
     pub fn try_new(
         kind: StringKind,
         value: std::string::String,
+        range: text::Range,
         toml_version: TomlVersion,
     ) -> Result<Self, crate::support::string::ParseError> {
-        let string = Self { kind, value };
+        let string = Self { kind, value, range };
         string.try_to_raw_string(toml_version)?;
 
         Ok(string)
     }
 
     #[inline]
-    pub fn kind(&self) -> &StringKind {
-        &self.kind
+    pub fn kind(&self) -> StringKind {
+        self.kind
     }
 
     #[inline]
@@ -51,16 +54,16 @@ impl String {
         toml_version: TomlVersion,
     ) -> Result<std::string::String, crate::support::string::ParseError> {
         match self.kind {
-            StringKind::BasicString(_) => {
+            StringKind::BasicString => {
                 crate::support::string::try_from_basic_string(&self.value, toml_version)
             }
-            StringKind::LiteralString(_) => {
+            StringKind::LiteralString => {
                 crate::support::string::try_from_literal_string(&self.value)
             }
-            StringKind::MultiLineBasicString(_) => {
+            StringKind::MultiLineBasicString => {
                 crate::support::string::try_from_multi_line_basic_string(&self.value, toml_version)
             }
-            StringKind::MultiLineLiteralString(_) => {
+            StringKind::MultiLineLiteralString => {
                 crate::support::string::try_from_multi_line_literal_string(&self.value)
             }
         }
@@ -68,14 +71,7 @@ impl String {
 
     #[inline]
     pub fn range(&self) -> text::Range {
-        match self.kind() {
-            StringKind::BasicString(node) => node.token(),
-            StringKind::LiteralString(node) => node.token(),
-            StringKind::MultiLineBasicString(node) => node.token(),
-            StringKind::MultiLineLiteralString(node) => node.token(),
-        }
-        .unwrap()
-        .range()
+        self.range
     }
 
     #[inline]
@@ -92,8 +88,9 @@ impl TryIntoDocumentTree<String> for ast::BasicString {
         let token = self.token().unwrap();
 
         String::try_new(
-            StringKind::BasicString(self),
+            StringKind::BasicString,
             token.text().to_string(),
+            token.range(),
             toml_version,
         )
         .map_err(|error| {
@@ -113,8 +110,9 @@ impl TryIntoDocumentTree<String> for ast::LiteralString {
         let token = self.token().unwrap();
 
         String::try_new(
-            StringKind::LiteralString(self),
+            StringKind::LiteralString,
             token.text().to_string(),
+            token.range(),
             toml_version,
         )
         .map_err(|error| {
@@ -134,8 +132,9 @@ impl TryIntoDocumentTree<String> for ast::MultiLineBasicString {
         let token = self.token().unwrap();
 
         String::try_new(
-            StringKind::MultiLineBasicString(self),
+            StringKind::MultiLineBasicString,
             token.text().to_string(),
+            token.range(),
             toml_version,
         )
         .map_err(|error| {
@@ -155,8 +154,9 @@ impl TryIntoDocumentTree<String> for ast::MultiLineLiteralString {
         let token = self.token().unwrap();
 
         String::try_new(
-            StringKind::MultiLineLiteralString(self),
+            StringKind::MultiLineLiteralString,
             token.text().to_string(),
+            token.range(),
             toml_version,
         )
         .map_err(|error| {
@@ -0,0 +1,331 @@
+use std::ops::Range;
+
+use diagnostic::TextEdit;
+
+/// The maximum edit distance of the lines computed exactly.
+///
+/// Beyond it, the changed lines are replaced as a whole,
+/// which keeps the diff of a completely rewritten file fast.
+const MAX_EDIT_DISTANCE: usize = 1000;
+
+/// The number of the unchanged lines shown around the changes of a unified diff.
+const CONTEXT_LINES: usize = 3;
+
+/// A run of changed lines, replacing `old` lines with `new` lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hunk {
+    old: Range<usize>,
+    new: Range<usize>,
+}
+
+/// Compute the edits turning `old` into `new`, which replace only the changed lines.
+///
+/// The edits are sorted by their ranges and do not overlap.
+pub fn text_edits(old: &str, new: &str) -> Vec<TextEdit> {
+    let old_lines = old.split_inclusive('\n').collect::<Vec<_>>();
+    let new_lines = new.split_inclusive('\n').collect::<Vec<_>>();
+
+    diff_lines(&old_lines, &new_lines)
+        .into_iter()
+        .map(|hunk| {
+            TextEdit::new(
+                text::Range::new(
+                    line_start(&old_lines, hunk.old.start),
+                    line_start(&old_lines, hunk.old.end),
+                ),
+                new_lines[hunk.new].concat(),
+            )
+        })
+        .collect()
+}
+
+/// Make the unified diff turning `old` into `new`, or an empty string if they are the same.
+pub fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    let old_lines = old.split_inclusive('\n').collect::<Vec<_>>();
+    let new_lines = new.split_inclusive('\n').collect::<Vec<_>>();
+
+    let hunks = diff_lines(&old_lines, &new_lines);
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let mut diff = format!("--- {old_name}\n+++ {new_name}\n");
+
+    let mut groups: Vec<Vec<Hunk>> = vec![];
+    for hunk in hunks {
+        match groups.last_mut() {
+            Some(group) if hunk.old.start - group.last().unwrap().old.end <= 2 * CONTEXT_LINES => {
+                group.push(hunk)
+            }
+            _ => groups.push(vec![hunk]),
+        }
+    }
+
+    for group in groups {
+        let first = group.first().unwrap();
+        let last = group.last().unwrap();
+        let context_before = first.old.start.min(CONTEXT_LINES);
+        let context_after = (old_lines.len() - last.old.end).min(CONTEXT_LINES);
+        let old_range = first.old.start - context_before..last.old.end + context_after;
+        let new_range = first.new.start - context_before..last.new.end + context_after;
+
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(&old_range),
+            hunk_range(&new_range)
+        ));
+
+        let mut old_index = old_range.start;
+        for hunk in &group {
+            for line in &old_lines[old_index..hunk.old.start] {
+                push_line(&mut diff, ' ', line);
+            }
+            for line in &old_lines[hunk.old.clone()] {
+                push_line(&mut diff, '-', line);
+            }
+            for line in &new_lines[hunk.new.clone()] {
+                push_line(&mut diff, '+', line);
+            }
+            old_index = hunk.old.end;
+        }
+        for line in &old_lines[old_index..old_range.end] {
+            push_line(&mut diff, ' ', line);
+        }
+    }
+
+    diff
+}
+
+/// The `start,count` of the hunk header, where `start` is 1-based,
+/// or the line before the hunk if the hunk is empty.
+fn hunk_range(range: &Range<usize>) -> String {
+    let start = if range.is_empty() {
+        range.start
+    } else {
+        range.start + 1
+    };
+    format!("{start},{}", range.len())
+}
+
+fn push_line(diff: &mut String, prefix: char, line: &str) {
+    diff.push(prefix);
+    diff.push_str(line);
+    if !line.ends_with('\n') {
+        diff.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// The position of the start of the line, or the end of the text for the line after the last.
+fn line_start(lines: &[&str], line: usize) -> text::Position {
+    match lines.get(line.wrapping_sub(1)) {
+        Some(last_line) if line == lines.len() && !last_line.ends_with('\n') => {
+            text::Position::new(line as u32 - 1, last_line.chars().count() as u32)
+        }
+        _ => text::Position::new(line as u32, 0),
+    }
+}
+
+/// Compute the hunks of the changed lines with the Myers' diff algorithm.
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<Hunk> {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let old = &old[prefix..old.len() - suffix];
+    let new = &new[prefix..new.len() - suffix];
+
+    if old.is_empty() && new.is_empty() {
+        return vec![];
+    }
+
+    let Some(path) = shortest_edit_path(old, new) else {
+        return vec![Hunk {
+            old: prefix..prefix + old.len(),
+            new: prefix..prefix + new.len(),
+        }];
+    };
+
+    let mut hunks: Vec<Hunk> = vec![];
+    for window in path.windows(2) {
+        let ((x0, y0), (x1, y1)) = (window[0], window[1]);
+        if x1 - x0 == 1 && y1 - y0 == 1 {
+            continue;
+        }
+        match hunks.last_mut() {
+            Some(hunk) if hunk.old.end == prefix + x0 && hunk.new.end == prefix + y0 => {
+                hunk.old.end = prefix + x1;
+                hunk.new.end = prefix + y1;
+            }
+            _ => hunks.push(Hunk {
+                old: prefix + x0..prefix + x1,
+                new: prefix + y0..prefix + y1,
+            }),
+        }
+    }
+
+    hunks
+}
+
+/// Find the points of the shortest edit path from `(0, 0)` to `(old.len(), new.len())`,
+/// where each step is a deletion, an insertion or a diagonal move over the same line.
+///
+/// Returns `None` if the edit distance exceeds [`MAX_EDIT_DISTANCE`].
+fn shortest_edit_path(old: &[&str], new: &[&str]) -> Option<Vec<(usize, usize)>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * max as usize + 3];
+    let mut trace: Vec<Vec<isize>> = vec![];
+
+    'search: for d in 0..=max {
+        if d as usize > MAX_EDIT_DISTANCE {
+            return None;
+        }
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+
+        for k in (-d..=d).step_by(2) {
+            let index = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+                v[index + 1]
+            } else {
+                v[index - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index] = x;
+
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut path = vec![(n as usize, m as usize)];
+    let (mut x, mut y) = (n, m);
+    for (d, snapshot) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let get = |k: isize| snapshot[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 { 0 } else { get(prev_k) };
+        let prev_y = if d == 0 { 0 } else { prev_x - prev_k };
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            path.push((x as usize, y as usize));
+        }
+        if d > 0 {
+            x = prev_x;
+            y = prev_y;
+            path.push((x as usize, y as usize));
+        }
+    }
+    path.reverse();
+
+    Some(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    fn apply(source: &str, edits: &[TextEdit]) -> String {
+        let lines = source.split_inclusive('\n').collect::<Vec<_>>();
+        let offset = |position: text::Position| -> usize {
+            lines[..position.line() as usize]
+                .iter()
+                .map(|line| line.len())
+                .sum::<usize>()
+                + lines
+                    .get(position.line() as usize)
+                    .map(|line| {
+                        line.chars()
+                            .take(position.column() as usize)
+                            .map(char::len_utf8)
+                            .sum::<usize>()
+                    })
+                    .unwrap_or_default()
+        };
+
+        let mut result = source.to_string();
+        for edit in edits.iter().rev() {
+            result.replace_range(
+                offset(edit.range().start())..offset(edit.range().end()),
+                edit.new_text(),
+            );
+        }
+        result
+    }
+
+    #[rstest]
+    #[case("a = 1\n", "a = 1\n", vec![])]
+    #[case("a=1\nb = 2\nc=3\n", "a = 1\nb = 2\nc = 3\n", vec![
+        TextEdit::new(((0, 0), (1, 0)).into(), "a = 1\n"),
+        TextEdit::new(((2, 0), (3, 0)).into(), "c = 3\n"),
+    ])]
+    #[case("a = 1\n\n\nb = 2\n", "a = 1\n\nb = 2\n", vec![TextEdit::new(((2, 0), (3, 0)).into(), "")])]
+    #[case("[a]\nb = 1", "[a]\nb = 1\n", vec![TextEdit::new(((1, 0), (1, 5)).into(), "b = 1\n")])]
+    #[case("k = \"あ\"\nb=2", "k = \"あ\"\nb = 2\n", vec![TextEdit::new(((1, 0), (1, 3)).into(), "b = 2\n")])]
+    fn diff_text_edits(#[case] old: &str, #[case] new: &str, #[case] expected: Vec<TextEdit>) {
+        let edits = text_edits(old, new);
+        pretty_assertions::assert_eq!(edits, expected);
+        pretty_assertions::assert_eq!(apply(old, &edits), new);
+    }
+
+    #[rstest]
+    #[case("a\nb\nc\nd\n", "b\nc\nd\ne\n")]
+    #[case("a\nb\nc\n", "")]
+    #[case("", "a\nb\n")]
+    #[case("a\nx\nb\ny\nc\n", "a\nb\nz\nc\nw\n")]
+    #[case("1\n2\n3\n4\n5\n6\n7\n8\n9\n", "1\n2\n3\n4\n5\n6\n7\n8\n9\n0\n")]
+    fn diff_text_edits_apply(#[case] old: &str, #[case] new: &str) {
+        pretty_assertions::assert_eq!(apply(old, &text_edits(old, new)), new);
+    }
+
+    #[test]
+    fn diff_unified() {
+        let old = "a=1\nb = 2\nc = 3\nd = 4\ne = 5\nf = 6\ng = 7\nh = 8\ni = 9\nj=10";
+        let new = "a = 1\nb = 2\nc = 3\nd = 4\ne = 5\nf = 6\ng = 7\nh = 8\ni = 9\nj = 10\n";
+
+        pretty_assertions::assert_eq!(
+            unified_diff(old, new, "a/tombi.toml", "b/tombi.toml"),
+            textwrap::dedent(
+                r#"
+                --- a/tombi.toml
+                +++ b/tombi.toml
+                @@ -1,4 +1,4 @@
+                -a=1
+                +a = 1
+                 b = 2
+                 c = 3
+                 d = 4
+                @@ -7,4 +7,4 @@
+                 g = 7
+                 h = 8
+                 i = 9
+                -j=10
+                \ No newline at end of file
+                +j = 10
+                "#
+            )
+            .trim_start()
+        );
+        assert_eq!(unified_diff(new, new, "a", "b"), "");
+    }
+}
@@ -113,7 +113,8 @@ impl Format for LeadingComment {
 impl Format for TailingComment {
     #[inline]
     fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", f.defs().tailing_comment_space())?;
+        let space = f.take_tailing_comment_space();
+        write!(f, "{space}")?;
         self.as_ref().fmt(f)
     }
 }
@@ -121,6 +122,7 @@ impl Format for TailingComment {
 #[cfg(test)]
 mod tests {
     use crate::test_format;
+    use config::{FormatOptions, TomlVersion};
 
     test_format! {
         #[test]
@@ -141,4 +143,16 @@ mod tests {
         #[test]
         fn only_space_comment2(r"#      ") -> Ok(r"#");
     }
+
+    test_format! {
+        #[test]
+        fn tailing_comment_space(
+            r#"key = "value"  # comment"#,
+            TomlVersion::default(),
+            FormatOptions {
+                trailing_comment_space: Some(1.try_into().unwrap()),
+                ..Default::default()
+            }
+        ) -> Ok(r#"key = "value" # comment"#);
+    }
 }
@@ -1,6 +1,7 @@
 use ast::AstNode;
 use itertools::Itertools;
 
+use super::value::normalize_key;
 use crate::Format;
 use std::fmt::Write;
 
@@ -8,7 +9,7 @@ impl Format for ast::Keys {
     fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
         let keys = self
             .keys()
-            .map(|key| key.syntax().text().to_string())
+            .map(|key| normalize_key(f, &key))
             .collect_vec()
             .join(".");
 
@@ -9,15 +9,23 @@ impl Format for ast::KeyValue {
             comment.fmt(f)?;
         }
 
+        let alignment = f.key_value_alignment(self);
+
         f.write_indent()?;
         self.keys().unwrap().fmt(f)?;
 
-        write!(f, " = ")?;
+        write!(f, "{} = ", " ".repeat(alignment.equals_padding))?;
+        let outer_comment_space = f.set_tailing_comment_space(alignment.comment_space);
 
         f.skip_indent();
-        self.value().unwrap().fmt(f)?;
+        let accessors = f.key_value_accessors(self);
+        let result = f.with_accessors(accessors, |f| self.value().unwrap().fmt(f));
 
-        // NOTE: tailing comment is output by `value.fmt(f)`.
+        // NOTE: tailing comment is output by `value.fmt(f)`,
+        //       and the key-values of an inline table are formatted inside of it,
+        //       so the space of the outer key-value is restored for its tailing comment.
+        f.set_tailing_comment_space(outer_comment_space);
+        result?;
 
         Ok(())
     }
@@ -26,6 +34,7 @@ impl Format for ast::KeyValue {
 #[cfg(test)]
 mod tests {
     use crate::test_format;
+    use config::{FormatOptions, TomlVersion};
 
     test_format! {
         #[test]
@@ -53,4 +62,152 @@ mod tests {
             "#
         ) -> Ok(source);
     }
+
+    test_format! {
+        #[test]
+        fn key_value_align_equals_and_comments(
+            r#"
+            name = "tombi"  # name
+            version = "0.1.0"  # version
+            # leading comment
+            a = 1
+            long_key = [1, 2]  # long key
+            array = [
+              1,
+            ]
+            b.c = true
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                align_equals: Some(true),
+                align_comments: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            name    = "tombi"  # name
+            version = "0.1.0"  # version
+            # leading comment
+            a        = 1
+            long_key = [1, 2]  # long key
+            array = [
+              1,
+            ]
+            b.c = true
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn key_value_align_comments_only(
+            r#"
+            a = 1  # a
+            long_key = "value"  # long key
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                align_comments: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            a = 1               # a
+            long_key = "value"  # long key
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn key_value_align_exceeds_line_width(
+            r#"
+            a = 1  # a comment long
+            long_key = 2
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                align_equals: Some(true),
+                align_comments: Some(true),
+                line_width: Some(25.try_into().unwrap()),
+                ..Default::default()
+            }
+        ) -> Ok(source);
+    }
+
+    test_format! {
+        #[test]
+        fn key_value_align_comments_with_inline_table(
+            r#"
+            a = { b = 1 }  # c1
+            long_key = "a long value"  # c2
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                align_comments: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            a = { b = 1 }              # c1
+            long_key = "a long value"  # c2
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn key_value_align_blocks_delimited_by_blank_lines(
+            r#"
+            a = 1  # a
+            bb = 2  # bb
+
+
+            long_key = 3  # long key
+            c = 4
+
+            [table]
+            d = 5
+            long_key = 6
+
+            e = 7
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                align_equals: Some(true),
+                align_comments: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            a  = 1  # a
+            bb = 2  # bb
+
+            long_key = 3  # long key
+            c        = 4
+
+            [table]
+            d        = 5
+            long_key = 6
+
+            e = 7
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn key_value_blank_lines_removed_without_alignment(
+            r#"
+            a = 1
+
+            bb = 2
+            "#
+        ) -> Ok(
+            r#"
+            a = 1
+            bb = 2
+            "#
+        );
+    }
 }
@@ -1,20 +1,35 @@
 use super::Format;
+use config::KeyOrder;
 use itertools::Itertools;
-use std::fmt::Write;
+use schema_store::Accessor;
+use std::{collections::HashMap, fmt::Write};
 
 impl Format for ast::Root {
     fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
         f.reset();
 
-        let items = self.items().collect_vec();
+        let mut items = self.items().collect_vec();
         if !items.is_empty() {
             self.begin_dangling_comments().fmt(f)?;
 
+            sort_items(&mut items, f);
+            f.align_key_values(
+                &items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ast::RootItem::KeyValue(key_value) => Some(key_value.clone()),
+                        _ => None,
+                    })
+                    .collect_vec(),
+            )?;
+            let accessors = items_accessors(&items, f);
+
             items
                 .into_iter()
+                .zip(accessors)
                 .fold(
                     (Header::Root { key_value_size: 0 }, vec![]),
-                    |(mut header, mut acc), item| match &item {
+                    |(mut header, mut acc), (item, accessors)| match &item {
                         ast::RootItem::Table(table) => {
                             let header_keys = table.header().unwrap().keys();
                             let key_value_size = table.key_values().count();
@@ -44,7 +59,7 @@ impl Format for ast::Root {
                                     }
                                 }
                             };
-                            acc.push(ItemOrNewLine::Item(item));
+                            acc.push(ItemOrNewLine::Item(item, accessors));
 
                             (
                                 Header::Table {
@@ -93,7 +108,7 @@ impl Format for ast::Root {
                                     }
                                 }
                             };
-                            acc.push(ItemOrNewLine::Item(item));
+                            acc.push(ItemOrNewLine::Item(item, accessors));
 
                             (
                                 Header::ArrayOfTables {
@@ -104,7 +119,12 @@ impl Format for ast::Root {
                                 acc,
                             )
                         }
-                        ast::RootItem::KeyValue(_) => {
+                        ast::RootItem::KeyValue(key_value) => {
+                            if matches!(header, Header::Root { key_value_size } if key_value_size > 0)
+                                && f.is_after_blank_line(key_value)
+                            {
+                                acc.push(ItemOrNewLine::BlankLine);
+                            }
                             header = if let Header::Root { key_value_size } = header {
                                 Header::Root {
                                     key_value_size: key_value_size + 1,
@@ -112,7 +132,7 @@ impl Format for ast::Root {
                             } else {
                                 header
                             };
-                            acc.push(ItemOrNewLine::Item(item));
+                            acc.push(ItemOrNewLine::Item(item, accessors));
                             (header, acc)
                         }
                     },
@@ -121,8 +141,8 @@ impl Format for ast::Root {
                 .into_iter()
                 .enumerate()
                 .try_for_each(|(i, item)| {
-                    if i > 0 && matches!(item, ItemOrNewLine::Item(_)) {
-                        ItemOrNewLine::NewLine.fmt(f)?;
+                    if i > 0 && matches!(item, ItemOrNewLine::Item(..)) {
+                        write!(f, "{}", f.line_ending())?;
                     }
                     item.fmt(f)
                 })?;
@@ -147,15 +167,91 @@ impl Format for ast::RootItem {
 }
 
 enum ItemOrNewLine {
-    Item(ast::RootItem),
+    Item(ast::RootItem, Vec<Accessor>),
     NewLine,
+    BlankLine,
+}
+
+/// Sort the root items according to `key-order`.
+///
+/// The key-values are sorted among themselves, and stay before the tables.
+/// With the `schema` order, the tables are sorted by their first header key,
+/// keeping the order of the tables with the same first key, such as `[[bin]]` and `[bin.x]`.
+fn sort_items(items: &mut [ast::RootItem], f: &crate::Formatter) {
+    let key_value_size = items
+        .iter()
+        .take_while(|item| matches!(item, ast::RootItem::KeyValue(_)))
+        .count();
+    let (key_values, tables) = items.split_at_mut(key_value_size);
+
+    f.sort_key_values(key_values, |item| match item {
+        ast::RootItem::KeyValue(key_value) => key_value,
+        _ => unreachable!(),
+    });
+
+    if f.options().key_order() == KeyOrder::Schema {
+        f.sort_by_keys(tables, |item| {
+            let header = match item {
+                ast::RootItem::Table(table) => table.header(),
+                ast::RootItem::ArrayOfTables(array_of_tables) => array_of_tables.header(),
+                ast::RootItem::KeyValue(_) => None,
+            };
+            header
+                .and_then(|header| header.keys().next())
+                .map(|key| vec![f.key_text(&key)])
+                .unwrap_or_default()
+        });
+    }
+}
+
+/// Get the accessors of the tables defined by the root items.
+///
+/// The keys after an array of tables refer to its last table.
+fn items_accessors(items: &[ast::RootItem], f: &crate::Formatter) -> Vec<Vec<Accessor>> {
+    let mut array_of_tables_lengths: HashMap<Vec<Accessor>, usize> = HashMap::new();
+
+    items
+        .iter()
+        .map(|item| {
+            let (header, is_array_of_tables) = match item {
+                ast::RootItem::Table(table) => (table.header(), false),
+                ast::RootItem::ArrayOfTables(array_of_tables) => (array_of_tables.header(), true),
+                ast::RootItem::KeyValue(_) => return vec![],
+            };
+            let keys = header
+                .into_iter()
+                .flat_map(|header| header.keys())
+                .collect_vec();
+
+            let mut accessors = vec![];
+            for (i, key) in keys.iter().enumerate() {
+                accessors.push(Accessor::Key(f.key_text(key)));
+                if i + 1 == keys.len() && is_array_of_tables {
+                    let length = array_of_tables_lengths
+                        .entry(accessors.clone())
+                        .or_default();
+                    *length += 1;
+                    accessors.push(Accessor::Index(*length - 1));
+                } else if let Some(length) = array_of_tables_lengths.get(&accessors) {
+                    accessors.push(Accessor::Index(length - 1));
+                }
+            }
+            accessors
+        })
+        .collect()
 }
 
 impl Format for ItemOrNewLine {
     fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
         match self {
-            Self::Item(it) => it.fmt(f),
-            Self::NewLine => write!(f, "{}", f.line_ending()),
+            Self::Item(it, accessors) => f.with_root_accessors(accessors.clone(), |f| it.fmt(f)),
+            Self::NewLine => write!(
+                f,
+                "{}",
+                f.line_ending()
+                    .repeat(f.defs().blank_lines_between_tables() as usize)
+            ),
+            Self::BlankLine => write!(f, "{}", f.line_ending()),
         }
     }
 }
@@ -182,6 +278,7 @@ enum Header {
 #[cfg(test)]
 mod test {
     use crate::test_format;
+    use config::{FormatOptions, TomlVersion};
 
     test_format! {
         #[test]
@@ -363,4 +460,54 @@ mod test {
             "#
         ) -> Ok(source);
     }
+
+    test_format! {
+        #[test]
+        fn blank_lines_between_tables_zero(
+            r#"
+            key = 1
+
+            [foo]
+            a = 1
+
+            [[bar]]
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                blank_lines_between_tables: Some(0.into()),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            key = 1
+            [foo]
+            a = 1
+            [[bar]]
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn blank_lines_between_tables_two(
+            r#"
+            [foo]
+            a = 1
+            [foo.bar]
+            "#,
+            TomlVersion::default(),
+            FormatOptions {
+                blank_lines_between_tables: Some(2.into()),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            [foo]
+            a = 1
+
+
+            [foo.bar]
+            "#
+        );
+    }
 }
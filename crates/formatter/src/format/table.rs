@@ -16,7 +16,9 @@ impl Format for ast::Table {
             comment.fmt(f)?;
         }
 
-        let key_values = self.key_values().collect_vec();
+        let mut key_values = self.key_values().collect_vec();
+        f.sort_key_values(&mut key_values, |key_value| key_value);
+        f.align_key_values(&key_values)?;
 
         if key_values.is_empty() {
             let dangling_comments = self.dangling_comments();
@@ -35,6 +37,9 @@ impl Format for ast::Table {
             for (i, key_value) in key_values.into_iter().enumerate() {
                 if i != 0 {
                     write!(f, "{}", f.line_ending())?;
+                    if f.is_after_blank_line(&key_value) {
+                        write!(f, "{}", f.line_ending())?;
+                    }
                 }
                 key_value.fmt(f)?;
             }
@@ -6,7 +6,11 @@ mod inline_table;
 mod integer;
 mod string;
 
+pub(crate) use string::normalize_key;
+
 use crate::Format;
+use config::TomlVersion;
+use document_tree::TryIntoDocumentTree;
 use std::fmt::Write;
 use syntax::SyntaxToken;
 
@@ -35,6 +39,11 @@ impl Format for ast::Value {
 
 trait LiteralNode {
     fn token(&self) -> Option<SyntaxToken>;
+
+    /// The text written by the formatter, which is the source text by default.
+    fn format_text(&self, _f: &crate::Formatter) -> String {
+        self.token().unwrap().text().to_string()
+    }
 }
 
 impl<T> Format for T
@@ -46,8 +55,9 @@ where
             comment.fmt(f)?;
         }
 
+        let text = self.format_text(f);
         f.write_indent()?;
-        write!(f, "{}", self.token().unwrap())?;
+        write!(f, "{}", text)?;
 
         if let Some(comment) = self.tailing_comment() {
             comment.fmt(f)?;
@@ -56,3 +66,47 @@ where
         Ok(())
     }
 }
+
+/// Get the normalized text of a number or date-time,
+/// or the source text if the normalized text changes the value in the document tree.
+fn verify_value(text: &str, new_text: String, toml_version: TomlVersion) -> String {
+    if new_text == text || is_same_value(text, &new_text, toml_version) {
+        new_text
+    } else {
+        text.to_string()
+    }
+}
+
+fn is_same_value(text: &str, new_text: &str, toml_version: TomlVersion) -> bool {
+    use document_tree::Value;
+
+    match (
+        document_tree_value(text, toml_version),
+        document_tree_value(new_text, toml_version),
+    ) {
+        (Some(Value::Integer(old)), Some(Value::Integer(new))) => old.value() == new.value(),
+        (Some(Value::Float(old)), Some(Value::Float(new))) => {
+            old.value().to_bits() == new.value().to_bits()
+        }
+        (Some(Value::OffsetDateTime(old)), Some(Value::OffsetDateTime(new))) => {
+            old.value() == new.value() && old.value().offset() == new.value().offset()
+        }
+        (Some(Value::LocalDateTime(old)), Some(Value::LocalDateTime(new))) => {
+            old.value() == new.value()
+        }
+        (Some(Value::LocalDate(old)), Some(Value::LocalDate(new))) => old.value() == new.value(),
+        (Some(Value::LocalTime(old)), Some(Value::LocalTime(new))) => old.value() == new.value(),
+        _ => false,
+    }
+}
+
+fn document_tree_value(text: &str, toml_version: TomlVersion) -> Option<document_tree::Value> {
+    let root = parser::parse(&format!("key = {text}"), toml_version)
+        .try_cast::<ast::Root>()
+        .ok()?;
+    let Some(ast::RootItem::KeyValue(key_value)) = root.items().next() else {
+        return None;
+    };
+
+    key_value.value()?.try_into_document_tree(toml_version).ok()
+}
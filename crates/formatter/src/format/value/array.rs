@@ -1,11 +1,13 @@
 use crate::Format;
 use ast::AstNode;
+use config::TrailingComma;
 use itertools::Itertools;
+use schema_store::Accessor;
 use std::fmt::Write;
 
 impl Format for ast::Array {
     fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
-        if self.should_be_multiline(f.toml_version()) || exceeds_line_width(self, f)? {
+        if should_be_multiline(self, f) || exceeds_line_width(self, f)? {
             format_multiline_array(self, f)
         } else {
             format_singleline_array(self, f)
@@ -13,6 +15,36 @@ impl Format for ast::Array {
     }
 }
 
+/// Whether the array is formatted in multiple lines regardless of the line width.
+///
+/// Unlike [`ast::Array::should_be_multiline`], the trailing comma of the source
+/// does not make the array multi-line with `trailing-comma = "never"`,
+/// because the comma is removed and the next format would join the lines.
+pub(crate) fn should_be_multiline(array: &ast::Array, f: &crate::Formatter) -> bool {
+    if f.defs().multiline_array_trailing_comma() != TrailingComma::Never {
+        return array.should_be_multiline(f.toml_version());
+    }
+
+    array.has_inner_comments()
+        || array
+            .values()
+            .any(|value| should_be_multiline_value(&value, f))
+}
+
+/// Whether the value of an array or an inline table is formatted in multiple lines
+/// regardless of the line width.
+pub(crate) fn should_be_multiline_value(value: &ast::Value, f: &crate::Formatter) -> bool {
+    match value {
+        ast::Value::Array(array) => should_be_multiline(array, f),
+        ast::Value::InlineTable(table) => {
+            crate::format::value::inline_table::should_be_multiline(table, f)
+        }
+        ast::Value::MultiLineBasicString(string) => string.token().unwrap().text().contains('\n'),
+        ast::Value::MultiLineLiteralString(string) => string.token().unwrap().text().contains('\n'),
+        _ => false,
+    }
+}
+
 pub(crate) fn exceeds_line_width(
     node: &ast::Array,
     f: &mut crate::Formatter,
@@ -26,10 +58,10 @@ pub(crate) fn exceeds_line_width(
         // Check if nested value should be multiline
         let should_be_multiline = match &value {
             ast::Value::Array(array) => {
-                array.should_be_multiline(f.toml_version()) || exceeds_line_width(array, f)?
+                should_be_multiline(array, f) || exceeds_line_width(array, f)?
             }
             ast::Value::InlineTable(table) => {
-                table.should_be_multiline(f.toml_version())
+                crate::format::value::inline_table::should_be_multiline(table, f)
                     || crate::format::value::inline_table::exceeds_line_width(table, f)?
             }
             _ => false,
@@ -64,24 +96,32 @@ fn format_multiline_array(
 
     f.inc_indent();
 
-    let values_with_comma = array.values_with_comma().collect_vec();
+    let mut values_with_comma = array.values_with_comma().collect_vec();
+    f.sort_string_values(&mut values_with_comma, |(value, _)| value);
 
     if values_with_comma.is_empty() {
         array.inner_dangling_comments().fmt(f)?;
     } else {
         array.inner_begin_dangling_comments().fmt(f)?;
 
+        let last_index = values_with_comma.len() - 1;
         for (i, (value, comma)) in values_with_comma.into_iter().enumerate() {
             // value format
             {
                 if i > 0 {
                     write!(f, "{}", f.line_ending())?;
                 }
-                value.fmt(f)?;
+                f.with_accessors([Accessor::Index(i)], |f| value.fmt(f))?;
             }
 
             // comma format
             {
+                let has_comma = match f.defs().multiline_array_trailing_comma() {
+                    _ if i != last_index => true,
+                    TrailingComma::Always => true,
+                    TrailingComma::Never => false,
+                    TrailingComma::Preserve => comma.is_some(),
+                };
                 let (comma_leading_comments, comma_tailing_comment) = match comma {
                     Some(comma) => (
                         comma.leading_comments().collect_vec(),
@@ -97,11 +137,11 @@ fn format_multiline_array(
                     }
                     f.write_indent()?;
                     write!(f, ",")?;
-                } else if value.tailing_comment().is_some() {
-                    write!(f, "{}", f.line_ending())?;
-                    f.write_indent()?;
-                    write!(f, ",")?;
-                } else {
+                } else if has_comma || comma_tailing_comment.is_some() {
+                    if value.tailing_comment().is_some() {
+                        write!(f, "{}", f.line_ending())?;
+                        f.write_indent()?;
+                    }
                     write!(f, ",")?;
                 }
 
@@ -138,12 +178,15 @@ fn format_singleline_array(
     f.write_indent()?;
     write!(f, "[{}", f.defs().singleline_array_bracket_inner_space())?;
 
-    for (i, value) in array.values().enumerate() {
+    let mut values = array.values().collect_vec();
+    f.sort_string_values(&mut values, |value| value);
+
+    for (i, value) in values.into_iter().enumerate() {
         if i > 0 {
             write!(f, ",{}", f.defs().singleline_array_space_after_comma())?;
         }
         f.skip_indent();
-        value.fmt(f)?;
+        f.with_accessors([Accessor::Index(i)], |f| value.fmt(f))?;
     }
 
     write!(f, "{}]", f.defs().singleline_array_bracket_inner_space())?;
@@ -158,7 +201,7 @@ fn format_singleline_array(
 #[cfg(test)]
 mod tests {
     use crate::test_format;
-    use config::FormatOptions;
+    use config::{FormatOptions, TrailingComma};
 
     use super::*;
 
@@ -471,4 +514,109 @@ mod tests {
             "#
         );
     }
+
+    test_format! {
+        #[test]
+        fn array_sort_string_arrays(
+            r#"
+            features = ["std", 'derive', "alloc"]
+            escaped = ["\u0062", "a", 'c']
+            numbers = [3, 1, 2]
+            strings = [
+              # zeta leading comment
+              "zeta",
+              "alpha",  # alpha tailing comment
+            ]
+            "#,
+            Default::default(),
+            FormatOptions {
+                sort_string_arrays: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            features = ["alloc", 'derive', "std"]
+            escaped = ["a", "\u0062", 'c']
+            numbers = [3, 1, 2]
+            strings = [
+              "alpha",  # alpha tailing comment
+              # zeta leading comment
+              "zeta",
+            ]
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn array_bracket_space(
+            r#"array = [1, 2, 3]"#,
+            Default::default(),
+            FormatOptions {
+                array_bracket_space: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(r#"array = [ 1, 2, 3 ]"#);
+    }
+
+    test_format! {
+        #[test]
+        fn array_trailing_comma_never(
+            r#"
+            array = [
+              1111111111,
+              2222222222  # comment
+            ]
+            "#,
+            Default::default(),
+            FormatOptions {
+                line_width: Some(20.try_into().unwrap()),
+                trailing_comma: Some(TrailingComma::Never),
+                ..Default::default()
+            }
+        ) -> Ok(source);
+    }
+
+    test_format! {
+        #[test]
+        fn array_trailing_comma_preserve(
+            r#"
+            array = [
+              1111111111,
+              2222222222
+            ]
+            nested = [
+              [1, 2],
+            ]
+            "#,
+            Default::default(),
+            FormatOptions {
+                line_width: Some(20.try_into().unwrap()),
+                trailing_comma: Some(TrailingComma::Preserve),
+                ..Default::default()
+            }
+        ) -> Ok(source);
+    }
+
+    #[rstest]
+    #[case("d = [1, 2,]", "d = [1, 2]")]
+    #[case("d = [\n  1,\n  2,\n]", "d = [1, 2]")]
+    #[case("d = [[1, 2,], [3,]]", "d = [[1, 2], [3]]")]
+    #[case("d = { a = [1, 2,] }", "d = { a = [1, 2] }")]
+    #[case("d = [\n  1,\n  2,  # comment\n]", "d = [\n  1,\n  2,  # comment\n]")]
+    fn array_trailing_comma_never_idempotent(#[case] source: &str, #[case] expected: &str) {
+        let options = FormatOptions {
+            trailing_comma: Some(TrailingComma::Never),
+            ..Default::default()
+        };
+        let format = |source: &str| {
+            crate::Formatter::new(config::TomlVersion::V1_1_0_Preview, &options)
+                .format(source)
+                .unwrap()
+        };
+
+        let formatted = format(source);
+        pretty_assertions::assert_eq!(formatted, format!("{expected}\n"));
+        pretty_assertions::assert_eq!(format(&formatted), formatted);
+    }
 }
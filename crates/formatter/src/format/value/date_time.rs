@@ -1,42 +1,48 @@
-use crate::format::Format;
-use ast::AstNode;
-use std::fmt::Write;
-
-use super::LiteralNode;
-
-macro_rules! impl_date_time_format {
-    (impl Format for $type:ty;) => {
-        impl Format for $type {
-            fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
-                for comment in self.leading_comments() {
-                    comment.fmt(f)?;
-                }
-
-                let token = self.token().unwrap();
-                let mut text = token.text().to_string();
-                if let Some(delimiter) = f.date_time_delimiter() {
-                    text.replace_range(10..11, &delimiter.to_string());
-                }
-
-                f.write_indent()?;
-                write!(f, "{}", text)?;
-
-                if let Some(comment) = self.tailing_comment() {
-                    comment.fmt(f)?;
-                }
-
-                Ok(())
-            }
-        }
-    };
-}
+use config::{FractionalSeconds, UtcOffset};
+
+use super::{verify_value, LiteralNode};
+
+impl LiteralNode for ast::OffsetDateTime {
+    fn token(&self) -> Option<syntax::SyntaxToken> {
+        self.token()
+    }
 
-impl_date_time_format! {
-    impl Format for ast::OffsetDateTime;
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        let token = self.token().unwrap();
+        let text = token.text();
+        let Some(index) = text
+            .rfind(['Z', 'z', '+', '-'])
+            .filter(|index| *index > "YYYY-MM-DD".len())
+        else {
+            return text.to_string();
+        };
+        let (date_time, offset) = text.split_at(index);
+
+        let offset = match (f.options().utc_offset(), offset) {
+            (UtcOffset::Z, "z" | "+00:00") => "Z",
+            (UtcOffset::Numeric, "Z" | "z") => "+00:00",
+            _ => offset,
+        };
+
+        verify_value(
+            text,
+            format!("{}{offset}", normalize_date_time(f, date_time)),
+            f.toml_version(),
+        )
+    }
 }
 
-impl_date_time_format! {
-    impl Format for ast::LocalDateTime;
+impl LiteralNode for ast::LocalDateTime {
+    fn token(&self) -> Option<syntax::SyntaxToken> {
+        self.token()
+    }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        let token = self.token().unwrap();
+        let text = token.text();
+
+        verify_value(text, normalize_date_time(f, text), f.toml_version())
+    }
 }
 
 impl LiteralNode for ast::LocalDate {
@@ -49,11 +55,53 @@ impl LiteralNode for ast::LocalTime {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        let token = self.token().unwrap();
+        let text = token.text();
+
+        verify_value(
+            text,
+            normalize_fractional_seconds(text, f.options().fractional_seconds()),
+            f.toml_version(),
+        )
+    }
+}
+
+/// Normalize the delimiter and the fractional seconds of the date-time without the offset.
+fn normalize_date_time(f: &crate::Formatter, date_time: &str) -> String {
+    let (date, time) = date_time.split_at("YYYY-MM-DD".len());
+    let (delimiter, time) = time.split_at(1);
+
+    format!(
+        "{date}{}{}",
+        f.date_time_delimiter().unwrap_or(delimiter),
+        normalize_fractional_seconds(time, f.options().fractional_seconds())
+    )
+}
+
+/// Normalize the fractional seconds of the time, e.g. `07:32:00.500`.
+///
+/// The fractional seconds are never truncated.
+fn normalize_fractional_seconds(time: &str, fractional_seconds: FractionalSeconds) -> String {
+    let (seconds, fraction) = time.split_once('.').unwrap_or((time, ""));
+    if fractional_seconds == FractionalSeconds::Preserve || seconds.len() < "HH:MM:SS".len() {
+        return time.to_string();
+    }
+
+    let fraction = fraction.trim_end_matches('0');
+    match fractional_seconds.digits() {
+        None if fraction.is_empty() => seconds.to_string(),
+        None => format!("{seconds}.{fraction}"),
+        Some(digits) if fraction.len() <= digits => format!("{seconds}.{fraction:0<digits$}"),
+        Some(_) => time.to_string(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test_format;
+    use config::{FormatOptions, FractionalSeconds, UtcOffset};
 
     test_format! {
         #[test]
@@ -104,4 +152,86 @@ mod tests {
         #[test]
         fn valid_local_time_key_value2("lt2 = 00:32:00.999999") -> Ok(source);
     }
+
+    test_format! {
+        #[test]
+        fn offset_datetime_utc_offset_z(
+            r#"
+            odt1 = 1979-05-27T07:32:00+00:00
+            odt2 = 1979-05-27T07:32:00z
+            odt3 = 1979-05-27T07:32:00-00:00
+            odt4 = 1979-05-27T00:32:00-07:00
+            "#,
+            Default::default(),
+            FormatOptions {
+                utc_offset: Some(UtcOffset::Z),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            odt1 = 1979-05-27T07:32:00Z
+            odt2 = 1979-05-27T07:32:00Z
+            odt3 = 1979-05-27T07:32:00-00:00
+            odt4 = 1979-05-27T00:32:00-07:00
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn offset_datetime_utc_offset_numeric(
+            "odt = 1979-05-27 07:32:00.5Z",
+            Default::default(),
+            FormatOptions {
+                utc_offset: Some(UtcOffset::Numeric),
+                ..Default::default()
+            }
+        ) -> Ok("odt = 1979-05-27T07:32:00.5+00:00");
+    }
+
+    test_format! {
+        #[test]
+        fn fractional_seconds_trim(
+            r#"
+            odt = 1979-05-27T07:32:00.000Z
+            ldt = 1979-05-27T07:32:00.500
+            lt = 00:32:00.999990
+            "#,
+            Default::default(),
+            FormatOptions {
+                fractional_seconds: Some(FractionalSeconds::Trim),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            odt = 1979-05-27T07:32:00Z
+            ldt = 1979-05-27T07:32:00.5
+            lt = 00:32:00.99999
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn fractional_seconds_milliseconds(
+            r#"
+            odt = 1979-05-27T07:32:00-07:00
+            ldt = 1979-05-27T07:32:00.5
+            lt1 = 00:32:00.999000
+            lt2 = 00:32:00.999999
+            "#,
+            Default::default(),
+            FormatOptions {
+                fractional_seconds: Some(FractionalSeconds::Milliseconds),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            odt = 1979-05-27T07:32:00.000-07:00
+            ldt = 1979-05-27T07:32:00.500
+            lt1 = 00:32:00.999
+            lt2 = 00:32:00.999999
+            "#
+        );
+    }
 }
@@ -1,14 +1,82 @@
-use super::LiteralNode;
+use config::NumberUnderscores;
+
+use super::{integer::normalize_underscores, verify_value, LiteralNode};
 
 impl LiteralNode for ast::Float {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_float(f, self.token().unwrap().text())
+    }
+}
+
+/// Normalize the float according to `number-underscores` and `normalize-float-exponent`.
+fn normalize_float(f: &crate::Formatter, text: &str) -> String {
+    if text.ends_with("inf") || text.ends_with("nan") {
+        return text.to_string();
+    }
+    let number_underscores = f.options().number_underscores();
+
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (
+            &text[..index],
+            Some((&text[index..index + 1], &text[index + 1..])),
+        ),
+        None => (text, None),
+    };
+    let (sign, mantissa) = split_sign(mantissa);
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+
+    let mut new_text = format!(
+        "{sign}{}",
+        normalize_underscores(integer, number_underscores, 3)
+    );
+    if let Some(fraction) = fraction {
+        new_text.push('.');
+        match number_underscores {
+            NumberUnderscores::Remove => new_text.push_str(&fraction.replace('_', "")),
+            NumberUnderscores::Preserve | NumberUnderscores::Group => new_text.push_str(fraction),
+        }
+    }
+    if let Some((marker, exponent)) = exponent {
+        let exponent = match number_underscores {
+            NumberUnderscores::Remove => exponent.replace('_', ""),
+            NumberUnderscores::Preserve | NumberUnderscores::Group => exponent.to_string(),
+        };
+        if f.options().normalize_float_exponent() {
+            let (sign, digits) = split_sign(&exponent);
+            let digits = digits.trim_start_matches('0');
+            new_text.push('e');
+            if sign == "-" {
+                new_text.push('-');
+            }
+            new_text.push_str(if digits.is_empty() { "0" } else { digits });
+        } else {
+            new_text.push_str(marker);
+            new_text.push_str(&exponent);
+        }
+    }
+
+    verify_value(text, new_text, f.toml_version())
+}
+
+fn split_sign(text: &str) -> (&str, &str) {
+    if text.starts_with(['+', '-']) {
+        text.split_at(1)
+    } else {
+        ("", text)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test_format;
+    use config::{FormatOptions, NumberUnderscores};
 
     test_format! {
         #[test]
@@ -64,4 +132,54 @@ mod tests {
         #[test]
         fn invalid_key_value3("invalid_float_3 = 3.e+20") -> Err(_);
     }
+
+    test_format! {
+        #[test]
+        fn float_normalize_exponent(
+            r#"
+            flt1 = 1e06
+            flt2 = -2E-02
+            flt3 = 5e+22
+            flt4 = 6.626E00
+            flt5 = inf
+            "#,
+            Default::default(),
+            FormatOptions {
+                normalize_float_exponent: Some(true),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            flt1 = 1e6
+            flt2 = -2e-2
+            flt3 = 5e22
+            flt4 = 6.626e0
+            flt5 = inf
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn float_group_underscores(
+            "flt = -224617.445_991_228",
+            Default::default(),
+            FormatOptions {
+                number_underscores: Some(NumberUnderscores::Group),
+                ..Default::default()
+            }
+        ) -> Ok("flt = -224_617.445_991_228");
+    }
+
+    test_format! {
+        #[test]
+        fn float_remove_underscores(
+            "flt = 224_617.445_991_228e1_0",
+            Default::default(),
+            FormatOptions {
+                number_underscores: Some(NumberUnderscores::Remove),
+                ..Default::default()
+            }
+        ) -> Ok("flt = 224617.445991228e10");
+    }
 }
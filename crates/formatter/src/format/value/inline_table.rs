@@ -1,11 +1,12 @@
 use crate::Format;
 use ast::AstNode;
+use config::{TomlVersion, TrailingComma};
 use itertools::Itertools;
 use std::fmt::Write;
 
 impl Format for ast::InlineTable {
     fn fmt(&self, f: &mut crate::Formatter) -> Result<(), std::fmt::Error> {
-        if self.should_be_multiline(f.toml_version()) || exceeds_line_width(self, f)? {
+        if should_be_multiline(self, f) || exceeds_line_width(self, f)? {
             format_multiline_inline_table(self, f)
         } else {
             format_singleline_inline_table(self, f)
@@ -13,6 +14,28 @@ impl Format for ast::InlineTable {
     }
 }
 
+/// Whether the inline table is formatted in multiple lines regardless of the line width.
+///
+/// See [`crate::format::value::array::should_be_multiline`] for the arrays in it.
+pub(crate) fn should_be_multiline(table: &ast::InlineTable, f: &crate::Formatter) -> bool {
+    if f.defs().multiline_array_trailing_comma() != TrailingComma::Never {
+        return table.should_be_multiline(f.toml_version());
+    }
+
+    match f.toml_version() {
+        TomlVersion::V1_0_0 => false,
+        TomlVersion::V1_1_0_Preview => {
+            table.has_tailing_comma_after_last_value()
+                || table.has_inner_comments()
+                || table.key_values().any(|key_value| {
+                    key_value.value().is_some_and(|value| {
+                        crate::format::value::array::should_be_multiline_value(&value, f)
+                    })
+                })
+        }
+    }
+}
+
 pub(crate) fn exceeds_line_width(
     node: &ast::InlineTable,
     f: &mut crate::Formatter,
@@ -27,11 +50,11 @@ pub(crate) fn exceeds_line_width(
         if let Some(value) = key_value.value() {
             let should_be_multiline = match value {
                 ast::Value::Array(array) => {
-                    array.should_be_multiline(f.toml_version())
+                    crate::format::value::array::should_be_multiline(&array, f)
                         || crate::format::value::array::exceeds_line_width(&array, f)?
                 }
                 ast::Value::InlineTable(table) => {
-                    table.should_be_multiline(f.toml_version()) || exceeds_line_width(&table, f)?
+                    should_be_multiline(&table, f) || exceeds_line_width(&table, f)?
                 }
                 _ => false,
             };
@@ -65,7 +88,8 @@ fn format_multiline_inline_table(
 
     f.inc_indent();
 
-    let key_values_with_comma = table.key_values_with_comma().collect_vec();
+    let mut key_values_with_comma = table.key_values_with_comma().collect_vec();
+    f.sort_key_values(&mut key_values_with_comma, |(key_value, _)| key_value);
 
     if key_values_with_comma.is_empty() {
         table.inner_dangling_comments().fmt(f)?;
@@ -143,7 +167,10 @@ fn format_singleline_inline_table(
         f.defs().singleline_inline_table_brace_inner_space()
     )?;
 
-    for (i, key_value) in table.key_values().enumerate() {
+    let mut key_values = table.key_values().collect_vec();
+    f.sort_key_values(&mut key_values, |key_value| key_value);
+
+    for (i, key_value) in key_values.into_iter().enumerate() {
         if i > 0 {
             write!(
                 f,
@@ -281,4 +308,16 @@ mod tests {
             "#
         );
     }
+
+    test_format! {
+        #[test]
+        fn inline_table_without_brace_space(
+            r#"point = { x = 1, y = 2 }"#,
+            TomlVersion::default(),
+            FormatOptions {
+                inline_table_brace_space: Some(false),
+                ..Default::default()
+            }
+        ) -> Ok(r#"point = {x = 1, y = 2}"#);
+    }
 }
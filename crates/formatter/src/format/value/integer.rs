@@ -1,32 +1,99 @@
-use super::LiteralNode;
+use config::{HexDigitCase, NumberUnderscores};
+
+use super::{verify_value, LiteralNode};
 
 impl LiteralNode for ast::IntegerBin {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_integer(f, self.token().unwrap().text(), "0b", 4)
+    }
 }
 
 impl LiteralNode for ast::IntegerHex {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_integer(f, self.token().unwrap().text(), "0x", 4)
+    }
 }
 
 impl LiteralNode for ast::IntegerDec {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        let text = self.token().unwrap().text().to_string();
+        let sign = if text.starts_with(['+', '-']) {
+            &text[..1]
+        } else {
+            ""
+        };
+        normalize_integer(f, &text, sign, 3)
+    }
 }
 
 impl LiteralNode for ast::IntegerOct {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_integer(f, self.token().unwrap().text(), "0o", 3)
+    }
+}
+
+/// Normalize the digits after the prefix according to `number-underscores` and `hex-digit-case`.
+fn normalize_integer(f: &crate::Formatter, text: &str, prefix: &str, group_size: usize) -> String {
+    let Some(digits) = text.strip_prefix(prefix) else {
+        return text.to_string();
+    };
+
+    let mut digits = normalize_underscores(digits, f.options().number_underscores(), group_size);
+    if prefix == "0x" {
+        match f.options().hex_digit_case() {
+            HexDigitCase::Preserve => {}
+            HexDigitCase::Upper => digits = digits.to_ascii_uppercase(),
+            HexDigitCase::Lower => digits = digits.to_ascii_lowercase(),
+        }
+    }
+
+    verify_value(text, format!("{prefix}{digits}"), f.toml_version())
+}
+
+/// Remove or insert the underscores between the digits.
+pub(super) fn normalize_underscores(
+    digits: &str,
+    number_underscores: NumberUnderscores,
+    group_size: usize,
+) -> String {
+    let chars = digits.chars().filter(|c| *c != '_').collect::<Vec<_>>();
+    match number_underscores {
+        NumberUnderscores::Preserve => digits.to_string(),
+        NumberUnderscores::Remove => chars.into_iter().collect(),
+        NumberUnderscores::Group if chars.len() < 5 => chars.into_iter().collect(),
+        NumberUnderscores::Group => {
+            let mut output = String::with_capacity(chars.len() * 2);
+            for (i, c) in chars.iter().enumerate() {
+                if i > 0 && (chars.len() - i) % group_size == 0 {
+                    output.push('_');
+                }
+                output.push(*c);
+            }
+            output
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test_format;
+    use config::{FormatOptions, HexDigitCase, NumberUnderscores};
 
     test_format! {
         #[test]
@@ -102,4 +169,64 @@ mod tests {
         #[test]
         fn integer_dec_key_value8("int8 = 1_2_3_4_5") -> Ok(source);
     }
+
+    test_format! {
+        #[test]
+        fn integer_group_underscores(
+            r#"
+            int1 = 1000000
+            int2 = 53_49_221
+            int3 = 1000
+            hex = 0xdeadbeef
+            oct = 0o1234567
+            bin = 0b11010110
+            "#,
+            Default::default(),
+            FormatOptions {
+                number_underscores: Some(NumberUnderscores::Group),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            int1 = 1_000_000
+            int2 = 5_349_221
+            int3 = 1000
+            hex = 0xdead_beef
+            oct = 0o1_234_567
+            bin = 0b1101_0110
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn integer_remove_underscores(
+            r#"
+            int = -1_2_3_4_5
+            hex = 0xdead_beef
+            "#,
+            Default::default(),
+            FormatOptions {
+                number_underscores: Some(NumberUnderscores::Remove),
+                ..Default::default()
+            }
+        ) -> Ok(
+            r#"
+            int = -12345
+            hex = 0xdeadbeef
+            "#
+        );
+    }
+
+    test_format! {
+        #[test]
+        fn integer_hex_digit_case(
+            "hex = 0xdead_Beef",
+            Default::default(),
+            FormatOptions {
+                hex_digit_case: Some(HexDigitCase::Upper),
+                ..Default::default()
+            }
+        ) -> Ok("hex = 0xDEAD_BEEF");
+    }
 }
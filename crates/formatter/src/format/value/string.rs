@@ -1,32 +1,316 @@
+use ast::{
+    support::string::{
+        try_from_basic_string, try_from_literal_string, try_from_multi_line_basic_string,
+        try_from_multi_line_literal_string, ParseError,
+    },
+    AstNode,
+};
+use config::{MultiLineString, StringEscape, StringQuote, TomlVersion};
+
 use super::LiteralNode;
 
 impl LiteralNode for ast::BasicString {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_string(f, StringKind::Basic, self.token().unwrap().text())
+    }
 }
 
 impl LiteralNode for ast::MultiLineBasicString {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_string(f, StringKind::MultiLineBasic, self.token().unwrap().text())
+    }
 }
 
 impl LiteralNode for ast::LiteralString {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_string(f, StringKind::Literal, self.token().unwrap().text())
+    }
 }
 
 impl LiteralNode for ast::MultiLineLiteralString {
     fn token(&self) -> Option<syntax::SyntaxToken> {
         self.token()
     }
+
+    fn format_text(&self, f: &crate::Formatter) -> String {
+        normalize_string(
+            f,
+            StringKind::MultiLineLiteral,
+            self.token().unwrap().text(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StringKind {
+    Basic,
+    MultiLineBasic,
+    Literal,
+    MultiLineLiteral,
+}
+
+impl StringKind {
+    const fn new(is_literal: bool, is_multi_line: bool) -> Self {
+        match (is_literal, is_multi_line) {
+            (false, false) => Self::Basic,
+            (false, true) => Self::MultiLineBasic,
+            (true, false) => Self::Literal,
+            (true, true) => Self::MultiLineLiteral,
+        }
+    }
+
+    const fn is_literal(self) -> bool {
+        matches!(self, Self::Literal | Self::MultiLineLiteral)
+    }
+
+    const fn is_multi_line(self) -> bool {
+        matches!(self, Self::MultiLineBasic | Self::MultiLineLiteral)
+    }
+
+    fn parse(self, text: &str, toml_version: TomlVersion) -> Result<String, ParseError> {
+        match self {
+            Self::Basic => try_from_basic_string(text, toml_version),
+            Self::MultiLineBasic => try_from_multi_line_basic_string(text, toml_version),
+            Self::Literal => try_from_literal_string(text),
+            Self::MultiLineLiteral => try_from_multi_line_literal_string(text),
+        }
+    }
+}
+
+/// Get the text of the key normalized according to `unquote-keys`, `string-quote` and `string-escape`.
+pub(crate) fn normalize_key(f: &crate::Formatter, key: &ast::Key) -> String {
+    let (kind, text) = match key {
+        ast::Key::BareKey(_) => return key.syntax().text().to_string(),
+        ast::Key::BasicString(it) => (StringKind::Basic, it.token().unwrap().text().to_string()),
+        ast::Key::LiteralString(it) => {
+            (StringKind::Literal, it.token().unwrap().text().to_string())
+        }
+    };
+    let Ok(value) = kind.parse(&text, f.toml_version()) else {
+        return text;
+    };
+
+    if f.options().unquote_keys() && is_bare_key(&value, f.toml_version()) {
+        return value;
+    }
+
+    encode_string(f, kind, &text, &value, false)
+}
+
+/// Whether the value can be written as a bare key.
+///
+/// The key is parsed to check it, because a bare key like `1979-05-27` is lexed as a date.
+fn is_bare_key(value: &str, toml_version: TomlVersion) -> bool {
+    if value.is_empty()
+        || !value
+            .chars()
+            .all(|c| matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-'))
+    {
+        return false;
+    }
+
+    let Ok(root) = parser::parse(&format!("{value} = 0"), toml_version).try_cast::<ast::Root>()
+    else {
+        return false;
+    };
+    match root.items().next() {
+        Some(ast::RootItem::KeyValue(key_value)) => key_value
+            .keys()
+            .map(|keys| keys.keys().collect::<Vec<_>>())
+            .is_some_and(|keys| match keys.as_slice() {
+                [ast::Key::BareKey(key)] => key.syntax().text() == value,
+                _ => false,
+            }),
+        _ => false,
+    }
+}
+
+/// Get the text of the string normalized according to `multi-line-string`, `string-quote` and `string-escape`.
+fn normalize_string(f: &crate::Formatter, kind: StringKind, text: &str) -> String {
+    let Ok(value) = kind.parse(text, f.toml_version()) else {
+        return text.to_string();
+    };
+    if kind == StringKind::MultiLineBasic && has_line_ending_backslash(text) {
+        return text.to_string();
+    }
+
+    let is_multi_line = match f.options().multi_line_string() {
+        MultiLineString::Preserve => kind.is_multi_line(),
+        MultiLineString::Collapse => kind.is_multi_line() && value.contains(['\r', '\n']),
+        MultiLineString::Expand => kind.is_multi_line() || value.contains('\n'),
+    };
+
+    encode_string(f, kind, text, &value, is_multi_line)
+}
+
+/// Encode the value into the string preferred by the format options.
+///
+/// The source text is kept if the value cannot be encoded without changing it.
+fn encode_string(
+    f: &crate::Formatter,
+    kind: StringKind,
+    text: &str,
+    value: &str,
+    is_multi_line: bool,
+) -> String {
+    let string_escape = f.options().string_escape();
+
+    let mut is_literal = match f.options().string_quote() {
+        StringQuote::Preserve => kind.is_literal(),
+        StringQuote::Basic => kind.is_literal() && !needs_no_escape(value, '"', is_multi_line),
+        StringQuote::Literal => kind.is_literal() || needs_no_escape(value, '\'', is_multi_line),
+    };
+    if string_escape == StringEscape::Ascii && !value.is_ascii() {
+        is_literal = false;
+    }
+    if is_literal && !needs_no_escape(value, '\'', is_multi_line) {
+        return text.to_string();
+    }
+
+    let new_kind = StringKind::new(is_literal, is_multi_line);
+    if new_kind == kind && (is_literal || string_escape == StringEscape::Preserve) {
+        return text.to_string();
+    }
+
+    let new_body = match new_kind {
+        StringKind::Basic => escape(value, false, string_escape),
+        StringKind::MultiLineBasic => escape(value, true, string_escape),
+        StringKind::Literal | StringKind::MultiLineLiteral => value.to_string(),
+    };
+    if new_kind == kind && string_body(kind, text) == new_body {
+        return text.to_string();
+    }
+
+    // NOTE: The newline after the opening delimiter is trimmed from the value,
+    //       so it is added only for the values with newlines to keep them readable,
+    //       or kept if the source has it.
+    let has_newline = kind.is_multi_line() && string_body(kind, text).len() < text.len() - 6;
+    let newline = if value.contains('\n') || has_newline {
+        "\n"
+    } else {
+        ""
+    };
+    let new_text = match new_kind {
+        StringKind::Basic => format!("\"{new_body}\""),
+        StringKind::MultiLineBasic => format!("\"\"\"{newline}{new_body}\"\"\""),
+        StringKind::Literal => format!("'{new_body}'"),
+        StringKind::MultiLineLiteral => format!("'''{newline}{new_body}'''"),
+    };
+
+    // NOTE: Keep the source text if the value changes,
+    //       e.g. the value of a multi-line string starts with a newline.
+    match new_kind.parse(&new_text, f.toml_version()) {
+        Ok(new_value) if new_value == value => new_text,
+        _ => text.to_string(),
+    }
+}
+
+/// The text between the delimiters of the string,
+/// without the newline trimmed after the opening delimiter of a multi-line string.
+fn string_body(kind: StringKind, text: &str) -> &str {
+    if kind.is_multi_line() {
+        let body = &text[3..text.len() - 3];
+        body.strip_prefix("\r\n")
+            .or_else(|| body.strip_prefix('\n'))
+            .unwrap_or(body)
+    } else {
+        &text[1..text.len() - 1]
+    }
+}
+
+/// Whether the value can be written between the `quote`s without escaping.
+fn needs_no_escape(value: &str, quote: char, is_multi_line: bool) -> bool {
+    let has_quote = if is_multi_line {
+        value.contains(&quote.to_string().repeat(3)) || value.ends_with(quote)
+    } else {
+        value.contains(quote)
+    };
+
+    !has_quote
+        && (quote == '\'' || !value.contains('\\'))
+        && value.chars().all(|c| match c {
+            '\t' => true,
+            '\n' => is_multi_line,
+            c => !is_control_character(c),
+        })
+}
+
+/// Escape the value of a basic string.
+fn escape(value: &str, is_multi_line: bool, string_escape: StringEscape) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    let mut quotes = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            // NOTE: In a multi-line basic string, escape only the quote making `"""`
+            //       and the last quote, which would be a part of the closing delimiter.
+            '"' if is_multi_line && quotes < 2 && chars.peek().is_some() => {
+                output.push(c);
+                quotes += 1;
+                continue;
+            }
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\t' => output.push(c),
+            '\n' if is_multi_line => output.push(c),
+            '\r' if is_multi_line && chars.peek() == Some(&'\n') => output.push(c),
+            '\u{0008}' => output.push_str("\\b"),
+            '\n' => output.push_str("\\n"),
+            '\u{000C}' => output.push_str("\\f"),
+            '\r' => output.push_str("\\r"),
+            c if is_control_character(c) => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c if string_escape == StringEscape::Ascii && !c.is_ascii() => {
+                if c as u32 <= 0xFFFF {
+                    output.push_str(&format!("\\u{:04x}", c as u32));
+                } else {
+                    output.push_str(&format!("\\U{:08x}", c as u32));
+                }
+            }
+            c => output.push(c),
+        }
+        quotes = 0;
+    }
+
+    output
+}
+
+/// Whether the multi-line basic string has a line ending backslash,
+/// which trims the following whitespaces.
+fn has_line_ending_backslash(text: &str) -> bool {
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.next().is_some_and(char::is_whitespace) {
+            return true;
+        }
+    }
+    false
+}
+
+#[inline]
+const fn is_control_character(c: char) -> bool {
+    matches!(c, '\u{0000}'..='\u{001F}' | '\u{007F}')
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test_format;
+    use ast::AstNode;
+    use config::{FormatOptions, MultiLineString, StringEscape, StringQuote, TomlVersion};
+    use document_tree::TryIntoDocumentTree;
+    use rstest::rstest;
 
     test_format! {
         #[test]
@@ -37,4 +321,236 @@ mod tests {
         #[test]
         fn basic_string_key_value2(r#"key    = "value""#) -> Ok(r#"key = "value""#);
     }
+
+    /// Get the raw keys and values of the strings in the root table.
+    fn string_values(source: &str) -> Vec<(String, String)> {
+        let root =
+            ast::Root::cast(parser::parse(source, TomlVersion::default()).into_syntax_node())
+                .unwrap()
+                .try_into_document_tree(TomlVersion::default())
+                .unwrap();
+
+        root.key_values()
+            .iter()
+            .filter_map(|(key, value)| match value {
+                document_tree::Value::String(string) => Some((
+                    key.to_raw_text(TomlVersion::default()),
+                    string.to_raw_string(TomlVersion::default()),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case::quote_basic(
+        FormatOptions {
+            string_quote: Some(StringQuote::Basic),
+            ..Default::default()
+        },
+        r#"
+        a = 'value'
+        b = 'C:\Users'
+        c = '''
+        line1
+        line2'''
+        'd' = 1
+        "#,
+        r#"
+        a = "value"
+        b = 'C:\Users'
+        c = """
+        line1
+        line2"""
+        "d" = 1
+        "#
+    )]
+    #[case::quote_literal(
+        FormatOptions {
+            string_quote: Some(StringQuote::Literal),
+            ..Default::default()
+        },
+        r#"
+        a = "value"
+        b = "it's"
+        c = "\\d+"
+        "d" = 1
+        "#,
+        r#"
+        a = 'value'
+        b = "it's"
+        c = '\d+'
+        'd' = 1
+        "#
+    )]
+    #[case::collapse(
+        FormatOptions {
+            multi_line_string: Some(MultiLineString::Collapse),
+            ..Default::default()
+        },
+        r#"
+        a = """
+        value"""
+        b = '''
+        it's'''
+        c = """
+        line1
+        line2"""
+        "#,
+        r#"
+        a = "value"
+        b = '''
+        it's'''
+        c = """
+        line1
+        line2"""
+        "#
+    )]
+    #[case::expand(
+        FormatOptions {
+            multi_line_string: Some(MultiLineString::Expand),
+            ..Default::default()
+        },
+        r#"
+        a = "line1\nline2"
+        b = "\nline2"
+        c = "value"
+        "#,
+        r#"
+        a = """
+        line1
+        line2"""
+        b = "\nline2"
+        c = "value"
+        "#
+    )]
+    #[case::escape_unicode(
+        FormatOptions {
+            string_escape: Some(StringEscape::Unicode),
+            ..Default::default()
+        },
+        r#"
+        a = "caf\u00e9"
+        b = "\u0041\u000A\"\U0001F600"
+        c = """
+        a\u0022\u0022\u0022"""
+        d = """
+        The quick \
+          brown fox."""
+        "#,
+        r#"
+        a = "café"
+        b = "A\n\"😀"
+        c = """
+        a""\""""
+        d = """
+        The quick \
+          brown fox."""
+        "#
+    )]
+    #[case::escape_ascii(
+        FormatOptions {
+            string_escape: Some(StringEscape::Ascii),
+            ..Default::default()
+        },
+        r#"
+        a = "café"
+        b = 'ü'
+        c = "😀"
+        "#,
+        r#"
+        a = "caf\u00e9"
+        b = "\u00fc"
+        c = "\U0001f600"
+        "#
+    )]
+    #[case::unquote_keys(
+        FormatOptions {
+            unquote_keys: Some(true),
+            string_quote: Some(StringQuote::Basic),
+            ..Default::default()
+        },
+        r#"
+        "a" = 1
+        'b-c' = 2
+        "d.e" = 3
+        'f g' = 4
+        "" = 5
+        "1979-05-27" = 6
+        "#,
+        r#"
+        a = 1
+        b-c = 2
+        "d.e" = 3
+        "f g" = 4
+        "" = 5
+        "1979-05-27" = 6
+        "#
+    )]
+    fn normalize_string(
+        #[case] options: FormatOptions,
+        #[case] source: &str,
+        #[case] expected: &str,
+    ) {
+        let source = textwrap::dedent(source).trim_start().to_string();
+        let formatted = crate::Formatter::new(TomlVersion::default(), &options)
+            .format(&source)
+            .unwrap();
+
+        pretty_assertions::assert_eq!(formatted, textwrap::dedent(expected).trim_start());
+        pretty_assertions::assert_eq!(string_values(&formatted), string_values(&source));
+    }
+
+    #[rstest]
+    #[case::quote_basic(FormatOptions {
+        string_quote: Some(StringQuote::Basic),
+        ..Default::default()
+    })]
+    #[case::quote_literal(FormatOptions {
+        string_quote: Some(StringQuote::Literal),
+        ..Default::default()
+    })]
+    #[case::collapse(FormatOptions {
+        multi_line_string: Some(MultiLineString::Collapse),
+        ..Default::default()
+    })]
+    #[case::expand(FormatOptions {
+        multi_line_string: Some(MultiLineString::Expand),
+        ..Default::default()
+    })]
+    #[case::escape_unicode(FormatOptions {
+        string_escape: Some(StringEscape::Unicode),
+        ..Default::default()
+    })]
+    #[case::escape_ascii(FormatOptions {
+        string_escape: Some(StringEscape::Ascii),
+        ..Default::default()
+    })]
+    #[case::unquote_keys(FormatOptions {
+        unquote_keys: Some(true),
+        ..Default::default()
+    })]
+    fn normalize_string_idempotent(#[case] options: FormatOptions) {
+        let source = textwrap::dedent(
+            r#"
+            "a" = "value"
+            arr = ["b", """c"""]
+            x = """c"""
+            y = '''d'''
+            z = """
+            line1
+            line2"""
+            w = "caf\u00e9\nü"
+            "#,
+        );
+        let format = |source: &str| {
+            crate::Formatter::new(TomlVersion::default(), &options)
+                .format(source)
+                .unwrap()
+        };
+
+        let formatted = format(source.trim_start());
+        pretty_assertions::assert_eq!(format(&formatted), formatted);
+        pretty_assertions::assert_eq!(string_values(&formatted), string_values(&source));
+    }
 }
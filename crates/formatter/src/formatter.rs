@@ -1,10 +1,14 @@
+mod alignment;
 pub mod definitions;
+mod sort;
 
 use crate::Format;
+use ast::AstNode;
 use config::{DateTimeDelimiter, LineEnding, TomlVersion};
-use diagnostic::Diagnostic;
 use diagnostic::SetDiagnostics;
-use std::fmt::Write;
+use diagnostic::{Diagnostic, TextEdit};
+use std::{collections::HashMap, fmt::Write};
+use syntax::SyntaxKind::{LINE_BREAK, WHITESPACE};
 
 pub struct Formatter<'a> {
     toml_version: TomlVersion,
@@ -12,6 +16,12 @@ pub struct Formatter<'a> {
     skip_indent: bool,
     defs: crate::Definitions,
     options: &'a crate::FormatOptions,
+    schema: Option<&'a schema_store::DocumentSchema>,
+    /// The accessors of the table or value being formatted.
+    accessors: Vec<schema_store::Accessor>,
+    key_value_alignments: HashMap<ast::KeyValue, alignment::KeyValueAlignment>,
+    /// The space before the tailing comment of the key-value being formatted, if it is aligned.
+    tailing_comment_space: Option<usize>,
     buf: String,
 }
 
@@ -22,12 +32,24 @@ impl<'a> Formatter<'a> {
             toml_version,
             indent_depth: 0,
             skip_indent: false,
-            defs: Default::default(),
+            defs: crate::Definitions::new(options),
             options,
+            schema: None,
+            accessors: vec![],
+            key_value_alignments: HashMap::new(),
+            tailing_comment_space: None,
             buf: String::new(),
         }
     }
 
+    /// Set the schema of the document, which gives the order of the keys
+    /// when `key-order` is `schema`.
+    #[inline]
+    pub fn with_schema(mut self, schema: Option<&'a schema_store::DocumentSchema>) -> Self {
+        self.schema = schema;
+        self
+    }
+
     /// Format a node and return the result as a string
     pub(crate) fn format_to_string<T: Format>(
         &mut self,
@@ -70,6 +92,83 @@ impl<'a> Formatter<'a> {
         }
     }
 
+    /// Format only the top-level items overlapping the span of the source.
+    ///
+    /// With `align-equals` or `align-comments`, all the root key-values are formatted
+    /// if any of them overlaps the range, to align them with each other.
+    ///
+    /// Returns the edit replacing the items with their formatted text,
+    /// or `None` if no item overlaps the span or the items are already formatted.
+    pub fn format_range(
+        self,
+        source: &str,
+        span: text::Span,
+    ) -> Result<Option<TextEdit>, Vec<Diagnostic>> {
+        let root = match parser::parse(source, self.toml_version).try_cast::<ast::Root>() {
+            Ok(root) => root,
+            Err(errors) => {
+                let mut diagnostics = Vec::new();
+                for error in errors {
+                    error.set_diagnostic(&mut diagnostics);
+                }
+                return Err(diagnostics);
+            }
+        };
+
+        let items = root
+            .items()
+            .filter_map(|item| {
+                let mut tokens = item
+                    .syntax()
+                    .descendants_with_tokens()
+                    .filter_map(|element| element.into_token())
+                    .filter(|token| !matches!(token.kind(), WHITESPACE | LINE_BREAK));
+                let first = tokens.next()?;
+                let last = tokens.last().unwrap_or_else(|| first.clone());
+                let overlapping =
+                    first.span().start() <= span.end() && span.start() <= last.span().end();
+                Some((item, first, last, overlapping))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE: The root key-values are aligned with each other,
+        //       so all of them are formatted if any of them overlaps the range.
+        let align_root_key_values = (self.options().align_equals()
+            || self.options().align_comments())
+            && items.iter().any(|(item, _, _, overlapping)| {
+                *overlapping && matches!(item, ast::RootItem::KeyValue(_))
+            });
+
+        let overlapping_tokens = items
+            .into_iter()
+            .filter(|(item, _, _, overlapping)| {
+                *overlapping
+                    || (align_root_key_values && matches!(item, ast::RootItem::KeyValue(_)))
+            })
+            .map(|(_, first, last, _)| (first, last))
+            .collect::<Vec<_>>();
+
+        let (Some((first, _)), Some((_, last))) =
+            (overlapping_tokens.first(), overlapping_tokens.last())
+        else {
+            return Ok(None);
+        };
+
+        let line_ending = self.line_ending();
+        let old_text = &source[text::Span::new(first.span().start(), last.span().end())];
+        let formatted = self.format(old_text)?;
+        let new_text = formatted.strip_suffix(line_ending).unwrap_or(&formatted);
+
+        if new_text == old_text {
+            return Ok(None);
+        }
+
+        Ok(Some(TextEdit::new(
+            text::Range::new(first.range().start(), last.range().end()),
+            new_text,
+        )))
+    }
+
     #[inline]
     pub(crate) fn toml_version(&self) -> TomlVersion {
         self.toml_version
@@ -90,6 +189,23 @@ impl<'a> Formatter<'a> {
         self.options.line_width.unwrap_or_default().value()
     }
 
+    /// Set the space before the tailing comment, and returns the previous one
+    /// to be restored after the nested key-values of the value are formatted.
+    #[inline]
+    pub(crate) fn set_tailing_comment_space(&mut self, space: Option<usize>) -> Option<usize> {
+        std::mem::replace(&mut self.tailing_comment_space, space)
+    }
+
+    /// Returns the space before the tailing comment,
+    /// which is aligned only for the comment of the key-value being formatted.
+    #[inline]
+    pub(crate) fn take_tailing_comment_space(&mut self) -> String {
+        match self.tailing_comment_space.take() {
+            Some(space) => " ".repeat(space),
+            None => self.defs.tailing_comment_space(),
+        }
+    }
+
     #[inline]
     pub(crate) fn line_ending(&self) -> &'static str {
         match self.options.line_ending.unwrap_or_default() {
@@ -154,3 +270,86 @@ impl std::fmt::Write for Formatter<'_> {
         self.buf.write_str(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    fn span(source: &str, (start, end): ((u32, u32), (u32, u32))) -> text::Span {
+        text::Span::new(
+            text::Offset::from_position(source, start.into()),
+            text::Offset::from_position(source, end.into()),
+        )
+    }
+
+    #[rstest]
+    #[case::first_table(
+        "a=1\n\n[b]\nc=2\n\n[d]\ne=3\n",
+        ((2, 0), (2, 0)),
+        Some(TextEdit::new(((2, 0), (3, 3)).into(), "[b]\nc = 2"))
+    )]
+    #[case::tables(
+        "a=1\n\n[b]\nc=2\n\n[d]\ne  =  [1,2]  # comment\n",
+        ((3, 1), (5, 1)),
+        Some(TextEdit::new(((2, 0), (6, 22)).into(), "[b]\nc = 2\n\n[d]\ne = [1, 2]  # comment"))
+    )]
+    #[case::root_key_value(
+        "a=1\nb=2\n\n[c]\nd=3\n",
+        ((1, 0), (1, 3)),
+        Some(TextEdit::new(((1, 0), (1, 3)).into(), "b = 2"))
+    )]
+    #[case::leading_comment(
+        "# comment\n[a]\nb=1\n",
+        ((2, 0), (2, 0)),
+        Some(TextEdit::new(((0, 0), (2, 3)).into(), "# comment\n[a]\nb = 1"))
+    )]
+    #[case::after_multibyte_characters(
+        "s=\"🦀\"\nb=2\n\n[c]\nd=3\n",
+        ((1, 1), (1, 1)),
+        Some(TextEdit::new(((1, 0), (1, 3)).into(), "b = 2"))
+    )]
+    #[case::no_items("# comment\n\na = 1\n", ((0, 0), (0, 3)), None)]
+    #[case::formatted("a = 1\n\n[b]\nc=2\n", ((0, 0), (0, 0)), None)]
+    fn format_range(
+        #[case] source: &str,
+        #[case] range: ((u32, u32), (u32, u32)),
+        #[case] expected: Option<TextEdit>,
+    ) {
+        let options = crate::FormatOptions::default();
+        let result = Formatter::new(TomlVersion::default(), &options)
+            .format_range(source, span(source, range))
+            .unwrap();
+
+        pretty_assertions::assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case::root_key_value(
+        "a=1\nlong_key=2  # comment\n\n[c]\nd=3\n",
+        ((0, 0), (0, 3)),
+        Some(TextEdit::new(((0, 0), (1, 21)).into(), "a        = 1\nlong_key = 2  # comment"))
+    )]
+    #[case::aligned("a        = 1\nlong_key = 2\n", ((0, 0), (0, 12)), None)]
+    #[case::table(
+        "a = 1\n\n[b]\nc=1\nlong_key=2\n",
+        ((3, 0), (3, 3)),
+        Some(TextEdit::new(((2, 0), (4, 10)).into(), "[b]\nc        = 1\nlong_key = 2"))
+    )]
+    fn format_range_with_alignment(
+        #[case] source: &str,
+        #[case] range: ((u32, u32), (u32, u32)),
+        #[case] expected: Option<TextEdit>,
+    ) {
+        let options = crate::FormatOptions {
+            align_equals: Some(true),
+            align_comments: Some(true),
+            ..Default::default()
+        };
+        let result = Formatter::new(TomlVersion::default(), &options)
+            .format_range(source, span(source, range))
+            .unwrap();
+
+        pretty_assertions::assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,168 @@
+use ast::AstNode;
+use syntax::SyntaxKind::{LINE_BREAK, WHITESPACE};
+
+/// The padding of a key-value aligned with the key-values around it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct KeyValueAlignment {
+    /// The number of the spaces inserted between the keys and ` = `.
+    pub equals_padding: usize,
+
+    /// The number of the spaces before the tailing comment, if it is aligned.
+    pub comment_space: Option<usize>,
+}
+
+/// The widths of a single-line key-value formatted without alignment.
+struct KeyValueWidth {
+    key_value: ast::KeyValue,
+    keys: usize,
+    /// The width of the line without the tailing comment.
+    code: usize,
+    /// The width of the tailing comment, without the space before it.
+    comment: Option<usize>,
+}
+
+impl crate::Formatter<'_> {
+    /// Compute the alignments of the key-values of a table,
+    /// which are used when the key-values are formatted.
+    ///
+    /// The key-values are split into blocks by blank lines, comments and multi-line key-values,
+    /// and a block is aligned only if none of its lines exceeds `line-width`.
+    pub(crate) fn align_key_values(
+        &mut self,
+        key_values: &[ast::KeyValue],
+    ) -> Result<(), std::fmt::Error> {
+        if !self.options().align_equals() && !self.options().align_comments() {
+            return Ok(());
+        }
+
+        let mut blocks: Vec<Vec<KeyValueWidth>> = vec![vec![]];
+        for key_value in key_values {
+            let leading_comments_size = key_value.leading_comments().count();
+            let text = self.format_to_string(key_value)?;
+            let lines = text.split(self.line_ending()).collect::<Vec<_>>();
+
+            if lines.len() != leading_comments_size + 1 {
+                blocks.push(vec![]);
+                continue;
+            }
+            if leading_comments_size > 0 || self.is_after_blank_line(key_value) {
+                blocks.push(vec![]);
+            }
+
+            let line = lines.last().unwrap().chars().count();
+            let comment = match key_value.tailing_comment() {
+                Some(comment) => Some(self.format_to_string(comment.as_ref())?.chars().count()),
+                None => None,
+            };
+            let keys = match key_value.keys() {
+                Some(keys) => self.format_to_string(&keys)?.chars().count(),
+                None => 0,
+            };
+
+            blocks.last_mut().unwrap().push(KeyValueWidth {
+                key_value: key_value.clone(),
+                keys,
+                code: line
+                    - comment
+                        .map(|comment| comment + self.defs().tailing_comment_space().len())
+                        .unwrap_or_default(),
+                comment,
+            });
+        }
+
+        for block in blocks.into_iter().filter(|block| block.len() > 1) {
+            for (key_value, alignment) in self.align_block(&block) {
+                self.key_value_alignments.insert(key_value, alignment);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the key-value follows a blank line, which is kept when the key-values are aligned.
+    pub(crate) fn is_after_blank_line(&self, key_value: &ast::KeyValue) -> bool {
+        if !self.options().align_equals() && !self.options().align_comments() {
+            return false;
+        }
+
+        key_value
+            .syntax()
+            .children_with_tokens()
+            .take_while(|node_or_token| matches!(node_or_token.kind(), LINE_BREAK | WHITESPACE))
+            .filter(|node_or_token| node_or_token.kind() == LINE_BREAK)
+            .count()
+            > 1
+    }
+
+    #[inline]
+    pub(crate) fn key_value_alignment(&self, key_value: &ast::KeyValue) -> KeyValueAlignment {
+        self.key_value_alignments
+            .get(key_value)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn align_block(&self, block: &[KeyValueWidth]) -> Vec<(ast::KeyValue, KeyValueAlignment)> {
+        let line_width = self.line_width() as usize;
+        let tailing_comment_space = self.defs().tailing_comment_space().len();
+        let line = |code: usize, comment: Option<usize>| {
+            code + comment
+                .map(|comment| tailing_comment_space + comment)
+                .unwrap_or_default()
+        };
+
+        let keys_width = block
+            .iter()
+            .map(|width| width.keys)
+            .max()
+            .unwrap_or_default();
+        let mut equals_paddings = block
+            .iter()
+            .map(|width| {
+                if self.options().align_equals() {
+                    keys_width - width.keys
+                } else {
+                    0
+                }
+            })
+            .collect::<Vec<_>>();
+        if block.iter().zip(&equals_paddings).any(|(width, padding)| {
+            *padding > 0 && line(width.code + padding, width.comment) > line_width
+        }) {
+            equals_paddings.fill(0);
+        }
+
+        let comment_column = block
+            .iter()
+            .zip(&equals_paddings)
+            .filter(|(width, _)| width.comment.is_some())
+            .map(|(width, padding)| width.code + padding)
+            .max();
+        let align_comments = self.options().align_comments()
+            && block.iter().zip(&equals_paddings).all(|(width, padding)| {
+                comment_column.is_none_or(|column| {
+                    column == width.code + padding || line(column, width.comment) <= line_width
+                })
+            });
+
+        block
+            .iter()
+            .zip(equals_paddings)
+            .map(|(width, equals_padding)| {
+                let comment_space = match (width.comment, comment_column) {
+                    (Some(_), Some(column)) if align_comments => {
+                        Some(column - (width.code + equals_padding) + tailing_comment_space)
+                    }
+                    _ => None,
+                };
+                (
+                    width.key_value.clone(),
+                    KeyValueAlignment {
+                        equals_padding,
+                        comment_space,
+                    },
+                )
+            })
+            .collect()
+    }
+}
@@ -1,10 +1,32 @@
-/// Definitions provides the definition of the format that does not have the freedom set by [`FormatOptions`][crate::FormatOptions].
-///
-/// NOTE: Some of the items defined in Definitions may be moved to [`FormatOptions`][crate::FormatOptions] in the future.
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Definitions {}
+use config::TrailingComma;
+
+/// Definitions provides the definition of the format, resolved from [`FormatOptions`][crate::FormatOptions].
+#[derive(Debug, Clone, Copy)]
+pub struct Definitions {
+    array_bracket_space: bool,
+    inline_table_brace_space: bool,
+    tailing_comment_space: u8,
+    trailing_comma: TrailingComma,
+    blank_lines_between_tables: u8,
+}
+
+impl Default for Definitions {
+    fn default() -> Self {
+        Self::new(&crate::FormatOptions::default())
+    }
+}
 
 impl Definitions {
+    pub fn new(options: &crate::FormatOptions) -> Self {
+        Self {
+            array_bracket_space: options.array_bracket_space(),
+            inline_table_brace_space: options.inline_table_brace_space(),
+            tailing_comment_space: options.trailing_comment_space().value(),
+            trailing_comma: options.trailing_comma(),
+            blank_lines_between_tables: options.blank_lines_between_tables().value(),
+        }
+    }
+
     /// Returns the space before the tailing comment.
     ///
     /// ```toml
@@ -12,8 +34,8 @@ impl Definitions {
     /// #            ^^  <- this
     /// ```
     #[inline]
-    pub const fn tailing_comment_space(&self) -> &'static str {
-        "  "
+    pub fn tailing_comment_space(&self) -> String {
+        " ".repeat(self.tailing_comment_space as usize)
     }
 
     /// Returns the space inside the brackets of an array.
@@ -23,7 +45,11 @@ impl Definitions {
     /// #      ^       ^  <- this
     #[inline]
     pub const fn singleline_array_bracket_inner_space(&self) -> &'static str {
-        ""
+        if self.array_bracket_space {
+            " "
+        } else {
+            ""
+        }
     }
 
     /// Returns the space after the comma in an array.
@@ -44,7 +70,11 @@ impl Definitions {
     /// ```
     #[inline]
     pub const fn singleline_inline_table_brace_inner_space(&self) -> &'static str {
-        " "
+        if self.inline_table_brace_space {
+            " "
+        } else {
+            ""
+        }
     }
 
     /// Returns the space after the comma in an inline table.
@@ -57,4 +87,31 @@ impl Definitions {
     pub const fn singleline_inline_table_space_after_comma(&self) -> &'static str {
         " "
     }
+
+    /// Returns the policy of the comma after the last value of a multi-line array.
+    ///
+    /// ```toml
+    /// key = [
+    ///   1,
+    ///   2,
+    /// #  ^  <- this
+    /// ]
+    /// ```
+    #[inline]
+    pub const fn multiline_array_trailing_comma(&self) -> TrailingComma {
+        self.trailing_comma
+    }
+
+    /// Returns the number of the blank lines between tables.
+    ///
+    /// ```toml
+    /// [table1]
+    /// key = "value"
+    ///               <- this
+    /// [table2]
+    /// ```
+    #[inline]
+    pub const fn blank_lines_between_tables(&self) -> u8 {
+        self.blank_lines_between_tables
+    }
 }
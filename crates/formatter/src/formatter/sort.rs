@@ -0,0 +1,275 @@
+use ast::AstNode;
+use config::KeyOrder;
+use schema_store::Accessor;
+
+impl crate::Formatter<'_> {
+    /// Run `f` with the accessors of the table or value being formatted.
+    pub(crate) fn with_accessors<R>(
+        &mut self,
+        accessors: impl IntoIterator<Item = Accessor>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let depth = self.accessors.len();
+        self.accessors.extend(accessors);
+        let result = f(self);
+        self.accessors.truncate(depth);
+        result
+    }
+
+    /// Run `f` with the accessors of the root item, e.g. `[a.b]` or `[[a.b]]`.
+    pub(crate) fn with_root_accessors<R>(
+        &mut self,
+        accessors: Vec<Accessor>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let old_accessors = std::mem::replace(&mut self.accessors, accessors);
+        let result = f(self);
+        self.accessors = old_accessors;
+        result
+    }
+
+    /// Get the raw text of the key, or the source text if it is invalid.
+    pub(crate) fn key_text(&self, key: &ast::Key) -> String {
+        key.try_to_raw_text(self.toml_version())
+            .unwrap_or_else(|_| key.syntax().to_string())
+    }
+
+    /// Get the key accessors of the key-value, e.g. `a`, `b` of `a.b = 1`.
+    pub(crate) fn key_value_accessors(&self, key_value: &ast::KeyValue) -> Vec<Accessor> {
+        key_value
+            .keys()
+            .into_iter()
+            .flat_map(|keys| keys.keys())
+            .map(|key| Accessor::Key(self.key_text(&key)))
+            .collect()
+    }
+
+    /// Sort the items in the table being formatted according to `key-order`.
+    ///
+    /// The items with the same first key keep their order.
+    pub(crate) fn sort_by_keys<T>(&self, items: &mut [T], keys: impl Fn(&T) -> Vec<String>) {
+        match self.options().key_order() {
+            KeyOrder::Preserve => {}
+            KeyOrder::Alphabetical => items.sort_by_cached_key(|item| keys(item)),
+            KeyOrder::Schema => {
+                let Some(properties) = self.schema_properties() else {
+                    return;
+                };
+                items.sort_by_cached_key(|item| {
+                    let keys = keys(item);
+                    match keys
+                        .first()
+                        .and_then(|key| properties.iter().position(|property| property == key))
+                    {
+                        Some(index) => (index, vec![]),
+                        None => (properties.len(), keys),
+                    }
+                });
+            }
+        }
+    }
+
+    /// Sort the key-values in the table being formatted according to `key-order`.
+    pub(crate) fn sort_key_values<T>(
+        &self,
+        items: &mut [T],
+        key_value: impl Fn(&T) -> &ast::KeyValue,
+    ) {
+        self.sort_by_keys(items, |item| {
+            key_value(item)
+                .keys()
+                .into_iter()
+                .flat_map(|keys| keys.keys())
+                .map(|key| self.key_text(&key))
+                .collect()
+        });
+    }
+
+    /// Sort the values of the array if `sort-string-arrays` is enabled
+    /// and all of them are single-line strings.
+    pub(crate) fn sort_string_values<T>(&self, items: &mut [T], value: impl Fn(&T) -> &ast::Value) {
+        if !self.options().sort_string_arrays()
+            || !items
+                .iter()
+                .all(|item| self.string_value(value(item)).is_some())
+        {
+            return;
+        }
+
+        items.sort_by_cached_key(|item| self.string_value(value(item)).unwrap_or_default());
+    }
+
+    /// Get the decoded value of the single-line string value.
+    fn string_value(&self, value: &ast::Value) -> Option<String> {
+        match value {
+            ast::Value::BasicString(string) => ast::support::string::try_from_basic_string(
+                string.token()?.text(),
+                self.toml_version(),
+            )
+            .ok(),
+            ast::Value::LiteralString(string) => {
+                ast::support::string::try_from_literal_string(string.token()?.text()).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the property names of the schema of the table being formatted, in the order of the schema.
+    fn schema_properties(&self) -> Option<Vec<&str>> {
+        let document_schema = self.schema?;
+        let table_schema = document_schema.find_schema(&self.accessors)?;
+
+        Some(
+            document_schema
+                .properties(table_schema)
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use config::{FormatOptions, KeyOrder, TomlVersion};
+    use rstest::rstest;
+
+    use crate::Formatter;
+
+    fn schema() -> schema_store::DocumentSchema {
+        schema_store::parse_document_schema(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "package": {
+                    "type": "object",
+                    "properties": { "name": {}, "version": {}, "edition": {} },
+                },
+                "dependencies": { "$ref": "#/definitions/Dependencies" },
+                "dev-dependencies": { "$ref": "#/definitions/Dependencies" },
+                "bin": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": { "name": {}, "path": {} },
+                    },
+                },
+            },
+            "definitions": {
+                "Dependencies": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                },
+            },
+        }))
+    }
+
+    #[rstest]
+    #[case::schema(
+        KeyOrder::Schema,
+        r#"
+        [dev-dependencies]
+        rstest = "0.23"
+
+        # dependencies
+        [dependencies]
+        serde = "1"
+        # leading comment
+        anyhow = "1"  # tailing comment
+
+        [package]
+        version = "0.1.0"
+        name = "a"
+        edition = "2021"
+
+        [[bin]]
+        path = "src/main.rs"
+        name = "a"
+        "#,
+        r#"
+        [package]
+        name = "a"
+        version = "0.1.0"
+        edition = "2021"
+
+        # dependencies
+        [dependencies]
+        # leading comment
+        anyhow = "1"  # tailing comment
+        serde = "1"
+
+        [dev-dependencies]
+        rstest = "0.23"
+
+        [[bin]]
+        name = "a"
+        path = "src/main.rs"
+        "#
+    )]
+    #[case::alphabetical(
+        KeyOrder::Alphabetical,
+        r#"
+        [package]
+        version = "0.1.0"
+        name = "a"
+
+        [dependencies]
+        serde = { version = "1", features = ["derive"] }
+        anyhow = "1"
+        "#,
+        r#"
+        [package]
+        name = "a"
+        version = "0.1.0"
+
+        [dependencies]
+        anyhow = "1"
+        serde = { features = ["derive"], version = "1" }
+        "#
+    )]
+    #[case::preserve(
+        KeyOrder::Preserve,
+        r#"
+        [package]
+        version = "0.1.0"
+        name = "a"
+        "#,
+        r#"
+        [package]
+        version = "0.1.0"
+        name = "a"
+        "#
+    )]
+    fn format_with_key_order(
+        #[case] key_order: KeyOrder,
+        #[case] source: &str,
+        #[case] expected: &str,
+    ) {
+        let schema = schema();
+        let options = FormatOptions {
+            key_order: Some(key_order),
+            ..Default::default()
+        };
+        let formatted = Formatter::new(TomlVersion::default(), &options)
+            .with_schema(Some(&schema))
+            .format(&textwrap::dedent(source))
+            .unwrap();
+
+        pretty_assertions::assert_eq!(formatted, textwrap::dedent(expected).trim_start());
+    }
+
+    #[test]
+    fn key_order_schema_without_schema() {
+        let options = FormatOptions {
+            key_order: Some(KeyOrder::Schema),
+            ..Default::default()
+        };
+        let source = "b = 1\na = 2\n";
+
+        assert_eq!(
+            Formatter::new(TomlVersion::default(), &options)
+                .format(source)
+                .unwrap(),
+            source
+        );
+    }
+}
@@ -1,7 +1,9 @@
+mod diff;
 mod format;
 pub mod formatter;
 
 pub use config::FormatOptions;
+pub use diff::{text_edits, unified_diff};
 pub use formatter::Formatter;
 
 use diagnostic::Diagnostic;
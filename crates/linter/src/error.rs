@@ -1,5 +1,83 @@
+mod code;
+
+pub use code::diagnostic_codes;
+use schema_store::ValueType;
+
 #[derive(thiserror::Error, Debug)]
 pub enum ErrorKind {
     #[error("An empty quoted key is allowed, but it is not recommended")]
     KeyEmpty,
+
+    #[error("This suppression is not used")]
+    UnusedSuppression,
+
+    #[error("This suppression of \"{rule_name}\" is not used")]
+    UnusedRuleSuppression { rule_name: String },
+
+    #[error("Expected a value of type {expected}, but found {actual}")]
+    TypeMismatch { expected: String, actual: ValueType },
+
+    #[error("\"{key}\" is not allowed")]
+    KeyNotAllowed { key: String },
+
+    #[error("\"{key}\" is required")]
+    KeyRequired { key: String },
+
+    #[error("Expected one of [{expected}], but found {actual}")]
+    Enumerate { expected: String, actual: String },
+
+    #[error("Expected {expected}, but found {actual}")]
+    Const { expected: String, actual: String },
+
+    #[error("The value must be greater than or equal to {minimum}, but found {actual}")]
+    Minimum { minimum: f64, actual: String },
+
+    #[error("The value must be less than or equal to {maximum}, but found {actual}")]
+    Maximum { maximum: f64, actual: String },
+
+    #[error("The value must be greater than {minimum}, but found {actual}")]
+    ExclusiveMinimum { minimum: f64, actual: String },
+
+    #[error("The value must be less than {maximum}, but found {actual}")]
+    ExclusiveMaximum { maximum: f64, actual: String },
+
+    #[error("The value must be a multiple of {multiple_of}, but found {actual}")]
+    MultipleOf { multiple_of: f64, actual: String },
+
+    #[error("The length must be at least {min_length}, but found {actual}")]
+    MinLength { min_length: usize, actual: usize },
+
+    #[error("The length must be at most {max_length}, but found {actual}")]
+    MaxLength { max_length: usize, actual: usize },
+
+    #[error("The value must match the pattern \"{pattern}\", but found {actual}")]
+    Pattern { pattern: String, actual: String },
+
+    #[error("The array must have at least {min_items} items, but found {actual}")]
+    MinItems { min_items: usize, actual: usize },
+
+    #[error("The array must have at most {max_items} items, but found {actual}")]
+    MaxItems { max_items: usize, actual: usize },
+
+    #[error("The array items must be unique, but found a duplicate {actual}")]
+    UniqueItems { actual: String },
+
+    #[error("The table must have at least {min_properties} keys, but found {actual}")]
+    MinProperties {
+        min_properties: usize,
+        actual: usize,
+    },
+
+    #[error("The table must have at most {max_properties} keys, but found {actual}")]
+    MaxProperties {
+        max_properties: usize,
+        actual: usize,
+    },
+}
+
+impl ErrorKind {
+    /// Make an error diagnostic with the code of the error.
+    pub fn into_error(self, range: text::Range) -> diagnostic::Diagnostic {
+        diagnostic::Diagnostic::new_error(self.to_string(), range).with_code(self.code())
+    }
 }
@@ -0,0 +1,506 @@
+use diagnostic::DiagnosticCode;
+
+use super::ErrorKind;
+
+pub const KEY_EMPTY: DiagnosticCode = DiagnosticCode::new(
+    "T0201",
+    "key-empty",
+    r#"The key is an empty quoted string.
+
+An empty quoted key is allowed by TOML, but it is hard to read and to refer to.
+
+Invalid:
+
+```toml
+"" = "tombi"
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+```
+"#,
+);
+
+pub const UNUSED_SUPPRESSION: DiagnosticCode = DiagnosticCode::new(
+    "T0202",
+    "unused-suppression",
+    r#"The `# tombi: lint-ignore` or `# tombi: lint-disable` comment suppresses nothing.
+
+Remove the comment, or the rules in it that report nothing.
+
+Invalid:
+
+```toml
+# tombi: lint-ignore key-empty
+name = "tombi"
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+```
+"#,
+);
+
+pub const TYPE_MISMATCH: DiagnosticCode = DiagnosticCode::new(
+    "T0301",
+    "type-mismatch",
+    r#"The value does not have the type required by the schema.
+
+Invalid:
+
+```toml
+[package]
+version = 1
+```
+
+Fixed:
+
+```toml
+[package]
+version = "0.1.0"
+```
+"#,
+);
+
+pub const KEY_NOT_ALLOWED: DiagnosticCode = DiagnosticCode::new(
+    "T0302",
+    "key-not-allowed",
+    r#"The key is not defined by the schema, which does not allow additional keys.
+
+Invalid:
+
+```toml
+[package]
+nmae = "tombi"
+```
+
+Fixed:
+
+```toml
+[package]
+name = "tombi"
+```
+"#,
+);
+
+pub const KEY_REQUIRED: DiagnosticCode = DiagnosticCode::new(
+    "T0303",
+    "key-required",
+    r#"The key required by the schema is missing.
+
+Invalid:
+
+```toml
+[package]
+version = "0.1.0"
+```
+
+Fixed:
+
+```toml
+[package]
+name = "tombi"
+version = "0.1.0"
+```
+"#,
+);
+
+pub const ENUMERATE: DiagnosticCode = DiagnosticCode::new(
+    "T0304",
+    "enumerate",
+    r#"The value is not one of the values enumerated by the schema.
+
+Invalid:
+
+```toml
+[package]
+edition = "2020"
+```
+
+Fixed:
+
+```toml
+[package]
+edition = "2021"
+```
+"#,
+);
+
+pub const CONST: DiagnosticCode = DiagnosticCode::new(
+    "T0305",
+    "const",
+    r#"The value is not the constant value required by the schema.
+
+Invalid:
+
+```toml
+# The schema requires `version = 2`.
+version = 1
+```
+
+Fixed:
+
+```toml
+version = 2
+```
+"#,
+);
+
+pub const MINIMUM: DiagnosticCode = DiagnosticCode::new(
+    "T0306",
+    "minimum",
+    r#"The number is less than the minimum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires `workers >= 1`.
+workers = 0
+```
+
+Fixed:
+
+```toml
+workers = 1
+```
+"#,
+);
+
+pub const MAXIMUM: DiagnosticCode = DiagnosticCode::new(
+    "T0307",
+    "maximum",
+    r#"The number is greater than the maximum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires `line-width <= 200`.
+line-width = 300
+```
+
+Fixed:
+
+```toml
+line-width = 200
+```
+"#,
+);
+
+pub const EXCLUSIVE_MINIMUM: DiagnosticCode = DiagnosticCode::new(
+    "T0308",
+    "exclusive-minimum",
+    r#"The number is not greater than the exclusive minimum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires `timeout > 0`.
+timeout = 0
+```
+
+Fixed:
+
+```toml
+timeout = 30
+```
+"#,
+);
+
+pub const EXCLUSIVE_MAXIMUM: DiagnosticCode = DiagnosticCode::new(
+    "T0309",
+    "exclusive-maximum",
+    r#"The number is not less than the exclusive maximum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires `ratio < 1.0`.
+ratio = 1.0
+```
+
+Fixed:
+
+```toml
+ratio = 0.5
+```
+"#,
+);
+
+pub const MULTIPLE_OF: DiagnosticCode = DiagnosticCode::new(
+    "T0310",
+    "multiple-of",
+    r#"The number is not a multiple of the value required by the schema.
+
+Invalid:
+
+```toml
+# The schema requires a multiple of 2.
+indent-width = 3
+```
+
+Fixed:
+
+```toml
+indent-width = 4
+```
+"#,
+);
+
+pub const MIN_LENGTH: DiagnosticCode = DiagnosticCode::new(
+    "T0311",
+    "min-length",
+    r#"The string is shorter than the minimum length of the schema.
+
+Invalid:
+
+```toml
+[package]
+name = ""
+```
+
+Fixed:
+
+```toml
+[package]
+name = "tombi"
+```
+"#,
+);
+
+pub const MAX_LENGTH: DiagnosticCode = DiagnosticCode::new(
+    "T0312",
+    "max-length",
+    r#"The string is longer than the maximum length of the schema.
+
+Invalid:
+
+```toml
+# The schema requires at most 8 characters.
+code = "TOMBI-0001"
+```
+
+Fixed:
+
+```toml
+code = "T0001"
+```
+"#,
+);
+
+pub const PATTERN: DiagnosticCode = DiagnosticCode::new(
+    "T0313",
+    "pattern",
+    r#"The string does not match the pattern of the schema.
+
+Invalid:
+
+```toml
+# The schema requires the pattern "^[a-z][a-z0-9-]*$".
+name = "Tombi"
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+```
+"#,
+);
+
+pub const MIN_ITEMS: DiagnosticCode = DiagnosticCode::new(
+    "T0314",
+    "min-items",
+    r#"The array has fewer items than the minimum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires at least 1 item.
+authors = []
+```
+
+Fixed:
+
+```toml
+authors = ["Alice"]
+```
+"#,
+);
+
+pub const MAX_ITEMS: DiagnosticCode = DiagnosticCode::new(
+    "T0315",
+    "max-items",
+    r#"The array has more items than the maximum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires at most 5 items.
+keywords = ["toml", "formatter", "linter", "lsp", "cli", "schema"]
+```
+
+Fixed:
+
+```toml
+keywords = ["toml", "formatter", "linter", "lsp", "cli"]
+```
+"#,
+);
+
+pub const UNIQUE_ITEMS: DiagnosticCode = DiagnosticCode::new(
+    "T0316",
+    "unique-items",
+    r#"The array has duplicate items, but the schema requires unique items.
+
+Invalid:
+
+```toml
+features = ["derive", "std", "derive"]
+```
+
+Fixed:
+
+```toml
+features = ["derive", "std"]
+```
+"#,
+);
+
+pub const MIN_PROPERTIES: DiagnosticCode = DiagnosticCode::new(
+    "T0317",
+    "min-properties",
+    r#"The table has fewer keys than the minimum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires at least 1 key.
+[dependencies]
+```
+
+Fixed:
+
+```toml
+[dependencies]
+serde = "1.0"
+```
+"#,
+);
+
+pub const MAX_PROPERTIES: DiagnosticCode = DiagnosticCode::new(
+    "T0318",
+    "max-properties",
+    r#"The table has more keys than the maximum of the schema.
+
+Invalid:
+
+```toml
+# The schema requires at most 1 key.
+[target]
+linux = {}
+windows = {}
+```
+
+Fixed:
+
+```toml
+[target]
+linux = {}
+```
+"#,
+);
+
+/// All the diagnostic codes of the linter.
+pub const CODES: &[DiagnosticCode] = &[
+    KEY_EMPTY,
+    UNUSED_SUPPRESSION,
+    TYPE_MISMATCH,
+    KEY_NOT_ALLOWED,
+    KEY_REQUIRED,
+    ENUMERATE,
+    CONST,
+    MINIMUM,
+    MAXIMUM,
+    EXCLUSIVE_MINIMUM,
+    EXCLUSIVE_MAXIMUM,
+    MULTIPLE_OF,
+    MIN_LENGTH,
+    MAX_LENGTH,
+    PATTERN,
+    MIN_ITEMS,
+    MAX_ITEMS,
+    UNIQUE_ITEMS,
+    MIN_PROPERTIES,
+    MAX_PROPERTIES,
+];
+
+impl ErrorKind {
+    /// The stable code of the error.
+    pub const fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::KeyEmpty => KEY_EMPTY,
+            Self::UnusedSuppression | Self::UnusedRuleSuppression { .. } => UNUSED_SUPPRESSION,
+            Self::TypeMismatch { .. } => TYPE_MISMATCH,
+            Self::KeyNotAllowed { .. } => KEY_NOT_ALLOWED,
+            Self::KeyRequired { .. } => KEY_REQUIRED,
+            Self::Enumerate { .. } => ENUMERATE,
+            Self::Const { .. } => CONST,
+            Self::Minimum { .. } => MINIMUM,
+            Self::Maximum { .. } => MAXIMUM,
+            Self::ExclusiveMinimum { .. } => EXCLUSIVE_MINIMUM,
+            Self::ExclusiveMaximum { .. } => EXCLUSIVE_MAXIMUM,
+            Self::MultipleOf { .. } => MULTIPLE_OF,
+            Self::MinLength { .. } => MIN_LENGTH,
+            Self::MaxLength { .. } => MAX_LENGTH,
+            Self::Pattern { .. } => PATTERN,
+            Self::MinItems { .. } => MIN_ITEMS,
+            Self::MaxItems { .. } => MAX_ITEMS,
+            Self::UniqueItems { .. } => UNIQUE_ITEMS,
+            Self::MinProperties { .. } => MIN_PROPERTIES,
+            Self::MaxProperties { .. } => MAX_PROPERTIES,
+        }
+    }
+}
+
+/// All the diagnostic codes of Tombi, in the order of the codes.
+pub fn diagnostic_codes() -> impl Iterator<Item = DiagnosticCode> {
+    parser::CODES
+        .iter()
+        .chain(document_tree::CODES)
+        .chain(CODES)
+        .copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn codes_are_unique() {
+        let codes = diagnostic_codes().collect::<Vec<_>>();
+        for (i, code) in codes.iter().enumerate() {
+            assert!(codes[..i].iter().all(|other| other.code() != code.code()));
+            assert!(codes[..i].iter().all(|other| other.name() != code.name()));
+        }
+    }
+
+    #[test]
+    fn codes_are_sorted_and_well_formed() {
+        let codes = diagnostic_codes().collect::<Vec<_>>();
+        assert!(codes.windows(2).all(|pair| pair[0].code() < pair[1].code()));
+        for code in codes {
+            assert!(code.code().len() == 5 && code.code().starts_with('T'));
+            assert!(code.code()[1..].chars().all(|c| c.is_ascii_digit()));
+            assert!(code
+                .name()
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c == '-'));
+            assert!(code.explanation().contains("Invalid:"));
+            assert!(code.explanation().contains("Fixed:"));
+        }
+    }
+}
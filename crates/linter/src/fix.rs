@@ -0,0 +1,165 @@
+use ast::{AstNode, AstToken};
+use diagnostic::{Diagnostic, Fix, TextEdit};
+use syntax::SyntaxKind;
+
+/// Apply the fixes of the diagnostics to the source.
+///
+/// A fix overlapping with a fix applied before is skipped,
+/// and will be found again by linting the fixed source.
+pub fn apply_fixes(source: &str, diagnostics: &[Diagnostic]) -> String {
+    let mut fixes: Vec<&Fix> = vec![];
+    for fix in diagnostics.iter().filter_map(Diagnostic::fix) {
+        if !fixes.contains(&fix) {
+            fixes.push(fix);
+        }
+    }
+
+    let mut edits: Vec<(std::ops::Range<usize>, &str)> = vec![];
+    for fix in fixes {
+        let fix_edits = fix
+            .edits()
+            .iter()
+            .map(|edit| {
+                let start = text::Offset::from_position(source, edit.range().start());
+                let end = text::Offset::from_position(source, edit.range().end());
+                (start.into()..end.into(), edit.new_text())
+            })
+            .collect::<Vec<_>>();
+
+        if fix_edits.iter().all(|(span, _)| {
+            edits.iter().all(|(other, _)| {
+                (span.end <= other.start || other.end <= span.start) && span.start != other.start
+            })
+        }) {
+            edits.extend(fix_edits);
+        }
+    }
+
+    edits.sort_by_key(|(span, _)| span.start);
+
+    let mut fixed = source.to_string();
+    for (span, new_text) in edits.into_iter().rev() {
+        fixed.replace_range(span, new_text);
+    }
+    fixed
+}
+
+/// The fix of an invalid TOML structure found by the document tree.
+pub(crate) fn document_tree_error_fix(
+    root: &ast::Root,
+    error: &document_tree::Error,
+) -> Option<Fix> {
+    match error {
+        document_tree::Error::ParseStringError {
+            error: ast::support::string::ParseError::UnicodeKey,
+            range,
+        } => root
+            .syntax()
+            .descendants()
+            .filter_map(ast::Key::cast)
+            .find(|key| matches!(key, ast::Key::BareKey(_)) && key.syntax().range() == *range)
+            .map(|key| {
+                // NOTE: A bare key never contains the characters to be escaped.
+                let quoted = format!("\"{}\"", key.syntax().text());
+                Fix::new(
+                    format!("Quote the key as {quoted}"),
+                    vec![TextEdit::new(*range, quoted)],
+                )
+            }),
+        document_tree::Error::DuplicateKey { key, range } => root
+            .syntax()
+            .descendants()
+            .filter_map(ast::KeyValue::cast)
+            .find(|key_value| {
+                key_value
+                    .keys()
+                    .is_some_and(|keys| keys.keys().any(|key| key.syntax().range() == *range))
+            })
+            .filter(|key_value| {
+                // NOTE: The key values of inline tables are separated by commas,
+                //       so only the lines of the key values are removed.
+                key_value.syntax().parent().is_some_and(|parent| {
+                    matches!(
+                        parent.kind(),
+                        SyntaxKind::ROOT | SyntaxKind::TABLE | SyntaxKind::ARRAY_OF_TABLES
+                    )
+                })
+            })
+            .and_then(|key_value| {
+                // NOTE: The key value node begins with the line break before it.
+                let start = match key_value.leading_comments().next() {
+                    Some(comment) => ast::Comment::from(comment).syntax().range().start(),
+                    None => key_value.keys()?.range().start(),
+                };
+                Some(Fix::new(
+                    format!("Remove the duplicate key \"{key}\""),
+                    vec![TextEdit::delete(line_range(text::Range::new(
+                        start,
+                        key_value.range().end(),
+                    )))],
+                ))
+            }),
+        _ => None,
+    }
+}
+
+/// The range of the whole lines of the range, including the last line break.
+pub(crate) fn line_range(range: text::Range) -> text::Range {
+    text::Range::new(
+        text::Position::new(range.start().line(), 0),
+        text::Position::new(range.end().line() + 1, 0),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    async fn lint_fix(source: &str, toml_version: config::TomlVersion) -> String {
+        let diagnostics = crate::Linter::new(
+            toml_version,
+            &Default::default(),
+            None,
+            None,
+            &schema_store::SchemaStore::default(),
+        )
+        .lint(source)
+        .await
+        .err()
+        .unwrap_or_default();
+
+        apply_fixes(source, &diagnostics)
+    }
+
+    #[rstest]
+    #[case("a = 1\nb = 2\na = 3\n", "a = 1\nb = 2\n")]
+    #[case("a = 1\na = 2", "a = 1\n")]
+    #[case("[t]\nx = 1\n# comment\nx = 2\ny = 3\n", "[t]\nx = 1\ny = 3\n")]
+    #[case("a = { b = 1, b = 2 }\n", "a = { b = 1, b = 2 }\n")]
+    #[case("a = 1  # tombi: lint-ignore\n", "a = 1\n")]
+    #[case("# tombi: lint-ignore key-empty\na = 1\n", "a = 1\n")]
+    #[case(
+        "# tombi: lint-ignore key-empty, unknown-rule\n'' = 1\n",
+        "# tombi: lint-ignore key-empty\n'' = 1\n"
+    )]
+    #[tokio::test]
+    async fn fix_default(#[case] source: &str, #[case] expected: &str) {
+        assert_eq!(
+            lint_fix(source, config::TomlVersion::default()).await,
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("あ = 1\n", "\"あ\" = 1\n")]
+    #[case("a.ü.c = 1\n", "a.\"ü\".c = 1\n")]
+    #[tokio::test]
+    async fn fix_v1_0_0(#[case] source: &str, #[case] expected: &str) {
+        assert_eq!(
+            lint_fix(source, config::TomlVersion::V1_0_0).await,
+            expected
+        );
+    }
+}
@@ -1,10 +1,16 @@
 mod error;
+mod fix;
 mod lint;
 mod linter;
 mod rule;
+mod suppression;
+mod validation;
 
 pub use config::LintOptions;
+pub use error::diagnostic_codes;
+pub use fix::apply_fixes;
 pub use linter::Linter;
+pub use rule::{RuleMetadata, RULES};
 
 use diagnostic::Diagnostic;
 use error::ErrorKind;
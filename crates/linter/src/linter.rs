@@ -1,6 +1,11 @@
 use std::borrow::Cow;
 
-use crate::lint::Lint;
+use crate::{
+    lint::Lint,
+    rule::{Rule, UnusedSuppressionRule},
+    suppression::Suppressions,
+    validation::Validator,
+};
 use ast::AstNode;
 use config::TomlVersion;
 use diagnostic::Diagnostic;
@@ -14,6 +19,7 @@ pub struct Linter<'a> {
     source_path: Option<&'a std::path::Path>,
     schema_url: Option<&'a Url>,
     schema_store: &'a schema_store::SchemaStore,
+    suppressions: Suppressions,
     diagnostics: Vec<crate::Diagnostic>,
 }
 
@@ -32,13 +38,27 @@ impl<'a> Linter<'a> {
             source_path,
             schema_url,
             schema_store,
+            suppressions: Suppressions::default(),
             diagnostics: Vec::new(),
         }
     }
 
-    pub async fn lint(mut self, source: &str) -> Result<(), Vec<Diagnostic>> {
+    pub async fn lint(self, source: &str) -> Result<(), Vec<Diagnostic>> {
+        let parsed = parser::parse(source, self.toml_version);
+        self.lint_parsed(&parsed, None).await
+    }
+
+    /// Lint the parsed source, with its document tree if it is already built,
+    /// e.g. the cached parse of the language server.
+    ///
+    /// The document tree is built from the AST if it is not given.
+    pub async fn lint_parsed(
+        mut self,
+        parsed: &parser::Parsed<parser::SyntaxNode>,
+        document_tree: Option<&document_tree::Root>,
+    ) -> Result<(), Vec<Diagnostic>> {
         let toml_version = self.toml_version;
-        let _schema = if let Some(schema_url) = self.schema_url {
+        let document_schema = if let Some(schema_url) = self.schema_url {
             if let Ok(schema) = self.schema_store.get_schema_from_url(schema_url).await {
                 tracing::debug!("find schema from url: {}", schema_url);
                 tracing::debug!("{:?}", &schema);
@@ -57,26 +77,60 @@ impl<'a> Linter<'a> {
             None
         };
 
-        let p = parser::parse(source, toml_version);
         let mut errors = vec![];
 
-        for err in p.errors() {
+        for err in parsed.errors() {
             err.set_diagnostic(&mut errors);
         }
 
         if errors.is_empty() {
-            let Some(root) = ast::Root::cast(p.into_syntax_node()) else {
+            let Some(root) = ast::Root::cast(parsed.syntax_node()) else {
                 unreachable!("Root node is always present");
             };
 
+            self.suppressions = Suppressions::new(&root);
             root.lint(&mut self);
 
-            if let Err(errs) = root.try_into_document_tree(toml_version) {
-                for err in errs {
-                    err.set_diagnostic(&mut errors);
+            let document_tree = match document_tree {
+                Some(document_tree) => Ok(Cow::Borrowed(document_tree)),
+                None => root
+                    .clone()
+                    .try_into_document_tree(toml_version)
+                    .map(Cow::Owned),
+            };
+
+            match document_tree {
+                Ok(document_tree) => {
+                    if let Some(document_schema) = &document_schema {
+                        // NOTE: The schema validation is suppressed by the names of the codes,
+                        //       before the unused suppressions are checked.
+                        errors.extend(
+                            Validator::new(document_schema, toml_version)
+                                .validate(&document_tree)
+                                .into_iter()
+                                .filter(|diagnostic| {
+                                    !diagnostic.code().is_some_and(|code| {
+                                        self.suppressions.suppress(code.name(), diagnostic.range())
+                                    })
+                                }),
+                        );
+                    }
+                }
+                Err(errs) => {
+                    for err in errs {
+                        let mut diagnostics = vec![];
+                        err.set_diagnostic(&mut diagnostics);
+
+                        let fix = crate::fix::document_tree_error_fix(&root, &err);
+                        errors.extend(diagnostics.into_iter().map(|diagnostic| match &fix {
+                            Some(fix) => diagnostic.with_fix(fix.clone()),
+                            None => diagnostic,
+                        }));
+                    }
                 }
             }
 
+            UnusedSuppressionRule::check(&root, &mut self);
             errors.extend(self.into_diagnostics());
         }
 
@@ -105,7 +159,82 @@ impl<'a> Linter<'a> {
     }
 
     #[inline]
-    pub(crate) fn add_diagnostic(&mut self, diagnostic: crate::Diagnostic) {
-        self.diagnostics.push(diagnostic);
+    pub(crate) fn suppressions(&self) -> &Suppressions {
+        &self.suppressions
+    }
+
+    /// Report a problem found by the rule, unless it is suppressed by a comment.
+    pub(crate) fn report(
+        &mut self,
+        rule: &crate::rule::RuleMetadata,
+        error_kind: crate::ErrorKind,
+        range: text::Range,
+    ) {
+        if self.suppressions.suppress(rule.name, range) {
+            return;
+        }
+        self.add_rule_diagnostic(rule, error_kind, range, None);
+    }
+
+    /// Add a diagnostic of the rule, with the severity level of `[lint.rules]`.
+    ///
+    /// The fix is applied by `tombi lint --fix` and the code actions.
+    pub(crate) fn add_rule_diagnostic(
+        &mut self,
+        rule: &crate::rule::RuleMetadata,
+        error_kind: crate::ErrorKind,
+        range: text::Range,
+        fix: Option<diagnostic::Fix>,
+    ) {
+        let diagnostic = match self
+            .options
+            .rule_level(rule.name)
+            .unwrap_or(rule.default_level)
+        {
+            config::SeverityLevel::Off => return,
+            config::SeverityLevel::Warn => {
+                crate::Diagnostic::new_warning(error_kind.to_string(), range)
+            }
+            config::SeverityLevel::Error => {
+                crate::Diagnostic::new_error(error_kind.to_string(), range)
+            }
+        }
+        .with_code(error_kind.code());
+
+        self.diagnostics.push(match fix {
+            Some(fix) => diagnostic.with_fix(fix),
+            None => diagnostic,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("a = 1\n")]
+    #[case("'' = 1\n")]
+    #[case("a = 1  # tombi: lint-ignore\n")]
+    #[case("a = 1\na = 2\n")]
+    #[tokio::test]
+    async fn lint_parsed_with_document_tree(#[case] source: &str) {
+        let toml_version = TomlVersion::default();
+        let options = crate::LintOptions::default();
+        let schema_store = schema_store::SchemaStore::default();
+        let linter = || Linter::new(toml_version, &options, None, None, &schema_store);
+
+        let parsed = parser::parse(source, toml_version);
+        let document_tree = ast::Root::cast(parsed.syntax_node())
+            .unwrap()
+            .try_into_document_tree(toml_version)
+            .ok();
+
+        assert_eq!(
+            linter().lint_parsed(&parsed, document_tree.as_ref()).await,
+            linter().lint(source).await
+        );
     }
 }
@@ -1,6 +1,54 @@
 mod key_empty;
+mod unused_suppression;
+
+pub use config::SeverityLevel;
 pub use key_empty::KeyEmptyRule;
+pub use unused_suppression::UnusedSuppressionRule;
+
+/// All the rules of the linter.
+pub const RULES: &[RuleMetadata] = &[
+    <KeyEmptyRule as Rule<ast::Key>>::METADATA,
+    <UnusedSuppressionRule as Rule<ast::Root>>::METADATA,
+];
 
 pub trait Rule<N: ast::AstNode> {
+    const METADATA: RuleMetadata;
+
     fn check(node: &N, l: &mut crate::Linter);
 }
+
+/// Metadata of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleMetadata {
+    /// The stable name, used as the key of `[lint.rules]`.
+    pub name: &'static str,
+
+    /// A short description of the problem the rule reports.
+    pub description: &'static str,
+
+    /// The severity level used when `[lint.rules]` does not configure the rule.
+    pub default_level: SeverityLevel,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rule_names_are_configurable() {
+        for rule in RULES {
+            let rules = serde_json::from_value::<config::LintRules>(serde_json::json!({
+                rule.name: "error"
+            }))
+            .unwrap_or_else(|err| panic!("{} is not in LintRules: {err}", rule.name));
+            assert_eq!(rules.get(rule.name), Some(SeverityLevel::Error));
+        }
+    }
+
+    #[test]
+    fn rule_names_are_unique() {
+        for (i, rule) in RULES.iter().enumerate() {
+            assert!(RULES[..i].iter().all(|other| other.name != rule.name));
+        }
+    }
+}
@@ -1,19 +1,27 @@
-use crate::Rule;
+use crate::{rule::RuleMetadata, Rule};
 use ast::AstNode;
+use config::SeverityLevel;
 
 pub struct KeyEmptyRule;
 
 impl Rule<ast::Key> for KeyEmptyRule {
+    const METADATA: RuleMetadata = RuleMetadata {
+        name: "key-empty",
+        description: "An empty quoted key is allowed, but it is not recommended",
+        default_level: SeverityLevel::Warn,
+    };
+
     fn check(node: &ast::Key, l: &mut crate::Linter) {
         if match node {
             ast::Key::BareKey(_) => false,
             ast::Key::BasicString(node) => node.syntax().text() == "\"\"",
             ast::Key::LiteralString(node) => node.syntax().text() == "''",
         } {
-            l.add_diagnostic(diagnostic::Diagnostic::new_warning(
-                crate::ErrorKind::KeyEmpty.to_string(),
+            l.report(
+                &Self::METADATA,
+                crate::ErrorKind::KeyEmpty,
                 node.syntax().range(),
-            ));
+            );
         };
     }
 }
@@ -30,7 +38,45 @@ mod tests {
             vec![diagnostic::Diagnostic::new_warning(
                 crate::ErrorKind::KeyEmpty.to_string(),
                 text::Range::new((0, 0).into(), (0, 2).into()),
-            )]
+            )
+            .with_code(crate::ErrorKind::KeyEmpty.code())]
+        );
+    }
+
+    async fn lint_with_level(
+        source: &str,
+        level: config::SeverityLevel,
+    ) -> Result<(), Vec<diagnostic::Diagnostic>> {
+        let options = config::LintOptions {
+            rules: Some(config::LintRules {
+                key_empty: Some(level),
+                ..Default::default()
+            }),
+        };
+        crate::Linter::new(
+            Default::default(),
+            &options,
+            None,
+            None,
+            &schema_store::SchemaStore::default(),
+        )
+        .lint(source)
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_key_empty_level() {
+        assert_eq!(
+            lint_with_level("'' = 1", config::SeverityLevel::Off).await,
+            Ok(())
+        );
+        assert_eq!(
+            lint_with_level("'' = 1", config::SeverityLevel::Error).await,
+            Err(vec![diagnostic::Diagnostic::new_error(
+                crate::ErrorKind::KeyEmpty.to_string(),
+                text::Range::new((0, 0).into(), (0, 2).into()),
+            )
+            .with_code(crate::ErrorKind::KeyEmpty.code())])
         );
     }
 }
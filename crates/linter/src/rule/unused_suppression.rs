@@ -0,0 +1,162 @@
+use crate::{
+    rule::RuleMetadata,
+    suppression::{SuppressedRules, DIRECTIVE_PREFIX},
+    Rule,
+};
+use config::SeverityLevel;
+use diagnostic::{Fix, TextEdit};
+use itertools::Itertools;
+
+pub struct UnusedSuppressionRule;
+
+impl Rule<ast::Root> for UnusedSuppressionRule {
+    const METADATA: RuleMetadata = RuleMetadata {
+        name: "unused-suppression",
+        description: "A suppression comment does not suppress any problem",
+        default_level: SeverityLevel::Warn,
+    };
+
+    fn check(_node: &ast::Root, l: &mut crate::Linter) {
+        let mut unused = vec![];
+        for suppression in l.suppressions().iter() {
+            let remove_fix = || {
+                Fix::new(
+                    "Remove the unused suppression",
+                    vec![TextEdit::delete(suppression.removal_range)],
+                )
+            };
+
+            match &suppression.rules {
+                SuppressedRules::All { used: false } => unused.push((
+                    crate::ErrorKind::UnusedSuppression,
+                    suppression.comment_range,
+                    remove_fix(),
+                )),
+                SuppressedRules::All { used: true } => {}
+                SuppressedRules::Rules(rules) => {
+                    let used_rule_names = rules
+                        .iter()
+                        .filter(|(_, used)| *used)
+                        .map(|(rule_name, _)| rule_name)
+                        .collect_vec();
+
+                    let fix = if used_rule_names.is_empty() {
+                        remove_fix()
+                    } else {
+                        Fix::new(
+                            "Remove the unused rules from the suppression",
+                            vec![TextEdit::new(
+                                suppression.comment_range,
+                                format!(
+                                    "# {DIRECTIVE_PREFIX} {} {}",
+                                    suppression.directive,
+                                    used_rule_names.iter().join(", ")
+                                ),
+                            )],
+                        )
+                    };
+
+                    for (rule_name, _) in rules.iter().filter(|(_, used)| !used) {
+                        unused.push((
+                            crate::ErrorKind::UnusedRuleSuppression {
+                                rule_name: rule_name.to_owned(),
+                            },
+                            suppression.comment_range,
+                            fix.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // NOTE: The unused suppressions are not suppressed by themselves.
+        for (error_kind, range, fix) in unused {
+            l.add_rule_diagnostic(&Self::METADATA, error_kind, range, Some(fix));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    const KEY_EMPTY: &str = "An empty quoted key is allowed, but it is not recommended";
+
+    #[rstest]
+    #[case("# tombi: lint-ignore key-empty\n'' = 1", vec![])]
+    #[case("'' = 1  # tombi: lint-ignore key-empty", vec![])]
+    #[case("'' = 1  # tombi: lint-ignore", vec![])]
+    #[case("# tombi: lint-disable\n\n[a]\n'' = 1\n[b]\n'' = 2", vec![])]
+    #[case("# tombi: lint-disable key-empty\n'' = 1\n[a]\n\"\" = 2", vec![])]
+    #[case(
+        "[a]  # tombi: lint-ignore key-empty\n'' = 1",
+        vec![KEY_EMPTY, "This suppression of \"key-empty\" is not used"]
+    )]
+    #[case(
+        "# tombi: lint-ignore key-empty\na = 1",
+        vec!["This suppression of \"key-empty\" is not used"]
+    )]
+    #[case("a = 1  # tombi: lint-ignore", vec!["This suppression is not used"])]
+    #[case(
+        "# tombi: lint-ignore key-empty, unknown-rule\n'' = 1",
+        vec!["This suppression of \"unknown-rule\" is not used"]
+    )]
+    #[tokio::test]
+    async fn test_suppression(#[case] source: &str, #[case] expected: Vec<&str>) {
+        let messages = crate::lint(source)
+            .await
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(messages, expected);
+    }
+
+    #[rstest]
+    #[case("# tombi: lint-disable\n\nname = 1", vec![])]
+    #[case("# tombi: lint-disable type-mismatch\n\nname = 1", vec![])]
+    #[case("name = 1  # tombi: lint-ignore type-mismatch", vec![])]
+    #[case(
+        "# tombi: lint-disable key-empty\n\nname = 1",
+        vec![
+            "Expected a value of type String, but found Integer",
+            "This suppression of \"key-empty\" is not used",
+        ]
+    )]
+    #[tokio::test]
+    async fn test_schema_suppression(#[case] source: &str, #[case] expected: Vec<&str>) {
+        // NOTE: The cases run in parallel, so the schema file is written only once.
+        static SCHEMA_URL: std::sync::OnceLock<url::Url> = std::sync::OnceLock::new();
+        let schema_url = SCHEMA_URL.get_or_init(|| {
+            let schema_path = std::env::temp_dir().join(format!(
+                "linter-suppression-test-{}.json",
+                std::process::id()
+            ));
+            std::fs::write(
+                &schema_path,
+                r#"{ "type": "object", "properties": { "name": { "type": "string" } } }"#,
+            )
+            .unwrap();
+            url::Url::from_file_path(&schema_path).unwrap()
+        });
+
+        let messages = crate::Linter::new(
+            config::TomlVersion::default(),
+            &Default::default(),
+            None,
+            Some(schema_url),
+            &schema_store::SchemaStore::default(),
+        )
+        .lint(source)
+        .await
+        .err()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|diagnostic| diagnostic.message().to_string())
+        .collect::<Vec<_>>();
+
+        assert_eq!(messages, expected);
+    }
+}
@@ -0,0 +1,221 @@
+use ast::{AstNode, AstToken};
+use syntax::SyntaxKind;
+
+pub(crate) const DIRECTIVE_PREFIX: &str = "tombi:";
+const LINT_IGNORE: &str = "lint-ignore";
+const LINT_DISABLE: &str = "lint-disable";
+
+/// Suppression comments of a document.
+///
+/// ```toml
+/// # tombi: lint-disable key-empty
+///
+/// # tombi: lint-ignore key-empty
+/// "" = 1
+/// "" = 2  # tombi: lint-ignore key-empty
+/// ```
+///
+/// `lint-disable` applies to the whole document and must be at the top of it,
+/// and `lint-ignore` applies to the item it annotates as a leading or tailing comment.
+/// Without rule names, the directive applies to all the rules.
+#[derive(Debug, Default)]
+pub(crate) struct Suppressions {
+    suppressions: Vec<Suppression>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Suppression {
+    /// The range of the suppression comment.
+    pub comment_range: text::Range,
+
+    /// The range to remove the suppression comment,
+    /// which is the whole line unless the comment is a tailing comment.
+    pub removal_range: text::Range,
+
+    pub directive: &'static str,
+
+    /// The range of the suppressed item, or `None` for the whole document.
+    target_range: Option<text::Range>,
+
+    pub rules: SuppressedRules,
+}
+
+#[derive(Debug)]
+pub(crate) enum SuppressedRules {
+    All { used: bool },
+    Rules(Vec<(String, bool)>),
+}
+
+impl Suppressions {
+    pub fn new(root: &ast::Root) -> Self {
+        let mut suppressions = vec![];
+
+        let first_item_comments = root
+            .items()
+            .next()
+            .into_iter()
+            .flat_map(|item| item.leading_comments().map(Into::into).collect::<Vec<_>>());
+        for comment in root
+            .begin_dangling_comments()
+            .into_iter()
+            .flatten()
+            .map(Into::into)
+            .chain(first_item_comments)
+        {
+            if let Some(Directive::Disable(rules)) = Directive::parse(&comment) {
+                suppressions.push(Suppression::new(&comment, LINT_DISABLE, None, rules));
+            }
+        }
+
+        for node in root.syntax().descendants() {
+            let (comments, target_range): (Vec<ast::Comment>, _) =
+                if let Some(key_value) = ast::KeyValue::cast(node.to_owned()) {
+                    (
+                        key_value
+                            .leading_comments()
+                            .map(Into::into)
+                            .chain(key_value.tailing_comment().map(Into::into))
+                            .collect(),
+                        key_value.range(),
+                    )
+                } else if let Some(table) = ast::Table::cast(node.to_owned()) {
+                    let Some(header) = table.header() else {
+                        continue;
+                    };
+                    (
+                        table
+                            .header_leading_comments()
+                            .map(Into::into)
+                            .chain(table.header_tailing_comment().map(Into::into))
+                            .collect(),
+                        header.range(),
+                    )
+                } else if let Some(array_of_tables) = ast::ArrayOfTables::cast(node.to_owned()) {
+                    let Some(header) = array_of_tables.header() else {
+                        continue;
+                    };
+                    (
+                        array_of_tables
+                            .header_leading_comments()
+                            .map(Into::into)
+                            .chain(array_of_tables.header_tailing_comment().map(Into::into))
+                            .collect(),
+                        header.range(),
+                    )
+                } else {
+                    continue;
+                };
+
+            for comment in comments {
+                if let Some(Directive::Ignore(rules)) = Directive::parse(&comment) {
+                    suppressions.push(Suppression::new(
+                        &comment,
+                        LINT_IGNORE,
+                        Some(target_range),
+                        rules,
+                    ));
+                }
+            }
+        }
+
+        Self { suppressions }
+    }
+
+    /// Whether the problem of the rule at the range is suppressed.
+    ///
+    /// The suppressions applied to the problem are marked as used.
+    pub fn suppress(&mut self, rule_name: &str, range: text::Range) -> bool {
+        let mut suppressed = false;
+        for suppression in &mut self.suppressions {
+            if suppression
+                .target_range
+                .is_some_and(|target_range| !target_range.contains(range.start()))
+            {
+                continue;
+            }
+            match &mut suppression.rules {
+                SuppressedRules::All { used } => {
+                    *used = true;
+                    suppressed = true;
+                }
+                SuppressedRules::Rules(rules) => {
+                    for (name, used) in rules {
+                        if name == rule_name {
+                            *used = true;
+                            suppressed = true;
+                        }
+                    }
+                }
+            }
+        }
+        suppressed
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Suppression> {
+        self.suppressions.iter()
+    }
+}
+
+impl Suppression {
+    fn new(
+        comment: &ast::Comment,
+        directive: &'static str,
+        target_range: Option<text::Range>,
+        rules: Vec<String>,
+    ) -> Self {
+        let comment_range = comment.syntax().range();
+
+        let mut start = comment_range.start();
+        let mut prev_token = comment.syntax().prev_token();
+        if let Some(whitespace) = prev_token
+            .as_ref()
+            .filter(|token| token.kind() == SyntaxKind::WHITESPACE)
+        {
+            start = whitespace.range().start();
+            prev_token = whitespace.prev_token();
+        }
+        let removal_range = match prev_token {
+            Some(token) if token.kind() != SyntaxKind::LINE_BREAK => {
+                text::Range::new(start, comment_range.end())
+            }
+            _ => crate::fix::line_range(comment_range),
+        };
+
+        Self {
+            comment_range,
+            removal_range,
+            directive,
+            target_range,
+            rules: if rules.is_empty() {
+                SuppressedRules::All { used: false }
+            } else {
+                SuppressedRules::Rules(rules.into_iter().map(|name| (name, false)).collect())
+            },
+        }
+    }
+}
+
+enum Directive {
+    Ignore(Vec<String>),
+    Disable(Vec<String>),
+}
+
+impl Directive {
+    fn parse(comment: &ast::Comment) -> Option<Self> {
+        let text = comment.syntax().text().strip_prefix('#')?.trim();
+        let text = text.strip_prefix(DIRECTIVE_PREFIX)?.trim_start();
+
+        let (directive, rules) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        let rules = rules
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|name| !name.is_empty())
+            .map(ToString::to_string)
+            .collect();
+
+        match directive {
+            LINT_IGNORE => Some(Self::Ignore(rules)),
+            LINT_DISABLE => Some(Self::Disable(rules)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,480 @@
+mod array;
+mod primitive;
+mod table;
+
+use config::TomlVersion;
+use diagnostic::{Diagnostic, Fix, TextEdit};
+use itertools::Itertools;
+use schema_store::{DocumentSchema, ObjectSchema, SchemaComposition, SchemaType, ValueType};
+
+use crate::ErrorKind;
+use primitive::{validate_number, validate_string};
+
+/// Validates a document tree against a JSON Schema.
+pub(crate) struct Validator<'a> {
+    document_schema: &'a DocumentSchema,
+    toml_version: TomlVersion,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new(document_schema: &'a DocumentSchema, toml_version: TomlVersion) -> Self {
+        Self {
+            document_schema,
+            toml_version,
+        }
+    }
+
+    pub fn validate(&self, root: &document_tree::Root) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+
+        // NOTE: The root has no key, so the diagnostics about the root as a whole,
+        //       such as a missing required key, are reported at the whole document.
+        self.validate_table(
+            root,
+            &self.document_schema.value_schema,
+            root.range(),
+            &mut diagnostics,
+        );
+
+        diagnostics
+    }
+
+    /// Validate the value.
+    ///
+    /// `key_range` is the range of the key that owns the value,
+    /// used for the diagnostics that are about the value as a whole.
+    fn validate_value(
+        &self,
+        value: &document_tree::Value,
+        schema: &ObjectSchema,
+        key_range: text::Range,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        for composition in &schema.compositions {
+            self.validate_composition(value, composition, key_range, diagnostics);
+        }
+
+        if !schema.types.is_empty() && !accepts_value(schema, value) {
+            diagnostics.push(
+                ErrorKind::TypeMismatch {
+                    expected: expected_types(&schema.types),
+                    actual: ValueType::from(value),
+                }
+                .into_error(value.range()),
+            );
+            return;
+        }
+
+        self.validate_enumerate(value, schema, diagnostics);
+
+        match value {
+            document_tree::Value::Integer(integer) => {
+                validate_number(integer.value() as f64, value, schema, diagnostics)
+            }
+            document_tree::Value::Float(float) => {
+                validate_number(float.value(), value, schema, diagnostics)
+            }
+            document_tree::Value::String(string) => validate_string(
+                &string.to_raw_string(self.toml_version),
+                value,
+                schema,
+                diagnostics,
+            ),
+            document_tree::Value::Array(array) => self.validate_array(array, schema, diagnostics),
+            document_tree::Value::Table(table) => {
+                self.validate_table(table, schema, key_range, diagnostics)
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_composition(
+        &self,
+        value: &document_tree::Value,
+        composition: &SchemaComposition,
+        key_range: text::Range,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let schemas = composition
+            .schemas()
+            .iter()
+            .filter_map(|referable| self.document_schema.resolve(referable))
+            .collect_vec();
+
+        match composition {
+            SchemaComposition::AllOf(_) => {
+                for schema in schemas {
+                    self.validate_value(value, schema, key_range, diagnostics);
+                }
+            }
+            // NOTE: `oneOf` is validated like `anyOf`, because real-world schemas
+            //       often have overlapping branches.
+            SchemaComposition::AnyOf(_) | SchemaComposition::OneOf(_) => {
+                let mut candidates = vec![];
+                for schema in &schemas {
+                    let mut branch_diagnostics = vec![];
+                    self.validate_value(value, schema, key_range, &mut branch_diagnostics);
+                    if branch_diagnostics.is_empty() {
+                        return;
+                    }
+                    if value_schema_types(value)
+                        .iter()
+                        .any(|schema_type| self.document_schema.accepts_type(schema, *schema_type))
+                    {
+                        candidates.push(branch_diagnostics);
+                    }
+                }
+
+                if let Some(branch_diagnostics) = candidates
+                    .into_iter()
+                    .min_by_key(|diagnostics| diagnostics.len())
+                {
+                    diagnostics.extend(branch_diagnostics);
+                } else if !schemas.is_empty() {
+                    let types = schemas
+                        .iter()
+                        .flat_map(|schema| self.document_schema.flatten(schema))
+                        .flat_map(|schema| schema.types.iter().copied())
+                        .unique()
+                        .collect_vec();
+
+                    diagnostics.push(
+                        ErrorKind::TypeMismatch {
+                            expected: expected_types(&types),
+                            actual: ValueType::from(value),
+                        }
+                        .into_error(value.range()),
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_enumerate(
+        &self,
+        value: &document_tree::Value,
+        schema: &ObjectSchema,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if schema.const_value.is_none() && schema.enumerated_values.is_empty() {
+            return;
+        }
+
+        let actual = to_schema_value(value, self.toml_version);
+
+        if let Some(const_value) = &schema.const_value {
+            if !schema_value_eq(const_value, &actual) {
+                diagnostics.push(
+                    ErrorKind::Const {
+                        expected: const_value.to_string(),
+                        actual: actual.to_string(),
+                    }
+                    .into_error(value.range()),
+                );
+            }
+        }
+
+        if !schema.enumerated_values.is_empty()
+            && !schema
+                .enumerated_values
+                .iter()
+                .any(|enumerated_value| schema_value_eq(enumerated_value, &actual))
+        {
+            let diagnostic = ErrorKind::Enumerate {
+                expected: schema.enumerated_values.iter().join(", "),
+                actual: actual.to_string(),
+            }
+            .into_error(value.range());
+
+            diagnostics.push(
+                match closest_enumerated_value(&schema.enumerated_values, &actual) {
+                    Some(enumerated_value) => diagnostic.with_fix(Fix::new(
+                        format!("Replace with {enumerated_value}"),
+                        vec![TextEdit::new(value.range(), enumerated_value.to_string())],
+                    )),
+                    None => diagnostic,
+                },
+            );
+        }
+    }
+}
+
+/// The enumerated value closest to the actual value of the same type.
+///
+/// Strings are compared by the edit distance, and numbers by the difference.
+fn closest_enumerated_value<'a>(
+    enumerated_values: &'a [schema_store::Value],
+    actual: &schema_store::Value,
+) -> Option<&'a schema_store::Value> {
+    use schema_store::Value::*;
+
+    match actual {
+        String(actual) => enumerated_values
+            .iter()
+            .filter_map(|value| match value {
+                String(string) => Some((value, edit_distance(actual, string))),
+                _ => None,
+            })
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(value, _)| value),
+        Integer(_) | Float(_) => {
+            let as_f64 = |value: &schema_store::Value| match value {
+                Integer(integer) => Some(*integer as f64),
+                Float(float) => Some(*float),
+                _ => None,
+            };
+            let actual = as_f64(actual)?;
+            enumerated_values
+                .iter()
+                .filter_map(|value| Some((value, (as_f64(value)? - actual).abs())))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(value, _)| value)
+        }
+        _ => None,
+    }
+}
+
+/// The Levenshtein distance between the strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect_vec();
+    let mut distances = (0..=b.len()).collect_vec();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut prev_diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev_diagonal + usize::from(a_char != *b_char);
+            prev_diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(distances[j + 1] + 1);
+        }
+    }
+
+    distances[b.len()]
+}
+
+/// The JSON Schema types that the TOML value can satisfy.
+fn value_schema_types(value: &document_tree::Value) -> &'static [SchemaType] {
+    match value {
+        document_tree::Value::Boolean(_) => &[SchemaType::Boolean],
+        document_tree::Value::Integer(_) => &[SchemaType::Integer],
+        document_tree::Value::Float(_) => &[SchemaType::Numeric],
+        // NOTE: Date-time values are represented as strings in JSON Schema.
+        document_tree::Value::String(_)
+        | document_tree::Value::OffsetDateTime(_)
+        | document_tree::Value::LocalDateTime(_)
+        | document_tree::Value::LocalDate(_)
+        | document_tree::Value::LocalTime(_) => &[SchemaType::String],
+        document_tree::Value::Array(_) => &[SchemaType::Array],
+        document_tree::Value::Table(_) => &[SchemaType::Object],
+    }
+}
+
+fn accepts_value(schema: &ObjectSchema, value: &document_tree::Value) -> bool {
+    value_schema_types(value)
+        .iter()
+        .any(|schema_type| schema.accepts_type(*schema_type))
+}
+
+fn expected_types(types: &[SchemaType]) -> String {
+    types
+        .iter()
+        .map(|schema_type| match schema_type {
+            SchemaType::Null => "Null",
+            SchemaType::Boolean => "Boolean",
+            SchemaType::Integer => "Integer",
+            SchemaType::Numeric => "Float",
+            SchemaType::String => "String",
+            SchemaType::Array => "Array",
+            SchemaType::Object => "Table",
+        })
+        .join(" | ")
+}
+
+/// Convert the TOML value to a JSON Schema value to compare with `enum` and `const`.
+fn to_schema_value(value: &document_tree::Value, toml_version: TomlVersion) -> schema_store::Value {
+    match value {
+        document_tree::Value::Boolean(boolean) => schema_store::Value::Boolean(boolean.value()),
+        document_tree::Value::Integer(integer) => schema_store::Value::Integer(integer.value()),
+        document_tree::Value::Float(float) => schema_store::Value::Float(float.value()),
+        document_tree::Value::String(string) => {
+            schema_store::Value::String(string.to_raw_string(toml_version))
+        }
+        document_tree::Value::OffsetDateTime(date_time) => {
+            schema_store::Value::String(date_time.text().to_string())
+        }
+        document_tree::Value::LocalDateTime(date_time) => {
+            schema_store::Value::String(date_time.text().to_string())
+        }
+        document_tree::Value::LocalDate(date) => {
+            schema_store::Value::String(date.text().to_string())
+        }
+        document_tree::Value::LocalTime(time) => {
+            schema_store::Value::String(time.text().to_string())
+        }
+        document_tree::Value::Array(array) => schema_store::Value::Array(
+            array
+                .values()
+                .iter()
+                .map(|value| to_schema_value(value, toml_version))
+                .collect(),
+        ),
+        document_tree::Value::Table(table) => schema_store::Value::Object(
+            table
+                .key_values()
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        key.to_raw_text(toml_version),
+                        to_schema_value(value, toml_version),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Compare the values the way JSON does, where `1` and `1.0` are the same number.
+fn schema_value_eq(a: &schema_store::Value, b: &schema_store::Value) -> bool {
+    use schema_store::Value::*;
+
+    match (a, b) {
+        (Integer(a), Float(b)) | (Float(b), Integer(a)) => *a as f64 == *b,
+        (Array(a), Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| schema_value_eq(a, b))
+        }
+        (Object(a), Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| schema_value_eq(a, b)))
+        }
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ast::AstNode;
+    use document_tree::TryIntoDocumentTree;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn schema() -> DocumentSchema {
+        schema_store::parse_document_schema(serde_json::json!({
+            "type": "object",
+            "additionalProperties": false,
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "pattern": "^[a-z]+$", "maxLength": 8 },
+                "edition": { "enum": ["2018", "2021"] },
+                "jobs": { "type": "integer", "minimum": 1 },
+                "keywords": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "maxItems": 2,
+                    "uniqueItems": true,
+                },
+                "point": {
+                    "type": "array",
+                    "items": [{ "type": "string" }, { "type": "integer" }],
+                },
+                "version": {
+                    "anyOf": [{ "type": "string" }, { "$ref": "#/definitions/Workspace" }],
+                },
+            },
+            "definitions": {
+                "Workspace": {
+                    "type": "object",
+                    "properties": { "workspace": { "const": true } },
+                    "required": ["workspace"],
+                },
+            },
+        }))
+    }
+
+    fn validate(source: &str) -> Vec<(String, text::Range)> {
+        let root =
+            ast::Root::cast(parser::parse(source, TomlVersion::default()).into_syntax_node())
+                .unwrap()
+                .try_into_document_tree(TomlVersion::default())
+                .unwrap();
+
+        Validator::new(&schema(), TomlVersion::default())
+            .validate(&root)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.message().to_string(), diagnostic.range()))
+            .collect()
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> text::Range {
+        text::Range::new(start.into(), end.into())
+    }
+
+    #[rstest]
+    #[case("edition = \"2012\"", Some("2018"))]
+    #[case("edition = \"201\"", Some("2018"))]
+    #[case("edition = \"2022\"", Some("2021"))]
+    fn closest_enumerated_value_fix(#[case] source: &str, #[case] expected: Option<&str>) {
+        let root =
+            ast::Root::cast(parser::parse(source, TomlVersion::default()).into_syntax_node())
+                .unwrap()
+                .try_into_document_tree(TomlVersion::default())
+                .unwrap();
+
+        let fix = Validator::new(&schema(), TomlVersion::default())
+            .validate(&root)
+            .into_iter()
+            .find_map(|diagnostic| diagnostic.fix().cloned());
+
+        assert_eq!(
+            fix.map(|fix| fix.edits()[0].new_text().to_string()),
+            expected.map(|expected| format!("\"{expected}\""))
+        );
+    }
+
+    #[rstest]
+    #[case("kitten", "sitting", 3)]
+    #[case("", "abc", 3)]
+    #[case("2015", "2018", 1)]
+    fn edit_distance(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        assert_eq!(super::edit_distance(a, b), expected);
+    }
+
+    #[rstest]
+    #[case(r#"name = "tombi""#)]
+    #[case(
+        r#"name = "tombi"
+edition = "2021"
+jobs = 4
+keywords = ["toml", "lint"]
+version = "0.1.0""#
+    )]
+    #[case(
+        r#"name = "tombi"
+version = { workspace = true }"#
+    )]
+    #[case(
+        r#"name = "tombi"
+point = ["x", 1]"#
+    )]
+    fn valid(#[case] source: &str) {
+        assert_eq!(validate(source), vec![]);
+    }
+
+    #[rstest]
+    #[case("name = 1", "Expected a value of type String, but found Integer", range((0, 7), (0, 8)))]
+    #[case(r#"name = "Tombi""#, r#"The value must match the pattern "^[a-z]+$", but found "Tombi""#, range((0, 7), (0, 14)))]
+    #[case(r#"name = "abcdefghi""#, "The length must be at most 8, but found 9", range((0, 7), (0, 18)))]
+    #[case("edition = \"2015\"\nname = \"a\"", r#"Expected one of ["2018", "2021"], but found "2015""#, range((0, 10), (0, 16)))]
+    #[case("jobs = 0\nname = \"a\"", "The value must be greater than or equal to 1, but found 0", range((0, 7), (0, 8)))]
+    #[case("foo = 1\nname = \"a\"", "\"foo\" is not allowed", range((0, 0), (0, 3)))]
+    #[case("jobs = 1", "\"name\" is required", range((0, 0), (0, 8)))]
+    #[case("jobs = 1\n\n[version]\nworkspace = true", "\"name\" is required", range((0, 0), (3, 16)))]
+    #[case("keywords = [\"a\", \"a\"]\nname = \"a\"", r#"The array items must be unique, but found a duplicate "a""#, range((0, 17), (0, 20)))]
+    #[case("keywords = [\"a\", \"b\", \"c\"]\nname = \"a\"", "The array must have at most 2 items, but found 3", range((0, 11), (0, 26)))]
+    #[case("version = { workspace = false }\nname = \"a\"", "Expected true, but found false", range((0, 24), (0, 29)))]
+    #[case("version = 1\nname = \"a\"", "Expected a value of type String | Table, but found Integer", range((0, 10), (0, 11)))]
+    fn invalid(#[case] source: &str, #[case] message: &str, #[case] range: text::Range) {
+        assert_eq!(validate(source), vec![(message.to_string(), range)]);
+    }
+}
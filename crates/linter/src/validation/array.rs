@@ -0,0 +1,72 @@
+use diagnostic::Diagnostic;
+use schema_store::ObjectSchema;
+
+use super::{schema_value_eq, to_schema_value, Validator};
+use crate::ErrorKind;
+
+impl Validator<'_> {
+    pub(super) fn validate_array(
+        &self,
+        array: &document_tree::Array,
+        schema: &ObjectSchema,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if let Some(items) = schema
+            .items
+            .as_deref()
+            .and_then(|items| self.document_schema.resolve(items))
+        {
+            for value in array.values() {
+                self.validate_value(value, items, value.range(), diagnostics);
+            }
+        }
+
+        let length = array.values().len();
+
+        if let Some(min_items) = schema.min_items {
+            if length < min_items {
+                diagnostics.push(
+                    ErrorKind::MinItems {
+                        min_items,
+                        actual: length,
+                    }
+                    .into_error(array.range()),
+                );
+            }
+        }
+
+        if let Some(max_items) = schema.max_items {
+            if length > max_items {
+                diagnostics.push(
+                    ErrorKind::MaxItems {
+                        max_items,
+                        actual: length,
+                    }
+                    .into_error(array.range()),
+                );
+            }
+        }
+
+        if schema.unique_items {
+            let values = array
+                .values()
+                .iter()
+                .map(|value| to_schema_value(value, self.toml_version))
+                .collect::<Vec<_>>();
+
+            for (index, value) in values.iter().enumerate() {
+                if values[..index]
+                    .iter()
+                    .any(|other| schema_value_eq(other, value))
+                {
+                    diagnostics.push(
+                        ErrorKind::UniqueItems {
+                            actual: value.to_string(),
+                        }
+                        .into_error(array.values()[index].range()),
+                    );
+                }
+            }
+        }
+    }
+}
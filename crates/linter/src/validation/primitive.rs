@@ -0,0 +1,122 @@
+use diagnostic::Diagnostic;
+use schema_store::ObjectSchema;
+
+use crate::ErrorKind;
+
+pub(super) fn validate_number(
+    number: f64,
+    value: &document_tree::Value,
+    schema: &ObjectSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let actual = || match value {
+        document_tree::Value::Integer(integer) => integer.value().to_string(),
+        _ => number.to_string(),
+    };
+
+    if let Some(minimum) = schema.minimum {
+        if number < minimum {
+            diagnostics.push(
+                ErrorKind::Minimum {
+                    minimum,
+                    actual: actual(),
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+
+    if let Some(maximum) = schema.maximum {
+        if number > maximum {
+            diagnostics.push(
+                ErrorKind::Maximum {
+                    maximum,
+                    actual: actual(),
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+
+    if let Some(minimum) = schema.exclusive_minimum {
+        if number <= minimum {
+            diagnostics.push(
+                ErrorKind::ExclusiveMinimum {
+                    minimum,
+                    actual: actual(),
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+
+    if let Some(maximum) = schema.exclusive_maximum {
+        if number >= maximum {
+            diagnostics.push(
+                ErrorKind::ExclusiveMaximum {
+                    maximum,
+                    actual: actual(),
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+
+    if let Some(multiple_of) = schema.multiple_of {
+        let quotient = number / multiple_of;
+        if multiple_of > 0.0 && (quotient - quotient.round()).abs() > 1e-9 {
+            diagnostics.push(
+                ErrorKind::MultipleOf {
+                    multiple_of,
+                    actual: actual(),
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+}
+
+pub(super) fn validate_string(
+    string: &str,
+    value: &document_tree::Value,
+    schema: &ObjectSchema,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let length = string.chars().count();
+
+    if let Some(min_length) = schema.min_length {
+        if length < min_length {
+            diagnostics.push(
+                ErrorKind::MinLength {
+                    min_length,
+                    actual: length,
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+
+    if let Some(max_length) = schema.max_length {
+        if length > max_length {
+            diagnostics.push(
+                ErrorKind::MaxLength {
+                    max_length,
+                    actual: length,
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+
+    if let Some(pattern) = &schema.pattern {
+        if !pattern.is_match(string) {
+            diagnostics.push(
+                ErrorKind::Pattern {
+                    pattern: pattern.to_string(),
+                    actual: schema_store::Value::String(string.to_owned()).to_string(),
+                }
+                .into_error(value.range()),
+            );
+        }
+    }
+}
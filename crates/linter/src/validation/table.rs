@@ -0,0 +1,83 @@
+use diagnostic::Diagnostic;
+use schema_store::ObjectSchema;
+
+use super::Validator;
+use crate::ErrorKind;
+
+impl Validator<'_> {
+    pub(super) fn validate_table(
+        &self,
+        table: &document_tree::Table,
+        schema: &ObjectSchema,
+        key_range: text::Range,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let keys = table
+            .key_values()
+            .keys()
+            .map(|key| key.to_raw_text(self.toml_version))
+            .collect::<Vec<_>>();
+
+        for ((key, value), key_text) in table.key_values().iter().zip(&keys) {
+            match schema.property(key_text) {
+                Some(referable) => {
+                    if let Some(property_schema) = self.document_schema.resolve(referable) {
+                        self.validate_value(value, property_schema, key.range(), diagnostics);
+                    }
+                }
+                None => {
+                    // NOTE: The key may be defined by the schemas of `allOf`, `anyOf` and `oneOf`.
+                    if !schema.additional_properties
+                        && !self
+                            .document_schema
+                            .flatten(schema)
+                            .iter()
+                            .any(|schema| schema.property(key_text).is_some())
+                    {
+                        diagnostics.push(
+                            ErrorKind::KeyNotAllowed {
+                                key: key_text.to_owned(),
+                            }
+                            .into_error(key.range()),
+                        );
+                    }
+                }
+            }
+        }
+
+        for required in &schema.required {
+            if !keys.contains(required) {
+                diagnostics.push(
+                    ErrorKind::KeyRequired {
+                        key: required.to_owned(),
+                    }
+                    .into_error(key_range),
+                );
+            }
+        }
+
+        if let Some(min_properties) = schema.min_properties {
+            if keys.len() < min_properties {
+                diagnostics.push(
+                    ErrorKind::MinProperties {
+                        min_properties,
+                        actual: keys.len(),
+                    }
+                    .into_error(key_range),
+                );
+            }
+        }
+
+        if let Some(max_properties) = schema.max_properties {
+            if keys.len() > max_properties {
+                diagnostics.push(
+                    ErrorKind::MaxProperties {
+                        max_properties,
+                        actual: keys.len(),
+                    }
+                    .into_error(key_range),
+                );
+            }
+        }
+    }
+}
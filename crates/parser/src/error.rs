@@ -1,3 +1,9 @@
+#[cfg(feature = "diagnostic")]
+mod code;
+
+#[cfg(feature = "diagnostic")]
+pub use code::CODES;
+
 #[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u16)]
 pub enum ErrorKind {
@@ -135,9 +141,9 @@ impl From<lexer::Error> for Error {
 #[cfg(feature = "diagnostic")]
 impl diagnostic::SetDiagnostics for Error {
     fn set_diagnostic(&self, diagnostics: &mut Vec<diagnostic::Diagnostic>) {
-        diagnostics.push(diagnostic::Diagnostic::new_error(
-            self.to_message(),
-            self.range(),
-        ));
+        diagnostics.push(
+            diagnostic::Diagnostic::new_error(self.to_message(), self.range())
+                .with_code(self.kind.code()),
+        );
     }
 }
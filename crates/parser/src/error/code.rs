@@ -0,0 +1,544 @@
+use diagnostic::DiagnosticCode;
+
+use super::ErrorKind;
+
+pub const INVALID_KEY: DiagnosticCode = DiagnosticCode::new(
+    "T0001",
+    "invalid-key",
+    r#"The key contains characters that are not allowed in a bare key.
+
+A bare key may only contain ASCII letters, ASCII digits, underscores and dashes.
+Other keys must be quoted.
+
+Invalid:
+
+```toml
+key name = "tombi"
+```
+
+Fixed:
+
+```toml
+"key name" = "tombi"
+```
+"#,
+);
+
+pub const INVALID_BASIC_STRING: DiagnosticCode = DiagnosticCode::new(
+    "T0002",
+    "invalid-basic-string",
+    r#"The basic string is not closed, or contains a line break.
+
+A basic string is surrounded by double quotes and must be on a single line.
+Use a multi-line basic string for the text with line breaks.
+
+Invalid:
+
+```toml
+description = "TOML formatter
+```
+
+Fixed:
+
+```toml
+description = "TOML formatter"
+```
+"#,
+);
+
+pub const INVALID_LITERAL_STRING: DiagnosticCode = DiagnosticCode::new(
+    "T0003",
+    "invalid-literal-string",
+    r#"The literal string is not closed, or contains a line break.
+
+A literal string is surrounded by single quotes and must be on a single line.
+
+Invalid:
+
+```toml
+path = 'C:\Users
+```
+
+Fixed:
+
+```toml
+path = 'C:\Users'
+```
+"#,
+);
+
+pub const INVALID_MULTI_LINE_BASIC_STRING: DiagnosticCode = DiagnosticCode::new(
+    "T0004",
+    "invalid-multi-line-basic-string",
+    r#"The multi-line basic string is not closed.
+
+A multi-line basic string is surrounded by three double quotes.
+
+Invalid:
+
+```toml
+description = """
+TOML formatter
+```
+
+Fixed:
+
+```toml
+description = """
+TOML formatter
+"""
+```
+"#,
+);
+
+pub const INVALID_MULTI_LINE_LITERAL_STRING: DiagnosticCode = DiagnosticCode::new(
+    "T0005",
+    "invalid-multi-line-literal-string",
+    r#"The multi-line literal string is not closed.
+
+A multi-line literal string is surrounded by three single quotes.
+
+Invalid:
+
+```toml
+regex = '''
+\d{2}
+```
+
+Fixed:
+
+```toml
+regex = '''
+\d{2}
+'''
+```
+"#,
+);
+
+pub const INVALID_NUMBER: DiagnosticCode = DiagnosticCode::new(
+    "T0006",
+    "invalid-number",
+    r#"The number is not a valid integer or float.
+
+Underscores must be surrounded by digits, leading zeros are not allowed,
+and a float must have digits on both sides of the decimal point.
+
+Invalid:
+
+```toml
+port = 08_080
+ratio = .5
+```
+
+Fixed:
+
+```toml
+port = 8_080
+ratio = 0.5
+```
+"#,
+);
+
+pub const INVALID_OFFSET_DATE_TIME: DiagnosticCode = DiagnosticCode::new(
+    "T0007",
+    "invalid-offset-date-time",
+    r#"The offset date-time is not in the RFC 3339 format.
+
+Invalid:
+
+```toml
+released = 2024-1-1T00:00:00+09:00
+```
+
+Fixed:
+
+```toml
+released = 2024-01-01T00:00:00+09:00
+```
+"#,
+);
+
+pub const INVALID_LOCAL_DATE_TIME: DiagnosticCode = DiagnosticCode::new(
+    "T0008",
+    "invalid-local-date-time",
+    r#"The local date-time is not in the RFC 3339 format without an offset.
+
+Invalid:
+
+```toml
+started = 2024-01-01T9:00:00
+```
+
+Fixed:
+
+```toml
+started = 2024-01-01T09:00:00
+```
+"#,
+);
+
+pub const INVALID_LOCAL_DATE: DiagnosticCode = DiagnosticCode::new(
+    "T0009",
+    "invalid-local-date",
+    r#"The local date is not in the `YYYY-MM-DD` format.
+
+Invalid:
+
+```toml
+birthday = 2024/01/01
+```
+
+Fixed:
+
+```toml
+birthday = 2024-01-01
+```
+"#,
+);
+
+pub const INVALID_LOCAL_TIME: DiagnosticCode = DiagnosticCode::new(
+    "T0010",
+    "invalid-local-time",
+    r#"The local time is not in the `HH:MM:SS` format.
+
+Invalid:
+
+```toml
+alarm = 7:30:00
+```
+
+Fixed:
+
+```toml
+alarm = 07:30:00
+```
+"#,
+);
+
+pub const INVALID_LINE_BREAK: DiagnosticCode = DiagnosticCode::new(
+    "T0011",
+    "invalid-line-break",
+    r#"The line break is not LF or CRLF.
+
+TOML allows only LF (`\n`) and CRLF (`\r\n`) as line breaks.
+A carriage return (`\r`) alone is not a line break.
+
+Invalid:
+
+```toml
+key = 1␍other = 2
+```
+
+Fixed:
+
+```toml
+key = 1
+other = 2
+```
+"#,
+);
+
+pub const INVALID_TOKEN: DiagnosticCode = DiagnosticCode::new(
+    "T0012",
+    "invalid-token",
+    r#"The text is not a valid token of TOML.
+
+Invalid:
+
+```toml
+key = @value
+```
+
+Fixed:
+
+```toml
+key = "@value"
+```
+"#,
+);
+
+pub const UNKNOWN_LINE: DiagnosticCode = DiagnosticCode::new(
+    "T0013",
+    "unknown-line",
+    r#"The line is not a key value, a table header or an array of tables header.
+
+Invalid:
+
+```toml
+[package]
+tombi
+```
+
+Fixed:
+
+```toml
+[package]
+name = "tombi"
+```
+"#,
+);
+
+pub const EXPECTED_KEY: DiagnosticCode = DiagnosticCode::new(
+    "T0014",
+    "expected-key",
+    r#"A key is expected.
+
+Table headers and dotted keys must not have empty parts.
+
+Invalid:
+
+```toml
+[]
+package. = "tombi"
+```
+
+Fixed:
+
+```toml
+[package]
+package.name = "tombi"
+```
+"#,
+);
+
+pub const EXPECTED_VALUE: DiagnosticCode = DiagnosticCode::new(
+    "T0015",
+    "expected-value",
+    r#"A value is expected after `=`.
+
+TOML has no null value, so the key must be removed if it has no value.
+
+Invalid:
+
+```toml
+name =
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+```
+"#,
+);
+
+pub const EXPECTED_EQUAL: DiagnosticCode = DiagnosticCode::new(
+    "T0016",
+    "expected-equal",
+    r#"`=` is expected between the key and the value.
+
+Invalid:
+
+```toml
+name: "tombi"
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+```
+"#,
+);
+
+pub const EXPECTED_COMMA: DiagnosticCode = DiagnosticCode::new(
+    "T0017",
+    "expected-comma",
+    r#"`,` is expected between the values of an array or the key values of an inline table.
+
+Invalid:
+
+```toml
+authors = ["Alice" "Bob"]
+```
+
+Fixed:
+
+```toml
+authors = ["Alice", "Bob"]
+```
+"#,
+);
+
+pub const EXPECTED_BRACKET_END: DiagnosticCode = DiagnosticCode::new(
+    "T0018",
+    "expected-bracket-end",
+    r#"`]` is expected to close the array or the table header.
+
+Invalid:
+
+```toml
+[package
+authors = ["Alice", "Bob"
+```
+
+Fixed:
+
+```toml
+[package]
+authors = ["Alice", "Bob"]
+```
+"#,
+);
+
+pub const EXPECTED_DOUBLE_BRACKET_END: DiagnosticCode = DiagnosticCode::new(
+    "T0019",
+    "expected-double-bracket-end",
+    r#"`]]` is expected to close the array of tables header.
+
+Invalid:
+
+```toml
+[[bin]
+name = "tombi"
+```
+
+Fixed:
+
+```toml
+[[bin]]
+name = "tombi"
+```
+"#,
+);
+
+pub const EXPECTED_BRACE_END: DiagnosticCode = DiagnosticCode::new(
+    "T0020",
+    "expected-brace-end",
+    r#"`}` is expected to close the inline table.
+
+Invalid:
+
+```toml
+serde = { version = "1.0", features = ["derive"]
+```
+
+Fixed:
+
+```toml
+serde = { version = "1.0", features = ["derive"] }
+```
+"#,
+);
+
+pub const EXPECTED_LINE_BREAK: DiagnosticCode = DiagnosticCode::new(
+    "T0021",
+    "expected-line-break",
+    r#"A line break is expected after the key value or the table header.
+
+Each key value and table header must be on its own line.
+
+Invalid:
+
+```toml
+name = "tombi" version = "0.1.0"
+```
+
+Fixed:
+
+```toml
+name = "tombi"
+version = "0.1.0"
+```
+"#,
+);
+
+pub const INLINE_TABLE_MUST_SINGLE_LINE: DiagnosticCode = DiagnosticCode::new(
+    "T0022",
+    "inline-table-must-single-line",
+    r#"The inline table spans multiple lines, which is not allowed in TOML v1.0.0.
+
+Use a table instead, or TOML v1.1.0 which allows multi-line inline tables.
+
+Invalid:
+
+```toml
+serde = {
+    version = "1.0"
+}
+```
+
+Fixed:
+
+```toml
+serde = { version = "1.0" }
+```
+"#,
+);
+
+pub const FORBIDDEN_INLINE_TABLE_LAST_COMMA: DiagnosticCode = DiagnosticCode::new(
+    "T0023",
+    "forbidden-inline-table-last-comma",
+    r#"The inline table has a trailing comma, which is not allowed in TOML v1.0.0.
+
+Invalid:
+
+```toml
+serde = { version = "1.0", }
+```
+
+Fixed:
+
+```toml
+serde = { version = "1.0" }
+```
+"#,
+);
+
+/// All the diagnostic codes of the parser.
+pub const CODES: &[DiagnosticCode] = &[
+    INVALID_KEY,
+    INVALID_BASIC_STRING,
+    INVALID_LITERAL_STRING,
+    INVALID_MULTI_LINE_BASIC_STRING,
+    INVALID_MULTI_LINE_LITERAL_STRING,
+    INVALID_NUMBER,
+    INVALID_OFFSET_DATE_TIME,
+    INVALID_LOCAL_DATE_TIME,
+    INVALID_LOCAL_DATE,
+    INVALID_LOCAL_TIME,
+    INVALID_LINE_BREAK,
+    INVALID_TOKEN,
+    UNKNOWN_LINE,
+    EXPECTED_KEY,
+    EXPECTED_VALUE,
+    EXPECTED_EQUAL,
+    EXPECTED_COMMA,
+    EXPECTED_BRACKET_END,
+    EXPECTED_DOUBLE_BRACKET_END,
+    EXPECTED_BRACE_END,
+    EXPECTED_LINE_BREAK,
+    INLINE_TABLE_MUST_SINGLE_LINE,
+    FORBIDDEN_INLINE_TABLE_LAST_COMMA,
+];
+
+impl ErrorKind {
+    /// The stable code of the error.
+    pub const fn code(&self) -> DiagnosticCode {
+        match self {
+            Self::InvalidKey => INVALID_KEY,
+            Self::InvalidBasicString => INVALID_BASIC_STRING,
+            Self::InvalidLiteralString => INVALID_LITERAL_STRING,
+            Self::InvalidMultilineBasicString => INVALID_MULTI_LINE_BASIC_STRING,
+            Self::InvalidMultilineLiteralString => INVALID_MULTI_LINE_LITERAL_STRING,
+            Self::InvalidNumber => INVALID_NUMBER,
+            Self::InvalidOffsetDateTime => INVALID_OFFSET_DATE_TIME,
+            Self::InvalidLocalDateTime => INVALID_LOCAL_DATE_TIME,
+            Self::InvalidLocalDate => INVALID_LOCAL_DATE,
+            Self::InvalidLocalTime => INVALID_LOCAL_TIME,
+            Self::InvalidLineBreak => INVALID_LINE_BREAK,
+            Self::InvalidToken => INVALID_TOKEN,
+            Self::UnknownLine => UNKNOWN_LINE,
+            Self::ExpectedKey => EXPECTED_KEY,
+            Self::ExpectedValue => EXPECTED_VALUE,
+            Self::ExpectedEqual => EXPECTED_EQUAL,
+            Self::ExpectedComma => EXPECTED_COMMA,
+            Self::ExpectedBracketEnd => EXPECTED_BRACKET_END,
+            Self::ExpectedDoubleBracketEnd => EXPECTED_DOUBLE_BRACKET_END,
+            Self::ExpectedBraceEnd => EXPECTED_BRACE_END,
+            Self::ExpectedLineBreak => EXPECTED_LINE_BREAK,
+            Self::InlineTableMustSingleLine => INLINE_TABLE_MUST_SINGLE_LINE,
+            Self::ForbiddenInlineTableLastComma => FORBIDDEN_INLINE_TABLE_LAST_COMMA,
+        }
+    }
+}
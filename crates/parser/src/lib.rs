@@ -11,13 +11,15 @@ mod parser;
 mod token_set;
 
 use config::TomlVersion;
+#[cfg(feature = "diagnostic")]
+pub use error::CODES;
 pub use error::{Error, ErrorKind};
 pub use event::Event;
 use lexed::lex;
 pub use lexed::LexedStr;
 use output::Output;
 use parse::Parse;
-use parsed::Parsed;
+pub use parsed::Parsed;
 pub use syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
 pub fn parse(source: &str, toml_version: TomlVersion) -> Parsed<SyntaxNode> {
@@ -23,6 +23,10 @@ impl Accessors {
     pub fn new(accessors: Vec<Accessor>) -> Self {
         Self(accessors)
     }
+
+    pub fn as_slice(&self) -> &[Accessor] {
+        &self.0
+    }
 }
 
 impl std::fmt::Display for Accessors {
@@ -4,6 +4,7 @@ mod schema_type;
 mod value;
 
 pub use catalog::{JsonCatalog, DEFAULT_CATALOG_URL};
+pub(crate) use reference::normalize_reference;
 pub use reference::Referable;
 pub use schema_type::{SchemaComposition, SchemaType};
 pub use value::Value;
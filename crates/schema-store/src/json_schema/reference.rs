@@ -1,26 +1,135 @@
-use crate::{schema::DocumentSchema, SchemaStore};
+use crate::schema::ObjectSchema;
+
+use super::SchemaComposition;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Referable<T> {
+    /// A `$ref` that has not been replaced by its schema.
+    ///
+    /// After the document is loaded by `SchemaStore`, the reference is either
+    /// a local JSON pointer (`#/definitions/Package`) or an absolute URL,
+    /// and is the key of `DocumentSchema::definitions`.
     Ref(String),
     Schema(Result<T, ()>),
 }
 
-impl<T> Referable<T> {
-    pub fn take_schema(
-        &mut self,
-        _document: &DocumentSchema,
-        _store: &SchemaStore,
-    ) -> Result<&T, &()> {
+impl<T> From<T> for Referable<T> {
+    fn from(schema: T) -> Self {
+        Referable::Schema(Ok(schema))
+    }
+}
+
+impl Referable<ObjectSchema> {
+    /// Collect the references of this schema and all of its subschemas.
+    pub fn references(&self) -> Vec<String> {
+        let mut references = vec![];
+        self.collect_references(&mut references);
+        references
+    }
+
+    fn collect_references(&self, references: &mut Vec<String>) {
         match self {
-            Referable::Schema(s) => s.as_ref(),
-            Referable::Ref(_) => Err(&()),
+            Referable::Ref(reference) => references.push(reference.to_owned()),
+            Referable::Schema(Ok(schema)) => schema.collect_references(references),
+            Referable::Schema(Err(_)) => {}
+        }
+    }
+
+    /// Rewrite the references of this schema and all of its subschemas.
+    pub fn map_references(&mut self, f: &mut impl FnMut(&str) -> String) {
+        match self {
+            Referable::Ref(reference) => *reference = f(reference),
+            Referable::Schema(Ok(schema)) => schema.map_references(f),
+            Referable::Schema(Err(_)) => {}
         }
     }
 }
 
-impl<T> From<T> for Referable<T> {
-    fn from(schema: T) -> Self {
-        Referable::Schema(Ok(schema))
+impl ObjectSchema {
+    pub(crate) fn collect_references(&self, references: &mut Vec<String>) {
+        for schema in self.subschemas() {
+            schema.collect_references(references);
+        }
+    }
+
+    pub(crate) fn map_references(&mut self, f: &mut impl FnMut(&str) -> String) {
+        for schema in self.subschemas_mut() {
+            schema.map_references(f);
+        }
+    }
+
+    fn subschemas(&self) -> impl Iterator<Item = &Referable<ObjectSchema>> {
+        self.properties
+            .values()
+            .chain(self.pattern_properties.values())
+            .chain(self.additional_property_schema.as_deref())
+            .chain(self.items.as_deref())
+            .chain(self.compositions.iter().flat_map(|c| c.schemas()))
+    }
+
+    fn subschemas_mut(&mut self) -> impl Iterator<Item = &mut Referable<ObjectSchema>> {
+        self.properties
+            .values_mut()
+            .chain(self.pattern_properties.values_mut())
+            .chain(self.additional_property_schema.as_deref_mut())
+            .chain(self.items.as_deref_mut())
+            .chain(
+                self.compositions
+                    .iter_mut()
+                    .flat_map(SchemaComposition::schemas_mut),
+            )
+    }
+}
+
+/// Normalize a `$ref` found in the document at `base_url`.
+///
+/// References to the document itself become local JSON pointers (`#/...`),
+/// and the others become absolute URLs.
+pub(crate) fn normalize_reference(reference: &str, base_url: &url::Url) -> String {
+    let Ok(mut url) = base_url.join(reference) else {
+        return reference.to_owned();
+    };
+    let fragment = url.fragment().unwrap_or_default().to_owned();
+    url.set_fragment(None);
+
+    let mut base_url = base_url.to_owned();
+    base_url.set_fragment(None);
+
+    if url == base_url {
+        format!("#{fragment}")
+    } else {
+        url.set_fragment(Some(&fragment));
+        url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_local_reference() {
+        let base_url = url::Url::parse("https://example.com/schemas/cargo.json").unwrap();
+        assert_eq!(
+            normalize_reference("#/definitions/Package", &base_url),
+            "#/definitions/Package"
+        );
+        assert_eq!(
+            normalize_reference("cargo.json#/definitions/Package", &base_url),
+            "#/definitions/Package"
+        );
+    }
+
+    #[test]
+    fn normalize_relative_reference() {
+        let base_url = url::Url::parse("file:///schemas/cargo.json").unwrap();
+        assert_eq!(
+            normalize_reference("common.json#/definitions/Lints", &base_url),
+            "file:///schemas/common.json#/definitions/Lints"
+        );
+        assert_eq!(
+            normalize_reference("https://example.com/other.json", &base_url),
+            "https://example.com/other.json#"
+        );
     }
 }
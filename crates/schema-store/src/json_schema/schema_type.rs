@@ -2,20 +2,63 @@ use crate::schema::ObjectSchema;
 
 use super::Referable;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SchemaType {
     Null,
     Boolean,
+    Integer,
     Numeric,
     String,
     Array,
     Object,
 }
 
+impl SchemaType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "null" => Some(Self::Null),
+            "boolean" => Some(Self::Boolean),
+            "integer" => Some(Self::Integer),
+            "number" => Some(Self::Numeric),
+            "string" => Some(Self::String),
+            "array" => Some(Self::Array),
+            "object" => Some(Self::Object),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Integer => write!(f, "integer"),
+            Self::Numeric => write!(f, "number"),
+            Self::String => write!(f, "string"),
+            Self::Array => write!(f, "array"),
+            Self::Object => write!(f, "object"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SchemaComposition {
-    Type(SchemaType),
     OneOf(Vec<Referable<ObjectSchema>>),
     AnyOf(Vec<Referable<ObjectSchema>>),
     AllOf(Vec<Referable<ObjectSchema>>),
 }
+
+impl SchemaComposition {
+    pub fn schemas(&self) -> &[Referable<ObjectSchema>] {
+        match self {
+            Self::OneOf(schemas) | Self::AnyOf(schemas) | Self::AllOf(schemas) => schemas,
+        }
+    }
+
+    pub fn schemas_mut(&mut self) -> &mut Vec<Referable<ObjectSchema>> {
+        match self {
+            Self::OneOf(schemas) | Self::AnyOf(schemas) | Self::AllOf(schemas) => schemas,
+        }
+    }
+}
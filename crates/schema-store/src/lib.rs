@@ -7,9 +7,9 @@ mod value_type;
 
 pub use accessor::{Accessor, Accessors};
 pub use error::Error;
-use json_schema::SchemaComposition;
-pub use json_schema::{SchemaType, Value, DEFAULT_CATALOG_URL};
-pub use schema::{DocumentSchema, ObjectSchema};
+use indexmap::IndexMap;
+pub use json_schema::{Referable, SchemaComposition, SchemaType, Value, DEFAULT_CATALOG_URL};
+pub use schema::{DocumentSchema, ObjectSchema, Pattern};
 pub use store::SchemaStore;
 pub use value_type::ValueType;
 
@@ -123,79 +123,197 @@ fn get_item_table<'a>(
     }
 }
 
-pub fn parse_document_schema(mut content: serde_json::Value) -> DocumentSchema {
-    let mut schema = DocumentSchema::default();
+pub fn parse_document_schema(content: serde_json::Value) -> DocumentSchema {
+    let serde_json::Value::Object(mut object) = content else {
+        return DocumentSchema::default();
+    };
 
-    if content.get("properties").is_some() {
-        if let serde_json::Value::Object(object) = content["properties"].take() {
-            for (key, value) in object.into_iter() {
-                if let Some(value_schema) = parse_value_schema(value) {
-                    schema
-                        .properties
-                        .insert(Accessor::Key(key.to_string()), value_schema);
+    let mut definitions = IndexMap::new();
+    for keyword in ["definitions", "$defs"] {
+        if let Some(serde_json::Value::Object(defs)) = object.remove(keyword) {
+            for (key, value) in defs {
+                if let Some(schema) = parse_referable_schema(value) {
+                    definitions.insert(format!("#/{keyword}/{key}"), schema);
                 }
             }
         }
     }
-    if content.get("definitions").is_some() {
-        if let serde_json::Value::Object(object) = content["definitions"].take() {
-            for (key, value) in object.into_iter() {
-                if let Some(value_schema) = parse_value_schema(value) {
-                    schema.definitions.insert(key, value_schema);
-                }
+
+    DocumentSchema {
+        schema_url: None,
+        value_schema: parse_object_schema(object),
+        definitions,
+    }
+}
+
+/// Parse a JSON Schema that may be a `$ref` or a boolean schema.
+fn parse_referable_schema(value: serde_json::Value) -> Option<Referable<ObjectSchema>> {
+    match value {
+        serde_json::Value::Object(object) => {
+            // NOTE: In Draft 7 and earlier, the siblings of `$ref` are ignored.
+            if let Some(serde_json::Value::String(reference)) = object.get("$ref") {
+                return Some(Referable::Ref(reference.to_owned()));
             }
+            Some(parse_object_schema(object).into())
         }
+        serde_json::Value::Bool(true) => Some(ObjectSchema::default().into()),
+        _ => None,
     }
-    schema
 }
 
-fn parse_value_schema(object: serde_json::Value) -> Option<ObjectSchema> {
-    match object {
-        serde_json::Value::Object(object) => {
-            let mut value_schema = ObjectSchema::default();
+fn parse_referable_schemas(value: serde_json::Value) -> Vec<Referable<ObjectSchema>> {
+    match value {
+        serde_json::Value::Array(array) => array
+            .into_iter()
+            .filter_map(parse_referable_schema)
+            .collect(),
+        _ => Vec::with_capacity(0),
+    }
+}
 
-            for (key, value) in object {
-                match key.as_str() {
-                    "title" => {
-                        if let serde_json::Value::String(title) = value {
-                            value_schema.title = Some(title);
-                        }
-                    }
-                    "description" => {
-                        if let serde_json::Value::String(description) = value {
-                            value_schema.description = Some(description);
-                        }
-                    }
-                    "type" => {
-                        if let serde_json::Value::String(type_str) = value {
-                            let schema_type = match type_str.as_str() {
-                                "null" => SchemaType::Null,
-                                "boolean" => SchemaType::Boolean,
-                                "number" => SchemaType::Numeric,
-                                "string" => SchemaType::String,
-                                "array" => SchemaType::Array,
-                                "object" => SchemaType::Object,
-                                _ => continue,
-                            };
-                            value_schema.schema = Some(SchemaComposition::Type(schema_type).into());
-                        }
-                    }
-                    "default" => {
-                        value_schema.default = Some(value.into());
-                    }
-                    "enum" => {
-                        if let serde_json::Value::Array(array) = value {
-                            for value in array {
-                                value_schema.enumerated_values.push(value.into());
-                            }
-                        }
+fn parse_schema_map(value: serde_json::Value) -> IndexMap<String, Referable<ObjectSchema>> {
+    match value {
+        serde_json::Value::Object(object) => object
+            .into_iter()
+            .filter_map(|(key, value)| parse_referable_schema(value).map(|schema| (key, schema)))
+            .collect(),
+        _ => IndexMap::with_capacity(0),
+    }
+}
+
+fn parse_object_schema(object: serde_json::Map<String, serde_json::Value>) -> ObjectSchema {
+    let mut value_schema = ObjectSchema::default();
+    let mut exclusive_minimum_flag = false;
+    let mut exclusive_maximum_flag = false;
+
+    for (key, value) in object {
+        match key.as_str() {
+            "title" => {
+                if let serde_json::Value::String(title) = value {
+                    value_schema.title = Some(title);
+                }
+            }
+            "description" => {
+                if let serde_json::Value::String(description) = value {
+                    value_schema.description = Some(description);
+                }
+            }
+            "type" => match value {
+                serde_json::Value::String(type_str) => {
+                    value_schema.types.extend(SchemaType::from_name(&type_str));
+                }
+                serde_json::Value::Array(array) => {
+                    value_schema.types.extend(
+                        array
+                            .iter()
+                            .filter_map(|value| value.as_str().and_then(SchemaType::from_name)),
+                    );
+                }
+                _ => {}
+            },
+            "default" => {
+                value_schema.default = Some(value.into());
+            }
+            "const" => {
+                value_schema.const_value = Some(value.into());
+            }
+            "enum" => {
+                if let serde_json::Value::Array(array) = value {
+                    for value in array {
+                        value_schema.enumerated_values.push(value.into());
                     }
-                    _ => {}
                 }
             }
-
-            Some(value_schema)
+            "oneOf" => {
+                value_schema
+                    .compositions
+                    .push(SchemaComposition::OneOf(parse_referable_schemas(value)));
+            }
+            "anyOf" => {
+                value_schema
+                    .compositions
+                    .push(SchemaComposition::AnyOf(parse_referable_schemas(value)));
+            }
+            "allOf" => {
+                value_schema
+                    .compositions
+                    .push(SchemaComposition::AllOf(parse_referable_schemas(value)));
+            }
+            "properties" => {
+                value_schema.properties = parse_schema_map(value);
+            }
+            "patternProperties" => {
+                value_schema.pattern_properties = parse_schema_map(value)
+                    .into_iter()
+                    .filter_map(|(pattern, schema)| Some((Pattern::new(&pattern)?, schema)))
+                    .collect();
+            }
+            "additionalProperties" => match value {
+                serde_json::Value::Bool(allowed) => {
+                    value_schema.additional_properties = allowed;
+                }
+                value => {
+                    value_schema.additional_property_schema =
+                        parse_referable_schema(value).map(Box::new);
+                }
+            },
+            "required" => {
+                if let serde_json::Value::Array(array) = value {
+                    value_schema.required = array
+                        .into_iter()
+                        .filter_map(|value| match value {
+                            serde_json::Value::String(key) => Some(key),
+                            _ => None,
+                        })
+                        .collect();
+                }
+            }
+            "minProperties" => value_schema.min_properties = as_usize(&value),
+            "maxProperties" => value_schema.max_properties = as_usize(&value),
+            "items" => {
+                // NOTE: The tuple form of `items` constrains each item by its position,
+                //       which is not supported, so the items are left unconstrained.
+                value_schema.items = match value {
+                    serde_json::Value::Array(_) => None,
+                    value => parse_referable_schema(value).map(Box::new),
+                };
+            }
+            "minItems" => value_schema.min_items = as_usize(&value),
+            "maxItems" => value_schema.max_items = as_usize(&value),
+            "uniqueItems" => value_schema.unique_items = value.as_bool().unwrap_or_default(),
+            "minimum" => value_schema.minimum = value.as_f64(),
+            "maximum" => value_schema.maximum = value.as_f64(),
+            "exclusiveMinimum" => match value {
+                // NOTE: Draft 4 uses a boolean modifier of `minimum`.
+                serde_json::Value::Bool(flag) => exclusive_minimum_flag = flag,
+                value => value_schema.exclusive_minimum = value.as_f64(),
+            },
+            "exclusiveMaximum" => match value {
+                serde_json::Value::Bool(flag) => exclusive_maximum_flag = flag,
+                value => value_schema.exclusive_maximum = value.as_f64(),
+            },
+            "multipleOf" => value_schema.multiple_of = value.as_f64(),
+            "minLength" => value_schema.min_length = as_usize(&value),
+            "maxLength" => value_schema.max_length = as_usize(&value),
+            "pattern" => {
+                if let serde_json::Value::String(pattern) = value {
+                    value_schema.pattern = Pattern::new(&pattern);
+                }
+            }
+            _ => {}
         }
-        _ => None,
     }
+
+    if exclusive_minimum_flag {
+        value_schema.exclusive_minimum = value_schema.minimum.take();
+    }
+    if exclusive_maximum_flag {
+        value_schema.exclusive_maximum = value_schema.maximum.take();
+    }
+
+    value_schema
+}
+
+fn as_usize(value: &serde_json::Value) -> Option<usize> {
+    value.as_u64().map(|value| value as usize)
 }
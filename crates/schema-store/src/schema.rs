@@ -1,7 +1,9 @@
 mod catalog;
 mod document_schema;
 mod object_schema;
+mod pattern;
 
 pub use catalog::CatalogSchema;
 pub use document_schema::DocumentSchema;
 pub use object_schema::ObjectSchema;
+pub use pattern::Pattern;
@@ -1,12 +1,199 @@
-use crate::Accessor;
+use indexmap::IndexMap;
+
+use crate::{
+    json_schema::{Referable, SchemaComposition},
+    Accessor, SchemaType,
+};
 
 use super::object_schema::ObjectSchema;
 
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct DocumentSchema {
-    pub title: Option<String>,
-    pub description: Option<String>,
     pub schema_url: Option<url::Url>,
-    pub properties: ahash::HashMap<Accessor, ObjectSchema>,
-    pub definitions: ahash::HashMap<String, ObjectSchema>,
+    pub value_schema: ObjectSchema,
+    /// The schemas of `definitions` and `$defs`, keyed by their JSON pointer
+    /// (e.g. `#/definitions/Package`).
+    pub definitions: IndexMap<String, Referable<ObjectSchema>>,
+}
+
+impl DocumentSchema {
+    pub fn title(&self) -> Option<&str> {
+        self.value_schema.title.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.value_schema.description.as_deref()
+    }
+}
+
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+impl DocumentSchema {
+    /// Get the schema of a referable.
+    ///
+    /// References are looked up in `definitions`,
+    /// so `None` is returned for those that could not be resolved.
+    pub fn resolve<'a>(
+        &'a self,
+        mut referable: &'a Referable<ObjectSchema>,
+    ) -> Option<&'a ObjectSchema> {
+        // NOTE: A definition may be just an alias of another reference,
+        //       so the lookup is limited to avoid an infinite loop.
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            match referable {
+                Referable::Schema(Ok(schema)) => return Some(schema),
+                Referable::Schema(Err(_)) => return None,
+                Referable::Ref(reference) => referable = self.definitions.get(reference)?,
+            }
+        }
+        None
+    }
+
+    /// Collect the references of the document.
+    pub fn references(&self) -> Vec<String> {
+        let mut references = vec![];
+        self.value_schema.collect_references(&mut references);
+        for definition in self.definitions.values() {
+            references.extend(definition.references());
+        }
+        references
+    }
+
+    /// Rewrite the references of the document.
+    pub fn map_references(&mut self, mut f: impl FnMut(&str) -> String) {
+        self.value_schema.map_references(&mut f);
+        for definition in self.definitions.values_mut() {
+            definition.map_references(&mut f);
+        }
+        self.definitions = std::mem::take(&mut self.definitions)
+            .into_iter()
+            .map(|(key, value)| (f(&key), value))
+            .collect();
+    }
+
+    /// Get the schema at the JSON pointer (e.g. `/definitions/Package/properties/name`).
+    pub fn pointer(&self, pointer: &str) -> Option<Referable<ObjectSchema>> {
+        let mut tokens = pointer
+            .split('/')
+            .skip(1)
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .peekable();
+
+        let referable = match tokens.peek().map(String::as_str) {
+            None => return Some(self.value_schema.clone().into()),
+            Some(keyword @ ("definitions" | "$defs")) => {
+                let key = format!("#/{keyword}/");
+                tokens.next();
+                self.definitions.get(&(key + &tokens.next()?))?
+            }
+            Some(_) => subschema(&self.value_schema, &mut tokens)?,
+        };
+
+        pointer_rest(referable, tokens)
+    }
+
+    /// Get the schema itself and all schemas composed by `oneOf`, `anyOf` and `allOf`.
+    pub fn flatten<'a>(&'a self, schema: &'a ObjectSchema) -> Vec<&'a ObjectSchema> {
+        let mut schemas = vec![schema];
+        let mut index = 0;
+        while index < schemas.len() {
+            for composition in &schemas[index].compositions {
+                for referable in composition.schemas() {
+                    if let Some(schema) = self.resolve(referable) {
+                        if !schemas.iter().any(|s| std::ptr::eq(*s, schema)) {
+                            schemas.push(schema);
+                        }
+                    }
+                }
+            }
+            index += 1;
+        }
+        schemas
+    }
+
+    /// Find the schema of the value at the accessors.
+    pub fn find_schema(&self, accessors: &[Accessor]) -> Option<&ObjectSchema> {
+        let mut schema = &self.value_schema;
+        for accessor in accessors {
+            schema = self.flatten(schema).into_iter().find_map(|schema| {
+                let referable = match accessor {
+                    Accessor::Key(key) => schema.property(key),
+                    Accessor::Index(_) => schema.items.as_deref(),
+                };
+                referable.and_then(|referable| self.resolve(referable))
+            })?;
+        }
+        Some(schema)
+    }
+
+    /// Get the known properties of the schema, including those of the composed schemas.
+    pub fn properties<'a>(&'a self, schema: &'a ObjectSchema) -> Vec<(&'a str, &'a ObjectSchema)> {
+        let mut properties: Vec<(&str, &ObjectSchema)> = vec![];
+        for schema in self.flatten(schema) {
+            for (key, referable) in &schema.properties {
+                if properties.iter().any(|(k, _)| k == key) {
+                    continue;
+                }
+                if let Some(property) = self.resolve(referable) {
+                    properties.push((key, property));
+                }
+            }
+        }
+        properties
+    }
+
+    /// Returns `true` if the value of the schema can be of the given type.
+    pub fn accepts_type(&self, schema: &ObjectSchema, schema_type: SchemaType) -> bool {
+        let schemas = self.flatten(schema);
+        if schemas.iter().all(|schema| schema.types.is_empty()) {
+            return true;
+        }
+        schemas
+            .iter()
+            .filter(|schema| !schema.types.is_empty())
+            .any(|schema| schema.accepts_type(schema_type))
+    }
+}
+
+fn subschema<'a>(
+    schema: &'a ObjectSchema,
+    tokens: &mut impl Iterator<Item = String>,
+) -> Option<&'a Referable<ObjectSchema>> {
+    match tokens.next()?.as_str() {
+        "properties" => schema.properties.get(&tokens.next()?),
+        "patternProperties" => schema.pattern_properties.get(tokens.next()?.as_str()),
+        "additionalProperties" => schema.additional_property_schema.as_deref(),
+        "items" => schema.items.as_deref(),
+        keyword @ ("oneOf" | "anyOf" | "allOf") => {
+            let index: usize = tokens.next()?.parse().ok()?;
+            schema
+                .compositions
+                .iter()
+                .find(|composition| {
+                    matches!(
+                        (keyword, composition),
+                        ("oneOf", SchemaComposition::OneOf(_))
+                            | ("anyOf", SchemaComposition::AnyOf(_))
+                            | ("allOf", SchemaComposition::AllOf(_))
+                    )
+                })?
+                .schemas()
+                .get(index)
+        }
+        _ => None,
+    }
+}
+
+fn pointer_rest(
+    mut referable: &Referable<ObjectSchema>,
+    tokens: impl Iterator<Item = String>,
+) -> Option<Referable<ObjectSchema>> {
+    let mut tokens = tokens.peekable();
+    while tokens.peek().is_some() {
+        let Referable::Schema(Ok(schema)) = referable else {
+            return None;
+        };
+        referable = subschema(schema, &mut tokens)?;
+    }
+    Some(referable.clone())
 }
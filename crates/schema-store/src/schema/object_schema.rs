@@ -1,15 +1,110 @@
+use indexmap::IndexMap;
+
 use crate::{
     json_schema::{Referable, SchemaComposition},
-    Value,
+    Pattern, SchemaType, Value,
 };
 
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ObjectSchema {
     pub title: Option<String>,
     pub description: Option<String>,
-    pub schema: Option<Referable<SchemaComposition>>,
+    pub types: Vec<SchemaType>,
+    pub compositions: Vec<SchemaComposition>,
     pub default: Option<Value>,
+    pub const_value: Option<Value>,
     pub enumerated_values: Vec<Value>,
+
+    // Object
+    pub properties: IndexMap<String, Referable<ObjectSchema>>,
+    pub pattern_properties: IndexMap<Pattern, Referable<ObjectSchema>>,
+    pub additional_properties: bool,
+    pub additional_property_schema: Option<Box<Referable<ObjectSchema>>>,
+    pub required: Vec<String>,
+    pub min_properties: Option<usize>,
+    pub max_properties: Option<usize>,
+
+    // Array
+    pub items: Option<Box<Referable<ObjectSchema>>>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub unique_items: bool,
+
+    // Numeric
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub exclusive_minimum: Option<f64>,
+    pub exclusive_maximum: Option<f64>,
+    pub multiple_of: Option<f64>,
+
+    // String
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<Pattern>,
+}
+
+impl Default for ObjectSchema {
+    fn default() -> Self {
+        Self {
+            title: None,
+            description: None,
+            types: Vec::new(),
+            compositions: Vec::new(),
+            default: None,
+            const_value: None,
+            enumerated_values: Vec::new(),
+            properties: IndexMap::new(),
+            pattern_properties: IndexMap::new(),
+            additional_properties: true,
+            additional_property_schema: None,
+            required: Vec::new(),
+            min_properties: None,
+            max_properties: None,
+            items: None,
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+            minimum: None,
+            maximum: None,
+            exclusive_minimum: None,
+            exclusive_maximum: None,
+            multiple_of: None,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+        }
+    }
+}
+
+impl ObjectSchema {
+    /// Returns `true` if the value of this schema can be of the given type.
+    ///
+    /// A schema without `type` accepts any type.
+    pub fn accepts_type(&self, schema_type: SchemaType) -> bool {
+        self.types.is_empty()
+            || self.types.iter().any(|t| {
+                *t == schema_type
+                    || (*t == SchemaType::Numeric && schema_type == SchemaType::Integer)
+            })
+    }
+
+    /// Get the schema of the property with the given key.
+    ///
+    /// `properties` is looked up first, then `patternProperties`,
+    /// and finally `additionalProperties`.
+    pub fn property(&self, key: &str) -> Option<&Referable<ObjectSchema>> {
+        if let Some(schema) = self.properties.get(key) {
+            return Some(schema);
+        }
+
+        for (pattern, schema) in &self.pattern_properties {
+            if pattern.is_match(key) {
+                return Some(schema);
+            }
+        }
+
+        self.additional_property_schema.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -34,4 +129,58 @@ mod test {
             }
         })
     }
+
+    #[test]
+    fn property_of_pattern_properties() {
+        let document_schema = crate::parse_document_schema(serde_json::json!({
+            "type": "object",
+            "properties": { "name": { "type": "string" } },
+            "patternProperties": {
+                "^x-": { "type": "integer" },
+                "(?=look-around)": { "type": "boolean" },
+            },
+            "additionalProperties": false,
+        }));
+        let schema = &document_schema.value_schema;
+
+        assert_eq!(schema.pattern_properties.len(), 1);
+        assert!(schema.pattern_properties.contains_key("^x-"));
+        assert!(schema.property("name").is_some());
+        assert!(schema.property("x-custom").is_some());
+        assert!(schema.property("custom").is_none());
+    }
+
+    #[test]
+    fn pattern_compiled_once() {
+        let document_schema = crate::parse_document_schema(serde_json::json!({
+            "type": "string",
+            "pattern": "^[a-z]+$",
+        }));
+        let pattern = document_schema.value_schema.pattern.as_ref().unwrap();
+
+        assert_eq!(pattern.as_str(), "^[a-z]+$");
+        assert!(pattern.is_match("tombi"));
+        assert!(!pattern.is_match("Tombi"));
+
+        let document_schema = crate::parse_document_schema(serde_json::json!({
+            "type": "string",
+            "pattern": "(?=look-around)",
+        }));
+        assert!(document_schema.value_schema.pattern.is_none());
+    }
+
+    #[test]
+    fn tuple_items_unconstrained() {
+        let document_schema = crate::parse_document_schema(serde_json::json!({
+            "type": "array",
+            "items": [{ "type": "string" }, { "type": "integer" }],
+        }));
+        assert!(document_schema.value_schema.items.is_none());
+
+        let document_schema = crate::parse_document_schema(serde_json::json!({
+            "type": "array",
+            "items": { "type": "string" },
+        }));
+        assert!(document_schema.value_schema.items.is_some());
+    }
 }
@@ -4,13 +4,17 @@ use std::sync::{Arc, RwLock};
 use url::Url;
 
 use crate::{
-    json_schema::JsonCatalog, schema::CatalogSchema, Accessor, DocumentSchema, ObjectSchema,
+    json_schema::{normalize_reference, JsonCatalog, Referable},
+    parse_document_schema,
+    schema::CatalogSchema,
+    DocumentSchema,
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct SchemaStore {
     http_client: reqwest::Client,
     schemas: DashMap<Url, Result<DocumentSchema, crate::Error>>,
+    config_schemas: Arc<RwLock<Vec<(std::path::PathBuf, CatalogSchema)>>>,
     catalogs: Arc<RwLock<Vec<CatalogSchema>>>,
 }
 
@@ -19,10 +23,16 @@ impl SchemaStore {
         Self {
             http_client: reqwest::Client::new(),
             schemas: DashMap::new(),
+            config_schemas: Arc::new(RwLock::new(Vec::new())),
             catalogs: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
+    /// Register the schemas of the config.
+    ///
+    /// The schemas previously registered by the config in the same directory are replaced,
+    /// and their cache is cleared so that the schema files are read again.
+    /// The schemas are applied only to the files under the config directory.
     pub fn load_config_schema(
         &self,
         config_path: Option<std::path::PathBuf>,
@@ -40,7 +50,15 @@ impl SchemaStore {
             return;
         };
 
-        if let Ok(mut catalogs) = self.catalogs.write() {
+        if let Ok(mut config_schemas) = self.config_schemas.write() {
+            config_schemas.retain(|(dir, config_schema)| {
+                if dir == config_dir {
+                    self.schemas.remove(&config_schema.url);
+                    false
+                } else {
+                    true
+                }
+            });
             for schema in schemas {
                 let Ok(url) = Url::parse(&format!(
                     "file://{}",
@@ -48,10 +66,22 @@ impl SchemaStore {
                 )) else {
                     continue;
                 };
-                catalogs.push(CatalogSchema {
-                    url,
-                    include: schema.include.unwrap_or_default(),
-                });
+                config_schemas.push((
+                    config_dir.to_owned(),
+                    CatalogSchema {
+                        url,
+                        include: schema.include.unwrap_or_default(),
+                    },
+                ));
+            }
+        }
+    }
+
+    /// Unregister the schemas of all the configs.
+    pub fn clear_config_schemas(&self) {
+        if let Ok(mut config_schemas) = self.config_schemas.write() {
+            for (_, config_schema) in config_schemas.drain(..) {
+                self.schemas.remove(&config_schema.url);
             }
         }
     }
@@ -88,85 +118,161 @@ impl SchemaStore {
         }
     }
 
-    pub async fn get_schema_from_url<'a>(
-        &'a self,
+    pub async fn get_schema_from_url(&self, url: &Url) -> Result<DocumentSchema, crate::Error> {
+        self.load_schema(url, &mut Vec::new()).await
+    }
+
+    /// Load the schema at the URL and resolve its references.
+    ///
+    /// `loading` is the stack of the schemas being loaded, used to detect cyclic references.
+    async fn load_schema(
+        &self,
         url: &Url,
+        loading: &mut Vec<Url>,
     ) -> Result<DocumentSchema, crate::Error> {
-        match self.schemas.get(url) {
-            Some(document_schema) => match document_schema.value() {
-                Ok(document_schema) => Ok(document_schema.clone()),
-                Err(err) => Err(err.clone()),
-            },
-            None => {
-                let schema: schemars::Schema = match url.scheme() {
-                    "file" => {
-                        let file = std::fs::File::open(url.path()).map_err(|_| {
-                            crate::Error::SchemaFileReadFailed {
-                                schema_path: url.path().to_string(),
-                            }
-                        })?;
+        if let Some(document_schema) = self.schemas.get(url) {
+            return document_schema.value().clone();
+        }
+
+        let mut document_schema = self.fetch_schema(url).await?;
 
-                        serde_json::from_reader(file)
+        loading.push(url.to_owned());
+        self.resolve_references(&mut document_schema, loading).await;
+        loading.pop();
+
+        self.schemas
+            .insert(url.to_owned(), Ok(document_schema.clone()));
+
+        Ok(document_schema)
+    }
+
+    /// Fetch and parse the schema at the URL without resolving its references.
+    async fn fetch_schema(&self, url: &Url) -> Result<DocumentSchema, crate::Error> {
+        let schema: serde_json::Value = match url.scheme() {
+            "file" => {
+                let file = std::fs::File::open(url.path()).map_err(|_| {
+                    crate::Error::SchemaFileReadFailed {
+                        schema_path: url.path().to_string(),
                     }
-                    "http" | "https" => {
-                        let response =
-                            self.http_client
-                                .get(url.as_str())
-                                .send()
-                                .await
-                                .map_err(|_| crate::Error::SchemaFetchFailed {
-                                    schema_url: url.to_string(),
-                                })?;
-
-                        let bytes = response.bytes().await.map_err(|_| {
-                            crate::Error::SchemaFetchFailed {
-                                schema_url: url.to_string(),
-                            }
+                })?;
+
+                serde_json::from_reader(file)
+            }
+            "http" | "https" => {
+                let response = self
+                    .http_client
+                    .get(url.as_str())
+                    .send()
+                    .await
+                    .map_err(|_| crate::Error::SchemaFetchFailed {
+                        schema_url: url.to_string(),
+                    })?;
+
+                let bytes =
+                    response
+                        .bytes()
+                        .await
+                        .map_err(|_| crate::Error::SchemaFetchFailed {
+                            schema_url: url.to_string(),
                         })?;
 
-                        serde_json::from_reader(std::io::Cursor::new(bytes))
-                    }
-                    _ => {
-                        return Err(crate::Error::UnsupportedUrlSchema {
-                            url_schema: url.scheme().to_string(),
-                        })
+                serde_json::from_reader(std::io::Cursor::new(bytes))
+            }
+            _ => {
+                return Err(crate::Error::UnsupportedUrlSchema {
+                    url_schema: url.scheme().to_string(),
+                })
+            }
+        }
+        .map_err(|_| crate::Error::SchemaFileParseFailed {
+            schema_path: url.path().to_string(),
+        })?;
+
+        let mut document_schema = parse_document_schema(schema);
+        document_schema.map_references(|reference| normalize_reference(reference, url));
+        document_schema.schema_url = Some(url.to_owned());
+
+        Ok(document_schema)
+    }
+
+    /// Resolve the references of the document into its `definitions`.
+    ///
+    /// The schemas referenced from other documents are copied into the document
+    /// with their own references rewritten, so every reference can be looked up
+    /// by `DocumentSchema::resolve` without the store.
+    async fn resolve_references(
+        &self,
+        document_schema: &mut DocumentSchema,
+        loading: &mut Vec<Url>,
+    ) {
+        let mut external_schemas: ahash::HashMap<Url, DocumentSchema> = Default::default();
+        let mut pending = document_schema.references();
+
+        while let Some(reference) = pending.pop() {
+            // NOTE: Every reference is resolved at most once, which also stops cyclic references.
+            if document_schema.definitions.contains_key(&reference) {
+                continue;
+            }
+
+            let resolved = match reference.split_once('#') {
+                Some(("", pointer)) => document_schema.pointer(pointer),
+                Some((external_url, pointer)) => {
+                    let Ok(external_url) = Url::parse(external_url) else {
+                        tracing::warn!("invalid schema reference: {reference}");
+                        document_schema
+                            .definitions
+                            .insert(reference, Referable::Schema(Err(())));
+                        continue;
+                    };
+
+                    if !external_schemas.contains_key(&external_url) {
+                        // NOTE: A schema that is still being loaded cannot be taken from the cache,
+                        //       so it is fetched again without resolving its references.
+                        let external_schema = if loading.contains(&external_url) {
+                            self.fetch_schema(&external_url).await
+                        } else {
+                            Box::pin(self.load_schema(&external_url, loading)).await
+                        };
+                        match external_schema {
+                            Ok(external_schema) => {
+                                external_schemas.insert(external_url.clone(), external_schema);
+                            }
+                            Err(err) => tracing::warn!("{err}"),
+                        }
                     }
-                }
-                .map_err(|_| crate::Error::SchemaFileParseFailed {
-                    schema_path: url.path().to_string(),
-                })?;
 
-                let document_schema = DocumentSchema {
-                    title: schema
-                        .get("title")
-                        .map(|obj| obj.as_str().map(|title| title.to_string()))
-                        .flatten(),
-                    description: schema
-                        .get("description")
-                        .map(|obj| obj.as_str().map(|title| title.to_string()))
-                        .flatten(),
-                    schema_url: Some(url.to_owned()),
-                    properties: schema
-                        .get("properties")
-                        .map(|obj| obj.as_object())
-                        .flatten()
-                        .map(|obj| {
-                            obj.iter()
-                                .filter_map(|(key, value)| {
-                                    value.as_object().map(|_| {
-                                        (Accessor::Key(key.clone()), ObjectSchema::default())
-                                    })
-                                })
-                                .collect()
+                    external_schemas
+                        .get(&external_url)
+                        .and_then(|external_schema| external_schema.pointer(pointer))
+                        .map(|mut schema| {
+                            schema.map_references(&mut |reference| {
+                                let reference = if reference.starts_with('#') {
+                                    format!("{external_url}{reference}")
+                                } else {
+                                    reference.to_owned()
+                                };
+                                match &document_schema.schema_url {
+                                    Some(url) => normalize_reference(&reference, url),
+                                    None => reference,
+                                }
+                            });
+                            schema
                         })
-                        .unwrap_or_default(),
-                    ..Default::default()
-                };
-
-                self.schemas
-                    .insert(url.to_owned(), Ok(document_schema.clone()));
+                }
+                None => None,
+            };
 
-                Ok(document_schema)
+            match resolved {
+                Some(schema) => {
+                    pending.extend(schema.references());
+                    document_schema.definitions.insert(reference, schema);
+                }
+                None => {
+                    tracing::warn!("failed to resolve schema reference: {reference}");
+                    document_schema
+                        .definitions
+                        .insert(reference, Referable::Schema(Err(())));
+                }
             }
         }
     }
@@ -175,10 +281,25 @@ impl SchemaStore {
         &self,
         source_path: &std::path::Path,
     ) -> Option<DocumentSchema> {
+        let absolute_source_path = std::path::absolute(source_path).ok();
         let matching_urls: Vec<_> = {
+            let config_schemas = self.config_schemas.read().ok()?;
             let catalogs = self.catalogs.read().ok()?;
-            catalogs
+            // NOTE: The schemas of the nearest config take precedence.
+            let mut config_schemas = config_schemas
                 .iter()
+                .filter(|(dir, _)| {
+                    absolute_source_path
+                        .as_ref()
+                        .is_some_and(|source_path| source_path.starts_with(dir))
+                })
+                .collect::<Vec<_>>();
+            config_schemas.sort_by_key(|(dir, _)| std::cmp::Reverse(dir.components().count()));
+
+            config_schemas
+                .into_iter()
+                .map(|(_, config_schema)| config_schema)
+                .chain(catalogs.iter())
                 .filter(|catalog| {
                     catalog.include.iter().any(|pat| {
                         let pattern = if !pat.contains("*") {
@@ -11,3 +11,37 @@ pub enum ValueType {
     Array,
     Table,
 }
+
+impl From<&document_tree::Value> for ValueType {
+    fn from(value: &document_tree::Value) -> Self {
+        match value {
+            document_tree::Value::Boolean(_) => ValueType::Boolean,
+            document_tree::Value::Integer(_) => ValueType::Integer,
+            document_tree::Value::Float(_) => ValueType::Float,
+            document_tree::Value::String(_) => ValueType::String,
+            document_tree::Value::OffsetDateTime(_) => ValueType::OffsetDateTime,
+            document_tree::Value::LocalDateTime(_) => ValueType::LocalDateTime,
+            document_tree::Value::LocalDate(_) => ValueType::LocalDate,
+            document_tree::Value::LocalTime(_) => ValueType::LocalTime,
+            document_tree::Value::Array(_) => ValueType::Array,
+            document_tree::Value::Table(_) => ValueType::Table,
+        }
+    }
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueType::Boolean => write!(f, "Boolean"),
+            ValueType::Integer => write!(f, "Integer"),
+            ValueType::Float => write!(f, "Float"),
+            ValueType::String => write!(f, "String"),
+            ValueType::OffsetDateTime => write!(f, "OffsetDateTime"),
+            ValueType::LocalDateTime => write!(f, "LocalDateTime"),
+            ValueType::LocalDate => write!(f, "LocalDate"),
+            ValueType::LocalTime => write!(f, "LocalTime"),
+            ValueType::Array => write!(f, "Array"),
+            ValueType::Table => write!(f, "Table"),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use config::SchemaInfo;
+use schema_store::SchemaStore;
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+fn schema_info(path: &str) -> SchemaInfo {
+    SchemaInfo {
+        toml_version: None,
+        path: path.to_string(),
+        include: Some(vec!["config.toml".to_string()]),
+    }
+}
+
+#[test]
+fn nearest_config_schema_takes_precedence() {
+    let dir = std::env::temp_dir().join(format!("schema-store-config-{}", std::process::id()));
+    let nested_dir = dir.join("nested");
+    std::fs::create_dir_all(&nested_dir).unwrap();
+    std::fs::write(dir.join("root.json"), r#"{ "title": "root" }"#).unwrap();
+    std::fs::write(nested_dir.join("nested.json"), r#"{ "title": "nested" }"#).unwrap();
+
+    let schema_store = SchemaStore::new();
+    schema_store.load_config_schema(Some(dir.join("tombi.toml")), vec![schema_info("root.json")]);
+    schema_store.load_config_schema(
+        Some(nested_dir.join("tombi.toml")),
+        vec![schema_info("nested.json")],
+    );
+
+    let title = |path: std::path::PathBuf| {
+        block_on(schema_store.get_schema_from_source(&path))
+            .and_then(|schema| schema.title().map(ToOwned::to_owned))
+    };
+    assert_eq!(title(dir.join("config.toml")).as_deref(), Some("root"));
+    assert_eq!(
+        title(nested_dir.join("config.toml")).as_deref(),
+        Some("nested")
+    );
+
+    schema_store.clear_config_schemas();
+    assert_eq!(title(dir.join("config.toml")), None);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use schema_store::{Accessor, SchemaStore, Value};
+use url::Url;
+
+fn project_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+fn keys(keys: &[&str]) -> Vec<Accessor> {
+    keys.iter()
+        .map(|key| Accessor::Key(key.to_string()))
+        .collect()
+}
+
+#[test]
+fn resolve_local_reference() {
+    let url = Url::from_file_path(project_root().join("schemas/cargo.schema.json")).unwrap();
+    let schema_store = SchemaStore::new();
+    let document_schema = block_on(schema_store.get_schema_from_url(&url)).unwrap();
+
+    let edition = document_schema
+        .find_schema(&keys(&["package", "edition"]))
+        .unwrap();
+    assert!(document_schema.flatten(edition).iter().any(|schema| schema
+        .enumerated_values
+        .contains(&Value::String("2021".into()))));
+
+    let dependency = document_schema
+        .find_schema(&keys(&["dependencies", "serde"]))
+        .unwrap();
+    assert!(document_schema
+        .properties(dependency)
+        .iter()
+        .any(|(key, _)| *key == "features"));
+}
+
+#[test]
+fn resolve_cyclic_file_reference() {
+    let dir = std::env::temp_dir().join(format!("schema-store-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("a.json"),
+        r##"{
+            "type": "object",
+            "properties": { "b": { "$ref": "b.json#/definitions/B" } },
+            "definitions": { "A": { "type": "string", "title": "A" } }
+        }"##,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("b.json"),
+        r##"{
+            "definitions": {
+                "B": {
+                    "type": "object",
+                    "properties": {
+                        "a": { "$ref": "a.json#/definitions/A" },
+                        "b": { "$ref": "#/definitions/B" }
+                    }
+                }
+            }
+        }"##,
+    )
+    .unwrap();
+
+    let url = Url::from_file_path(dir.join("a.json")).unwrap();
+    let schema_store = SchemaStore::new();
+    let document_schema = block_on(schema_store.get_schema_from_url(&url)).unwrap();
+
+    let a = document_schema
+        .find_schema(&keys(&["b", "b", "b", "a"]))
+        .unwrap();
+    assert_eq!(a.title.as_deref(), Some("A"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
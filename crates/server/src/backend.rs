@@ -1,59 +1,245 @@
 use super::handler::{
-    handle_diagnostic, handle_did_change, handle_did_change_configuration, handle_did_open,
-    handle_did_save, handle_document_symbol, handle_formatting, handle_hover, handle_initialize,
+    handle_code_action, handle_completion, handle_diagnostic, handle_did_change,
+    handle_did_change_configuration, handle_did_change_watched_files,
+    handle_did_change_workspace_folders, handle_did_open, handle_did_save,
+    handle_document_highlight, handle_document_symbol, handle_formatting, handle_goto_definition,
+    handle_hover, handle_initialize, handle_initialized, handle_on_type_formatting,
+    handle_prepare_rename, handle_range_formatting, handle_references, handle_rename,
     handle_semantic_tokens_full, handle_shutdown,
 };
-use crate::{document::DocumentSource, handler::handle_folding_range};
-use ast::AstNode;
+use crate::{
+    document::{DocumentSource, ParsedDocument},
+    handler::handle_folding_range,
+};
 use config::{Config, TomlVersion};
 use dashmap::DashMap;
 use schema_store::DEFAULT_CATALOG_URL;
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+};
 use tower_lsp::{
     lsp_types::{
-        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidOpenTextDocumentParams,
-        DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReportResult,
-        DocumentSymbolParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams, Hover,
-        HoverParams, InitializeParams, InitializeResult, SemanticTokensParams,
-        SemanticTokensResult, Url,
+        CodeActionParams, CodeActionResponse, CompletionParams, CompletionResponse,
+        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+        DidChangeWorkspaceFoldersParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+        DocumentDiagnosticParams, DocumentDiagnosticReportResult, DocumentHighlight,
+        DocumentHighlightParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+        DocumentSymbolParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams,
+        GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, InitializeParams,
+        InitializeResult, InitializedParams, Location, PrepareRenameResponse, ReferenceParams,
+        RenameParams, SemanticTokensParams, SemanticTokensResult, TextDocumentPositionParams, Url,
+        WorkspaceEdit,
     },
     LanguageServer,
 };
 
 #[derive(Debug)]
 pub struct Backend {
-    #[allow(dead_code)]
     pub client: tower_lsp::Client,
     pub document_sources: DashMap<Url, DocumentSource>,
-    toml_version: Option<TomlVersion>,
-    pub config: Config,
+    toml_version: RwLock<Option<TomlVersion>>,
+    config: RwLock<Config>,
+    workspace_folders: RwLock<Vec<PathBuf>>,
+    document_configs: DashMap<PathBuf, (Config, Option<PathBuf>)>,
     pub schema_store: schema_store::SchemaStore,
+    dynamic_watched_files_registration: AtomicBool,
 }
 
 impl Backend {
     pub fn new(client: tower_lsp::Client, toml_version: Option<TomlVersion>) -> Self {
+        let (config, config_path) = config::load_with_path();
+
+        let schema_store = schema_store::SchemaStore::new();
+        schema_store.load_config_schema(config_path, config.schemas.clone().unwrap_or_default());
+
         Self {
             client,
             document_sources: Default::default(),
-            toml_version,
-            config: config::load(),
-            schema_store: schema_store::SchemaStore::new(),
+            toml_version: RwLock::new(toml_version),
+            config: RwLock::new(config),
+            workspace_folders: Default::default(),
+            document_configs: Default::default(),
+            schema_store,
+            dynamic_watched_files_registration: AtomicBool::new(false),
         }
     }
 
+    /// Get the cached parse of the open document.
+    pub fn get_parsed(&self, uri: &Url) -> Option<Arc<ParsedDocument>> {
+        self.document_sources
+            .get(uri)
+            .map(|document_source| document_source.parsed(self.toml_version(uri)))
+    }
+
+    /// Get the source of the open document with its cached parse.
+    ///
+    /// They are taken together so that the positions of the parse refer to the source.
+    pub fn get_source_and_parsed(&self, uri: &Url) -> Option<(String, Arc<ParsedDocument>)> {
+        self.document_sources.get(uri).map(|document_source| {
+            (
+                document_source.source().to_owned(),
+                document_source.parsed(self.toml_version(uri)),
+            )
+        })
+    }
+
+    /// Get the AST of the open document if it has no syntax errors.
     pub fn get_ast(&self, uri: &Url) -> Option<ast::Root> {
-        self.document_sources.get(uri).and_then(|document_info| {
-            let p = parser::parse(&document_info.source, self.toml_version());
-            if !p.errors().is_empty() {
-                return None;
+        self.get_parsed(uri)
+            .filter(|parsed| !parsed.has_syntax_errors())
+            .map(|parsed| parsed.ast())
+    }
+
+    pub async fn get_document_schema(&self, uri: &Url) -> Option<schema_store::DocumentSchema> {
+        let source_path = uri.to_file_path().ok()?;
+
+        // NOTE: The schemas of the config are registered when the config is loaded.
+        self.config(uri);
+
+        self.schema_store.get_schema_from_source(&source_path).await
+    }
+
+    /// Get the config of the document.
+    ///
+    /// The config is searched from the workspace folder of the document to its ancestors,
+    /// and cached per workspace folder, so each folder of a multi-root workspace
+    /// can have its own config. The documents outside the workspace folders use
+    /// the config searched from their directory, and the documents without a file path,
+    /// such as untitled ones, use the config of the current directory.
+    ///
+    /// A config which fails to load is replaced with the config of the current directory
+    /// until the config files change.
+    ///
+    /// The `[[overrides]]` matching the document are applied.
+    pub fn config(&self, uri: &Url) -> Config {
+        let Some((source_path, dir)) = uri.to_file_path().ok().and_then(|path| {
+            let dir = self.config_dir(&path)?;
+            Some((path, dir))
+        }) else {
+            return self.default_config();
+        };
+
+        if let Some(document_config) = self.document_configs.get(&dir) {
+            let (config, config_path) = document_config.value();
+            return config.for_source(&source_path, config_path.as_deref());
+        }
+
+        let (config, config_path) = match config::try_load_with_path_from(&dir) {
+            Ok(config_with_path) => config_with_path,
+            Err(err) => {
+                tracing::error!("{}", err);
+                (self.default_config(), None)
             }
+        };
 
-            ast::Root::cast(p.into_syntax_node())
-        })
+        let registered = self
+            .document_configs
+            .iter()
+            .any(|document_config| document_config.1 == config_path);
+        if !registered && config_path.is_some() {
+            self.schema_store.load_config_schema(
+                config_path.clone(),
+                config.schemas.clone().unwrap_or_default(),
+            );
+        }
+
+        let source_config = config.for_source(&source_path, config_path.as_deref());
+        self.document_configs.insert(dir, (config, config_path));
+
+        source_config
     }
 
-    pub fn toml_version(&self) -> TomlVersion {
-        self.toml_version
-            .unwrap_or(self.config.toml_version.unwrap_or_default())
+    /// Get the directory to search the config of the document from,
+    /// which is the innermost workspace folder containing the document, or its directory.
+    fn config_dir(&self, source_path: &Path) -> Option<PathBuf> {
+        self.workspace_folders
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .filter(|folder| source_path.starts_with(folder))
+            .max_by_key(|folder| folder.components().count())
+            .cloned()
+            .or_else(|| source_path.parent().map(Path::to_path_buf))
+    }
+
+    /// Add and remove the workspace folders, and forget the configs of the documents.
+    pub fn update_workspace_folders(&self, added: &[Url], removed: &[Url]) {
+        let mut workspace_folders = self
+            .workspace_folders
+            .write()
+            .unwrap_or_else(|err| err.into_inner());
+        for path in removed.iter().filter_map(|uri| uri.to_file_path().ok()) {
+            workspace_folders.retain(|folder| *folder != path);
+        }
+        workspace_folders.extend(added.iter().filter_map(|uri| uri.to_file_path().ok()));
+        drop(workspace_folders);
+
+        self.document_configs.clear();
+    }
+
+    fn default_config(&self) -> Config {
+        self.config
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Reload the config of the current directory and forget the configs of the documents,
+    /// and re-register the schemas of the configs.
+    ///
+    /// The configs of the documents are forgotten even if the config fails to load,
+    /// so that the configs which failed to load are retried.
+    pub fn reload_config(&self) -> Result<(), config::Error> {
+        self.document_configs.clear();
+
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let (config, config_path) = config::try_load_with_path_from(&current_dir)?;
+
+        self.schema_store.clear_config_schemas();
+        self.schema_store
+            .load_config_schema(config_path, config.schemas.clone().unwrap_or_default());
+        *self.config.write().unwrap_or_else(|err| err.into_inner()) = config;
+
+        Ok(())
+    }
+
+    pub fn toml_version(&self, uri: &Url) -> TomlVersion {
+        self.editor_toml_version()
+            .or(self.config(uri).toml_version)
+            .unwrap_or_default()
+    }
+
+    /// Get the TOML version given by the editor.
+    pub fn editor_toml_version(&self) -> Option<TomlVersion> {
+        *self
+            .toml_version
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Set the TOML version given by the editor, which takes precedence over the config.
+    pub fn set_toml_version(&self, toml_version: Option<TomlVersion>) {
+        *self
+            .toml_version
+            .write()
+            .unwrap_or_else(|err| err.into_inner()) = toml_version;
+    }
+
+    /// Whether the client can register `workspace/didChangeWatchedFiles` dynamically.
+    pub fn dynamic_watched_files_registration(&self) -> bool {
+        self.dynamic_watched_files_registration
+            .load(Ordering::Relaxed)
+    }
+
+    /// Ask the client to pull the diagnostics of the open documents again.
+    pub async fn refresh_diagnostics(&self) {
+        if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+            tracing::warn!("failed to refresh diagnostics: {}", err);
+        }
     }
 }
 
@@ -64,7 +250,7 @@ impl LanguageServer for Backend {
         params: InitializeParams,
     ) -> Result<InitializeResult, tower_lsp::jsonrpc::Error> {
         if self
-            .config
+            .default_config()
             .schema
             .as_ref()
             .and_then(|options| options.catalog.as_ref())
@@ -81,9 +267,37 @@ impl LanguageServer for Backend {
             }
         }
 
+        #[allow(deprecated)]
+        let workspace_folders = match &params.workspace_folders {
+            Some(workspace_folders) => workspace_folders
+                .iter()
+                .map(|folder| folder.uri.clone())
+                .collect(),
+            None => params.root_uri.clone().into_iter().collect::<Vec<_>>(),
+        };
+        self.update_workspace_folders(&workspace_folders, &[]);
+
+        self.dynamic_watched_files_registration.store(
+            (|| -> _ {
+                params
+                    .capabilities
+                    .workspace
+                    .as_ref()?
+                    .did_change_watched_files
+                    .as_ref()?
+                    .dynamic_registration
+            })()
+            .unwrap_or_default(),
+            Ordering::Relaxed,
+        );
+
         handle_initialize(params).await
     }
 
+    async fn initialized(&self, params: InitializedParams) {
+        handle_initialized(self, params).await
+    }
+
     async fn shutdown(&self) -> Result<(), tower_lsp::jsonrpc::Error> {
         handle_shutdown()
     }
@@ -101,7 +315,15 @@ impl LanguageServer for Backend {
     }
 
     async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
-        handle_did_change_configuration(params).await
+        handle_did_change_configuration(self, params).await
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        handle_did_change_watched_files(self, params).await
+    }
+
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        handle_did_change_workspace_folders(self, params).await
     }
 
     async fn semantic_tokens_full(
@@ -122,6 +344,48 @@ impl LanguageServer for Backend {
         handle_hover(self, params).await
     }
 
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>, tower_lsp::jsonrpc::Error> {
+        handle_goto_definition(self, params).await
+    }
+
+    async fn references(
+        &self,
+        params: ReferenceParams,
+    ) -> Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error> {
+        handle_references(self, params).await
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>, tower_lsp::jsonrpc::Error> {
+        handle_document_highlight(self, params).await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>, tower_lsp::jsonrpc::Error> {
+        handle_prepare_rename(self, params).await
+    }
+
+    async fn rename(
+        &self,
+        params: RenameParams,
+    ) -> Result<Option<WorkspaceEdit>, tower_lsp::jsonrpc::Error> {
+        handle_rename(self, params).await
+    }
+
+    async fn completion(
+        &self,
+        params: CompletionParams,
+    ) -> Result<Option<CompletionResponse>, tower_lsp::jsonrpc::Error> {
+        handle_completion(self, params).await
+    }
+
     async fn folding_range(
         &self,
         params: FoldingRangeParams,
@@ -136,6 +400,27 @@ impl LanguageServer for Backend {
         handle_formatting(self, params).await
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<tower_lsp::lsp_types::TextEdit>>, tower_lsp::jsonrpc::Error> {
+        handle_range_formatting(self, params).await
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<tower_lsp::lsp_types::TextEdit>>, tower_lsp::jsonrpc::Error> {
+        handle_on_type_formatting(self, params).await
+    }
+
+    async fn code_action(
+        &self,
+        params: CodeActionParams,
+    ) -> Result<Option<CodeActionResponse>, tower_lsp::jsonrpc::Error> {
+        handle_code_action(self, params).await
+    }
+
     async fn diagnostic(
         &self,
         params: DocumentDiagnosticParams,
@@ -143,3 +428,46 @@ impl LanguageServer for Backend {
         handle_diagnostic(self, params).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn config_cached_per_workspace_folder() {
+        let root_dir = std::env::temp_dir().join(format!("server-test-{}", std::process::id()));
+        let folder = root_dir.join("folder");
+        std::fs::create_dir_all(folder.join("a")).unwrap();
+        std::fs::write(folder.join(config::CONFIG_FILENAME), "toml-version = 1").unwrap();
+
+        let (service, _) = tower_lsp::LspService::new(|client| Backend::new(client, None));
+        let backend = service.inner();
+        backend.update_workspace_folders(&[Url::from_directory_path(&folder).unwrap()], &[]);
+
+        let uri = |path: &str| Url::from_file_path(folder.join(path)).unwrap();
+        let toml_version = backend.default_config().toml_version;
+
+        // NOTE: The config which failed to load is cached until the config files change.
+        assert_eq!(backend.config(&uri("a/b.toml")).toml_version, toml_version);
+        std::fs::write(
+            folder.join(config::CONFIG_FILENAME),
+            "toml-version = \"v1.0.0\"",
+        )
+        .unwrap();
+        assert_eq!(backend.config(&uri("c.toml")).toml_version, toml_version);
+        assert_eq!(
+            backend
+                .document_configs
+                .iter()
+                .map(|entry| entry.key().clone())
+                .collect::<Vec<_>>(),
+            vec![folder.clone()]
+        );
+
+        let _ = backend.reload_config();
+        let config = backend.config(&uri("a/b.toml"));
+        std::fs::remove_dir_all(&root_dir).unwrap();
+
+        assert_eq!(config.toml_version, Some(TomlVersion::V1_0_0));
+    }
+}
@@ -0,0 +1,492 @@
+use ast::{algo::ancestors_at_position, AstNode};
+use config::TomlVersion;
+use itertools::Itertools;
+use schema_store::{Accessor, DocumentSchema, ObjectSchema, SchemaType, Value};
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, Documentation, MarkupContent,
+    MarkupKind,
+};
+
+/// The syntactic place where the completion was requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionHint {
+    /// Inside the header of a table: `[...]`.
+    Table,
+
+    /// Inside the header of an array of tables: `[[...]]`.
+    ArrayOfTables,
+
+    /// At the key of a key-value.
+    Key,
+
+    /// After the `=` of a key-value.
+    Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionContentKind {
+    Key,
+    Table,
+    ArrayOfTables,
+    EnumValue,
+    DefaultValue,
+    Boolean,
+}
+
+impl From<CompletionContentKind> for CompletionItemKind {
+    fn from(kind: CompletionContentKind) -> Self {
+        match kind {
+            CompletionContentKind::Key => CompletionItemKind::PROPERTY,
+            CompletionContentKind::Table | CompletionContentKind::ArrayOfTables => {
+                CompletionItemKind::STRUCT
+            }
+            CompletionContentKind::EnumValue => CompletionItemKind::ENUM_MEMBER,
+            CompletionContentKind::DefaultValue => CompletionItemKind::VALUE,
+            CompletionContentKind::Boolean => CompletionItemKind::KEYWORD,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionContent {
+    pub label: String,
+    pub kind: CompletionContentKind,
+    pub detail: Option<String>,
+    pub documentation: Option<String>,
+}
+
+impl CompletionContent {
+    fn new_key(key: &str, schema: &ObjectSchema) -> Self {
+        Self {
+            label: key.to_string(),
+            kind: CompletionContentKind::Key,
+            detail: schema.title.clone(),
+            documentation: schema.description.clone(),
+        }
+    }
+
+    fn new_header(key: &str, schema: &ObjectSchema, kind: CompletionContentKind) -> Self {
+        Self {
+            label: key.to_string(),
+            kind,
+            detail: schema.title.clone(),
+            documentation: schema.description.clone(),
+        }
+    }
+
+    fn new_value(value: &Value, kind: CompletionContentKind) -> Self {
+        Self {
+            label: value.to_string(),
+            kind,
+            detail: None,
+            documentation: None,
+        }
+    }
+}
+
+impl From<CompletionContent> for CompletionItem {
+    fn from(content: CompletionContent) -> Self {
+        let description = match content.kind {
+            CompletionContentKind::Table => Some("table".to_string()),
+            CompletionContentKind::ArrayOfTables => Some("array of tables".to_string()),
+            CompletionContentKind::DefaultValue => Some("default".to_string()),
+            _ => None,
+        };
+
+        CompletionItem {
+            label: content.label,
+            kind: Some(content.kind.into()),
+            label_details: description.map(|description| CompletionItemLabelDetails {
+                detail: None,
+                description: Some(description),
+            }),
+            detail: content.detail,
+            documentation: content.documentation.map(|documentation| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: documentation,
+                })
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+pub fn get_completion_contents(
+    root: &ast::Root,
+    position: text::Position,
+    document_schema: &DocumentSchema,
+    toml_version: TomlVersion,
+) -> Vec<CompletionContent> {
+    let Some((accessors, hint)) = get_completion_accessors_with_hint(root, position, toml_version)
+    else {
+        return Vec::with_capacity(0);
+    };
+
+    let Some(schema) = document_schema.find_schema(&accessors) else {
+        return Vec::with_capacity(0);
+    };
+
+    match hint {
+        CompletionHint::Table | CompletionHint::ArrayOfTables => {
+            let (kind, schema_type) = if hint == CompletionHint::Table {
+                (CompletionContentKind::Table, SchemaType::Object)
+            } else {
+                (CompletionContentKind::ArrayOfTables, SchemaType::Array)
+            };
+
+            document_schema
+                .properties(schema)
+                .into_iter()
+                .filter(|(_, schema)| document_schema.accepts_type(schema, schema_type))
+                .map(|(key, schema)| CompletionContent::new_header(key, schema, kind))
+                .sorted_by(|a, b| a.label.cmp(&b.label))
+                .collect()
+        }
+        CompletionHint::Key => {
+            let defined_keys = get_defined_keys(root, position, toml_version);
+
+            document_schema
+                .properties(schema)
+                .into_iter()
+                .filter(|(key, _)| !defined_keys.iter().any(|defined| defined == key))
+                .map(|(key, schema)| CompletionContent::new_key(key, schema))
+                .sorted_by(|a, b| a.label.cmp(&b.label))
+                .collect()
+        }
+        CompletionHint::Value => {
+            let mut contents: Vec<CompletionContent> = vec![];
+            let mut push_value = |value: &Value, kind| {
+                if matches!(value, Value::Null) {
+                    return;
+                }
+                let content = CompletionContent::new_value(value, kind);
+                if !contents.iter().any(|c| c.label == content.label) {
+                    contents.push(content);
+                }
+            };
+
+            let schemas = document_schema.flatten(schema);
+            for schema in &schemas {
+                if let Some(const_value) = &schema.const_value {
+                    push_value(const_value, CompletionContentKind::EnumValue);
+                }
+                for value in &schema.enumerated_values {
+                    push_value(value, CompletionContentKind::EnumValue);
+                }
+            }
+            for schema in &schemas {
+                if let Some(default) = &schema.default {
+                    push_value(default, CompletionContentKind::DefaultValue);
+                }
+            }
+
+            if schemas
+                .iter()
+                .any(|schema| schema.types.contains(&SchemaType::Boolean))
+            {
+                for value in [true, false] {
+                    push_value(&Value::Boolean(value), CompletionContentKind::Boolean);
+                }
+            }
+
+            contents
+        }
+    }
+}
+
+/// Get the accessors of the table that is being completed and the kind of completion.
+///
+/// The key being typed at the cursor is not included in the accessors.
+fn get_completion_accessors_with_hint(
+    root: &ast::Root,
+    position: text::Position,
+    toml_version: TomlVersion,
+) -> Option<(Vec<Accessor>, CompletionHint)> {
+    let root_range = root.syntax().range();
+    if position < root_range.start() || root_range.end() < position {
+        return None;
+    }
+
+    let mut accessors = vec![];
+    let mut hint = None;
+
+    // NOTE: Blank lines just before a header belong to the node of that header,
+    //       so the table is determined by the position of the header start.
+    if let Some(item) = get_header_item(root, position) {
+        let (header, bracket_start, bracket_end, header_hint) = match &item {
+            ast::RootItem::Table(table) => (
+                table.header(),
+                table.bracket_start(),
+                table.bracket_end(),
+                CompletionHint::Table,
+            ),
+            ast::RootItem::ArrayOfTables(array_of_tables) => (
+                array_of_tables.header(),
+                array_of_tables.double_bracket_start(),
+                array_of_tables.double_bracket_end(),
+                CompletionHint::ArrayOfTables,
+            ),
+            ast::RootItem::KeyValue(_) => unreachable!(),
+        };
+
+        let in_header = bracket_start.is_some_and(|bracket_start| {
+            bracket_start.range().end() <= position
+                && match bracket_end {
+                    Some(bracket_end) => position <= bracket_end.range().start(),
+                    None => bracket_start.range().end().line() == position.line(),
+                }
+        });
+
+        if in_header {
+            if let Some(header) = header {
+                accessors.extend(keys_to_accessors(
+                    header
+                        .keys()
+                        .take_while(|key| key.syntax().range().end() < position),
+                    toml_version,
+                )?);
+            }
+            return Some((accessors, header_hint));
+        }
+
+        if let Some(header) = header {
+            accessors.extend(keys_to_accessors(header.keys(), toml_version)?);
+        }
+        if let ast::RootItem::ArrayOfTables(array_of_tables) = &item {
+            accessors.push(Accessor::Index(get_array_of_tables_index(
+                root,
+                array_of_tables,
+                toml_version,
+            )));
+        }
+    }
+
+    let mut key_values_accessors = vec![];
+    let mut indexes = vec![];
+    for node in ancestors_at_position(root.syntax(), position) {
+        if ast::Table::can_cast(node.kind())
+            || ast::ArrayOfTables::can_cast(node.kind())
+            || ast::Root::can_cast(node.kind())
+        {
+            break;
+        }
+
+        if let Some(array) = ast::Array::cast(node.to_owned()) {
+            indexes.push(Accessor::Index(
+                array
+                    .values()
+                    .take_while(|value| value.range().end() < position)
+                    .count(),
+            ));
+            continue;
+        }
+
+        if ast::InlineTable::can_cast(node.kind()) {
+            hint.get_or_insert(CompletionHint::Key);
+            continue;
+        }
+
+        let Some(key_value) = ast::KeyValue::cast(node) else {
+            continue;
+        };
+        let Some(keys) = key_value.keys() else {
+            continue;
+        };
+
+        let mut key_value_accessors = if hint.is_none() {
+            if key_value
+                .eq()
+                .is_some_and(|eq| eq.range().end() <= position)
+            {
+                hint = Some(CompletionHint::Value);
+                keys_to_accessors(keys.keys(), toml_version)?
+            } else {
+                hint = Some(CompletionHint::Key);
+                keys_to_accessors(
+                    keys.keys()
+                        .take_while(|key| key.syntax().range().end() < position),
+                    toml_version,
+                )?
+            }
+        } else {
+            keys_to_accessors(keys.keys(), toml_version)?
+        };
+        key_value_accessors.extend(std::mem::take(&mut indexes).into_iter().rev());
+        key_values_accessors.push(key_value_accessors);
+    }
+
+    accessors.extend(key_values_accessors.into_iter().rev().flatten());
+
+    Some((accessors, hint.unwrap_or(CompletionHint::Key)))
+}
+
+/// Get the last table or array of tables whose header starts before the position.
+fn get_header_item(root: &ast::Root, position: text::Position) -> Option<ast::RootItem> {
+    root.items()
+        .filter(|item| {
+            let bracket_start = match item {
+                ast::RootItem::Table(table) => table.bracket_start(),
+                ast::RootItem::ArrayOfTables(array_of_tables) => {
+                    array_of_tables.double_bracket_start()
+                }
+                ast::RootItem::KeyValue(_) => return false,
+            };
+            bracket_start.is_some_and(|bracket_start| bracket_start.range().end() <= position)
+        })
+        .last()
+}
+
+/// Count the preceding array of tables that have the same header.
+fn get_array_of_tables_index(
+    root: &ast::Root,
+    array_of_tables: &ast::ArrayOfTables,
+    toml_version: TomlVersion,
+) -> usize {
+    let Some(header) = array_of_tables
+        .header()
+        .and_then(|header| keys_to_accessors(header.keys(), toml_version))
+    else {
+        return 0;
+    };
+
+    root.items()
+        .take_while(|item| item.syntax() != array_of_tables.syntax())
+        .filter(|item| match item {
+            ast::RootItem::ArrayOfTables(other) => other
+                .header()
+                .and_then(|other| keys_to_accessors(other.keys(), toml_version))
+                .is_some_and(|other| other == header),
+            _ => false,
+        })
+        .count()
+}
+
+/// Get the keys already defined in the table where the completion was requested.
+fn get_defined_keys(
+    root: &ast::Root,
+    position: text::Position,
+    toml_version: TomlVersion,
+) -> Vec<String> {
+    let key_values = match get_header_item(root, position) {
+        Some(ast::RootItem::Table(table)) => table.key_values().collect_vec(),
+        Some(ast::RootItem::ArrayOfTables(array_of_tables)) => {
+            array_of_tables.key_values().collect_vec()
+        }
+        _ => root
+            .items()
+            .filter_map(|item| match item {
+                ast::RootItem::KeyValue(key_value) => Some(key_value),
+                _ => None,
+            })
+            .collect_vec(),
+    };
+
+    key_values
+        .into_iter()
+        .filter(|key_value| !key_value.range().contains(position))
+        .filter_map(|key_value| key_value.keys()?.keys().next())
+        .filter_map(|key| key.try_to_raw_text(toml_version).ok())
+        .collect()
+}
+
+fn keys_to_accessors(
+    keys: impl Iterator<Item = ast::Key>,
+    toml_version: TomlVersion,
+) -> Option<Vec<Accessor>> {
+    keys.map(|key| key.try_to_raw_text(toml_version).ok().map(Accessor::Key))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    fn document_schema() -> DocumentSchema {
+        schema_store::parse_document_schema(serde_json::json!({
+            "title": "Sample",
+            "properties": {
+                "package": {
+                    "title": "Package",
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "version": { "type": "string" },
+                        "keywords": {
+                            "type": "array",
+                            "items": { "enum": ["cli", "toml"] },
+                        },
+                    },
+                },
+                "bin": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "test": { "type": "boolean", "default": true },
+                        },
+                    },
+                },
+                "profile": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "object",
+                        "properties": {
+                            "debug": { "anyOf": [{ "type": "boolean" }, { "enum": ["full"] }] },
+                        },
+                    },
+                },
+                "edition": {
+                    "title": "Edition",
+                    "type": "string",
+                    "enum": ["2015", "2018", "2021"],
+                    "default": "2021",
+                },
+                "publish": {
+                    "type": "boolean",
+                },
+                "name": {
+                    "type": "string",
+                },
+            }
+        }))
+    }
+
+    fn completion_labels(source: &str, position: (u32, u32)) -> Vec<String> {
+        let root =
+            ast::Root::cast(parser::parse(source, TomlVersion::default()).into_syntax_node())
+                .unwrap();
+
+        get_completion_contents(
+            &root,
+            text::Position::new(position.0, position.1),
+            &document_schema(),
+            TomlVersion::default(),
+        )
+        .into_iter()
+        .map(|content| content.label)
+        .collect()
+    }
+
+    #[rstest]
+    #[case("", (0, 0), vec!["bin", "edition", "name", "package", "profile", "publish"])]
+    #[case("na", (0, 2), vec!["bin", "edition", "name", "package", "profile", "publish"])]
+    #[case("name = \"a\"\n", (1, 0), vec!["bin", "edition", "package", "profile", "publish"])]
+    #[case("[pa", (0, 3), vec!["package", "profile"])]
+    #[case("[[bi", (0, 4), vec!["bin"])]
+    #[case("[package]\n", (1, 0), vec!["keywords", "name", "version"])]
+    #[case("[package]\nname = \"a\"\n", (2, 0), vec!["keywords", "version"])]
+    #[case("[package]\nkeywords = [", (1, 12), vec!["\"cli\"", "\"toml\""])]
+    #[case("[[bin]]\n", (1, 0), vec!["name", "test"])]
+    #[case("[[bin]]\ntest = ", (1, 7), vec!["true", "false"])]
+    #[case("[profile.release]\n", (1, 0), vec!["debug"])]
+    #[case("[profile.release]\ndebug = ", (1, 8), vec!["\"full\"", "true", "false"])]
+    #[case("package = {  }", (0, 12), vec!["keywords", "name", "version"])]
+    #[case("edition = ", (0, 10), vec!["\"2015\"", "\"2018\"", "\"2021\""])]
+    #[case("publish = ", (0, 10), vec!["true", "false"])]
+    fn completion(#[case] source: &str, #[case] position: (u32, u32), #[case] expected: Vec<&str>) {
+        assert_eq!(completion_labels(source, position), expected);
+    }
+}
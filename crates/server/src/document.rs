@@ -1,11 +1,216 @@
-#[derive(Debug, Clone)]
+use std::sync::{Arc, Mutex};
+
+use ast::AstNode;
+use config::TomlVersion;
+use diagnostic::SetDiagnostics;
+use document_tree::TryIntoDocumentTree;
+use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
+
+#[derive(Debug)]
 pub struct DocumentSource {
-    pub source: String,
+    source: String,
+    pub version: Option<i32>,
+    parsed: Mutex<Option<Arc<ParsedDocument>>>,
 }
 
 impl DocumentSource {
-    pub fn new(source: impl Into<String>) -> Self {
+    pub fn new(source: impl Into<String>, version: Option<i32>) -> Self {
         let source = source.into();
-        Self { source }
+        Self {
+            source,
+            version,
+            parsed: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Replace the whole source, e.g. with the text of `textDocument/didSave`.
+    pub fn set_source(&mut self, source: impl Into<String>) {
+        self.source = source.into();
+        self.invalidate();
+    }
+
+    /// Get the parse of the current source.
+    ///
+    /// The parse is cached until the source changes,
+    /// so the handlers of the same document version share one parse.
+    pub fn parsed(&self, toml_version: TomlVersion) -> Arc<ParsedDocument> {
+        let mut parsed = self.parsed.lock().unwrap_or_else(|err| err.into_inner());
+        match parsed.as_ref() {
+            Some(parsed) if parsed.toml_version == toml_version => parsed.clone(),
+            _ => {
+                let new_parsed = Arc::new(ParsedDocument::new(&self.source, toml_version));
+                *parsed = Some(new_parsed.clone());
+                new_parsed
+            }
+        }
+    }
+
+    fn invalidate(&mut self) {
+        *self.parsed.get_mut().unwrap_or_else(|err| err.into_inner()) = None;
+    }
+
+    /// Apply a change of `textDocument/didChange`.
+    ///
+    /// A change with a range replaces only that range, and the others replace the whole source.
+    pub fn apply_change(&mut self, change: TextDocumentContentChangeEvent) {
+        match change.range {
+            Some(range) => {
+                let start: usize = text::Offset::from_source(&self.source, range.start).into();
+                let end: usize = text::Offset::from_source(&self.source, range.end).into();
+                self.source
+                    .replace_range(start..end.max(start), &change.text);
+            }
+            None => self.source = change.text,
+        }
+        self.invalidate();
+    }
+}
+
+/// The parse of a document source.
+///
+/// Only the green tree of the syntax is kept because the syntax nodes are not thread-safe,
+/// so the AST is rebuilt from it on each access. The document tree is built once.
+#[derive(Debug)]
+pub struct ParsedDocument {
+    toml_version: TomlVersion,
+    parsed: parser::Parsed<parser::SyntaxNode>,
+    document_tree: Option<document_tree::Root>,
+    diagnostics: Vec<diagnostic::Diagnostic>,
+}
+
+impl ParsedDocument {
+    fn new(source: &str, toml_version: TomlVersion) -> Self {
+        let parsed = parser::parse(source, toml_version);
+
+        let mut diagnostics = vec![];
+        for error in parsed.errors() {
+            error.set_diagnostic(&mut diagnostics);
+        }
+
+        let mut document_tree = None;
+        if diagnostics.is_empty() {
+            if let Some(root) = ast::Root::cast(parsed.syntax_node()) {
+                match root.try_into_document_tree(toml_version) {
+                    Ok(root) => document_tree = Some(root),
+                    Err(errors) => {
+                        for error in errors {
+                            error.set_diagnostic(&mut diagnostics);
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            toml_version,
+            parsed,
+            document_tree,
+            diagnostics,
+        }
+    }
+
+    /// Get the AST, which is available even if the source has syntax errors.
+    pub fn ast(&self) -> ast::Root {
+        let Some(root) = ast::Root::cast(self.parsed.syntax_node()) else {
+            unreachable!("Root node is always present");
+        };
+        root
+    }
+
+    /// Get the parse result, e.g. to lint the document without parsing it again.
+    pub fn parsed(&self) -> &parser::Parsed<parser::SyntaxNode> {
+        &self.parsed
+    }
+
+    /// Get the document tree, which is available only if the source has no errors.
+    pub fn document_tree(&self) -> Option<&document_tree::Root> {
+        self.document_tree.as_ref()
+    }
+
+    /// Whether the source has syntax errors.
+    pub fn has_syntax_errors(&self) -> bool {
+        !self.parsed.errors().is_empty()
+    }
+
+    /// Whether the source has syntax errors or invalid TOML structures.
+    pub fn has_errors(&self) -> bool {
+        !self.diagnostics.is_empty()
+    }
+
+    /// Get the diagnostics of the syntax errors and the invalid TOML structures.
+    pub fn diagnostics(&self) -> &[diagnostic::Diagnostic] {
+        &self.diagnostics
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+    use tower_lsp::lsp_types::{Position, Range};
+
+    fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range::new(
+                Position::new(start.0, start.1),
+                Position::new(end.0, end.1),
+            )),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[rstest]
+    #[case("key = 1", vec![change((0, 6), (0, 7), "2")], "key = 2")]
+    #[case("a = 1\nb = 2", vec![change((1, 0), (1, 1), "c")], "a = 1\nc = 2")]
+    #[case("a = 1", vec![change((0, 5), (0, 5), "\nb = 2")], "a = 1\nb = 2")]
+    #[case("a = 1\nb = 2", vec![change((0, 5), (1, 5), "")], "a = 1")]
+    #[case("a = \"🦀\"\nb = 2", vec![change((0, 8), (1, 0), " # crab\n")], "a = \"🦀\" # crab\nb = 2")]
+    #[case(
+        "a = 1",
+        vec![change((0, 0), (0, 1), "b"), change((0, 4), (0, 5), "2")],
+        "b = 2"
+    )]
+    fn apply_change(
+        #[case] source: &str,
+        #[case] changes: Vec<TextDocumentContentChangeEvent>,
+        #[case] expected: &str,
+    ) {
+        let mut document_source = DocumentSource::new(source, Some(0));
+        for change in changes {
+            document_source.apply_change(change);
+        }
+        assert_eq!(document_source.source(), expected);
+    }
+
+    #[test]
+    fn parsed_is_invalidated_by_change() {
+        let mut document_source = DocumentSource::new("a = 1", Some(0));
+        let parsed = document_source.parsed(TomlVersion::default());
+        assert!(Arc::ptr_eq(
+            &parsed,
+            &document_source.parsed(TomlVersion::default())
+        ));
+        assert!(!parsed.has_errors());
+        assert!(parsed.document_tree().is_some());
+
+        document_source.apply_change(change((0, 4), (0, 5), ""));
+        let parsed = document_source.parsed(TomlVersion::default());
+        assert!(parsed.has_syntax_errors());
+        assert!(!parsed.diagnostics().is_empty());
+        assert!(parsed.document_tree().is_none());
+    }
+
+    #[test]
+    fn parsed_reports_document_tree_errors() {
+        let document_source = DocumentSource::new("a = 1\na = 2", Some(0));
+        let parsed = document_source.parsed(TomlVersion::default());
+        assert!(!parsed.has_syntax_errors());
+        assert!(parsed.has_errors());
     }
 }
@@ -1,25 +1,49 @@
+mod code_action;
+mod completion;
 mod diagnostic;
 mod did_change;
 mod did_change_configuration;
+mod did_change_watched_files;
+mod did_change_workspace_folders;
 mod did_open;
+mod document_highlight;
 mod document_symbol;
 mod folding_range;
 mod formatting;
+mod goto_definition;
 mod hover;
 mod initialize;
+mod initialized;
+mod on_type_formatting;
+mod prepare_rename;
+mod range_formatting;
+mod references;
+mod rename;
 mod semantic_tokens_full;
 mod shutdown;
 
 mod did_save;
+pub use code_action::handle_code_action;
+pub use completion::handle_completion;
 pub use diagnostic::handle_diagnostic;
 pub use did_change::handle_did_change;
 pub use did_change_configuration::handle_did_change_configuration;
+pub use did_change_watched_files::handle_did_change_watched_files;
+pub use did_change_workspace_folders::handle_did_change_workspace_folders;
 pub use did_open::handle_did_open;
 pub use did_save::handle_did_save;
+pub use document_highlight::handle_document_highlight;
 pub use document_symbol::handle_document_symbol;
 pub use folding_range::handle_folding_range;
 pub use formatting::handle_formatting;
+pub use goto_definition::handle_goto_definition;
 pub use hover::handle_hover;
 pub use initialize::handle_initialize;
+pub use initialized::handle_initialized;
+pub use on_type_formatting::handle_on_type_formatting;
+pub use prepare_rename::handle_prepare_rename;
+pub use range_formatting::handle_range_formatting;
+pub use references::handle_references;
+pub use rename::handle_rename;
 pub use semantic_tokens_full::handle_semantic_tokens_full;
 pub use shutdown::handle_shutdown;
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+    TextEdit, WorkspaceEdit,
+};
+
+use crate::backend::Backend;
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_code_action(
+    backend: &Backend,
+    CodeActionParams {
+        text_document,
+        range,
+        context,
+        ..
+    }: CodeActionParams,
+) -> Result<Option<CodeActionResponse>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_code_action");
+
+    let uri = &text_document.uri;
+    let source_path = uri.to_file_path().ok();
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(uri) else {
+        return Ok(None);
+    };
+
+    // NOTE: The quick fixes are offered only for the diagnostics shown in the editor,
+    //       so the linter does not run when there are none of them in the range.
+    if context.diagnostics.is_empty() {
+        return Ok(None);
+    }
+
+    let diagnostics = linter::Linter::new(
+        backend.toml_version(uri),
+        &backend.config(uri).lint.unwrap_or_default(),
+        source_path.as_deref(),
+        None,
+        &backend.schema_store,
+    )
+    .lint_parsed(parsed.parsed(), parsed.document_tree())
+    .await
+    .err()
+    .unwrap_or_default();
+
+    let start = text::Offset::from_source(&source, range.start);
+    let end = text::Offset::from_source(&source, range.end);
+    let code_actions = diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            text::Offset::from_position(&source, diagnostic.range().start()) <= end
+                && start <= text::Offset::from_position(&source, diagnostic.range().end())
+        })
+        .filter_map(|diagnostic| {
+            let fix = diagnostic.fix()?;
+            let lsp_range = diagnostic.range().to_lsp_range(&source);
+
+            Some(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title().to_string(),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(
+                    context
+                        .diagnostics
+                        .iter()
+                        .filter(|lsp_diagnostic| {
+                            lsp_diagnostic.range == lsp_range
+                                && lsp_diagnostic.message == diagnostic.message()
+                        })
+                        .cloned()
+                        .collect(),
+                ),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(
+                        uri.to_owned(),
+                        fix.edits()
+                            .iter()
+                            .map(|edit| TextEdit {
+                                range: edit.range().to_lsp_range(&source),
+                                new_text: edit.new_text().to_string(),
+                            })
+                            .collect(),
+                    )])),
+                    ..Default::default()
+                }),
+                is_preferred: Some(true),
+                ..Default::default()
+            }))
+        })
+        .collect::<Vec<_>>();
+
+    if code_actions.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(code_actions))
+    }
+}
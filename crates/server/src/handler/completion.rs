@@ -0,0 +1,38 @@
+use tower_lsp::lsp_types::{CompletionParams, CompletionResponse, TextDocumentPositionParams};
+
+use crate::{backend, completion::get_completion_contents};
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_completion(
+    backend: &backend::Backend,
+    CompletionParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    }: CompletionParams,
+) -> Result<Option<CompletionResponse>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_completion");
+
+    let Some(document_schema) = backend.get_document_schema(&text_document.uri).await else {
+        return Ok(None);
+    };
+
+    let Some(parsed) = backend.get_parsed(&text_document.uri) else {
+        return Ok(None);
+    };
+    let toml_version = backend.toml_version(&text_document.uri);
+
+    // NOTE: The document is usually incomplete while typing,
+    //       so the syntax tree is used even if there are parse errors.
+    let root = parsed.ast();
+
+    let items = get_completion_contents(&root, position.into(), &document_schema, toml_version)
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<_>>();
+
+    Ok(Some(CompletionResponse::Array(items)))
+}
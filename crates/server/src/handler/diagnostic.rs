@@ -1,7 +1,7 @@
-use config::LintOptions;
 use tower_lsp::lsp_types::{
-    DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
-    FullDocumentDiagnosticReport, RelatedFullDocumentDiagnosticReport,
+    CodeDescription, DiagnosticRelatedInformation, DocumentDiagnosticParams,
+    DocumentDiagnosticReport, DocumentDiagnosticReportResult, FullDocumentDiagnosticReport,
+    Location, NumberOrString, RelatedFullDocumentDiagnosticReport, Url,
 };
 
 use crate::backend::Backend;
@@ -13,43 +13,65 @@ pub async fn handle_diagnostic(
 ) -> Result<DocumentDiagnosticReportResult, tower_lsp::jsonrpc::Error> {
     tracing::info!("handle_diagnostic");
 
-    let diagnostics = match backend.document_sources.get(&text_document.uri).as_deref() {
-        Some(document) => linter::Linter::new(
-            backend.toml_version(),
-            backend
-                .config
-                .lint
-                .as_ref()
-                .unwrap_or(&LintOptions::default()),
-            None,
+    let source_path = text_document.uri.to_file_path().ok();
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(&text_document.uri) else {
+        return Ok(DocumentDiagnosticReportResult::Report(
+            DocumentDiagnosticReport::Full(Default::default()),
+        ));
+    };
+
+    // NOTE: The syntax errors and the invalid TOML structures are already found
+    //       by the cached parse, so the linter runs only on the valid documents.
+    let diagnostics = if parsed.has_errors() {
+        parsed.diagnostics().to_vec()
+    } else {
+        linter::Linter::new(
+            backend.toml_version(&text_document.uri),
+            &backend.config(&text_document.uri).lint.unwrap_or_default(),
+            source_path.as_deref(),
             None,
             &backend.schema_store,
         )
-        .lint(&document.source)
+        .lint_parsed(parsed.parsed(), parsed.document_tree())
         .await
-        .map_or_else(
-            |diagnostics| {
-                diagnostics
-                    .into_iter()
-                    .map(|diagnostic| tower_lsp::lsp_types::Diagnostic {
-                        range: diagnostic.range().into(),
-                        severity: Some(match diagnostic.level() {
-                            diagnostic::Level::WARNING => {
-                                tower_lsp::lsp_types::DiagnosticSeverity::WARNING
-                            }
-                            diagnostic::Level::ERROR => {
-                                tower_lsp::lsp_types::DiagnosticSeverity::ERROR
-                            }
-                        }),
-                        message: diagnostic.message().to_string(),
-                        ..Default::default()
+        .err()
+        .unwrap_or_default()
+    };
+
+    let diagnostics = diagnostics
+        .into_iter()
+        .map(|diagnostic| tower_lsp::lsp_types::Diagnostic {
+            range: diagnostic.range().to_lsp_range(&source),
+            severity: Some(match diagnostic.level() {
+                diagnostic::Level::WARNING => tower_lsp::lsp_types::DiagnosticSeverity::WARNING,
+                diagnostic::Level::ERROR => tower_lsp::lsp_types::DiagnosticSeverity::ERROR,
+            }),
+            code: diagnostic
+                .code()
+                .map(|code| NumberOrString::String(code.code().to_string())),
+            code_description: diagnostic.code().and_then(|code| {
+                Some(CodeDescription {
+                    href: Url::parse(&code.url()).ok()?,
+                })
+            }),
+            message: diagnostic.message().to_string(),
+            related_information: (!diagnostic.labels().is_empty()).then(|| {
+                diagnostic
+                    .labels()
+                    .iter()
+                    .map(|label| DiagnosticRelatedInformation {
+                        location: Location::new(
+                            text_document.uri.clone(),
+                            label.range().to_lsp_range(&source),
+                        ),
+                        message: label.message().to_string(),
                     })
                     .collect()
-            },
-            |_| vec![],
-        ),
-        None => vec![],
-    };
+            }),
+            ..Default::default()
+        })
+        .collect();
 
     Ok(DocumentDiagnosticReportResult::Report(
         DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
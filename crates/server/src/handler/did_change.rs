@@ -1,4 +1,3 @@
-use dashmap::try_result::TryResult;
 use tower_lsp::lsp_types::DidChangeTextDocumentParams;
 
 use crate::backend::Backend;
@@ -14,23 +13,27 @@ pub async fn handle_did_change(
     tracing::info!("handle_did_change");
 
     let uri = &text_document.uri;
-    let mut document = match backend.document_sources.try_get_mut(uri) {
-        TryResult::Present(document) => document,
-        TryResult::Absent => {
-            tracing::warn!("document not found: {}", uri);
-            return;
-        }
-        TryResult::Locked => {
-            tracing::warn!("document is locked: {}", uri);
-            return;
-        }
+    // NOTE: The changes are incremental, so a change must never be dropped.
+    //       It waits for the other handlers, which never hold the document across an await.
+    let Some(mut document) = backend.document_sources.get_mut(uri) else {
+        tracing::warn!("document not found: {}", uri);
+        return;
     };
 
+    if document
+        .version
+        .is_some_and(|version| text_document.version <= version)
+    {
+        tracing::warn!(
+            "stale change is ignored: {} (version {})",
+            uri,
+            text_document.version
+        );
+        return;
+    }
+
     for content_change in content_changes {
-        if let Some(range) = content_change.range {
-            tracing::warn!("range change is not supported: {:?}", range);
-        } else {
-            document.source = content_change.text;
-        }
+        document.apply_change(content_change);
     }
+    document.version = Some(text_document.version);
 }
@@ -1,6 +1,38 @@
+use config::TomlVersion;
 use tower_lsp::lsp_types::DidChangeConfigurationParams;
 
+use crate::backend::Backend;
+
+/// The settings of the editor extension, such as `tombi.tomlVersion` of VSCode.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Settings {
+    toml_version: Option<TomlVersion>,
+}
+
 #[tracing::instrument(level = "debug", skip_all)]
-pub async fn handle_did_change_configuration(_params: DidChangeConfigurationParams) {
+pub async fn handle_did_change_configuration(
+    backend: &Backend,
+    DidChangeConfigurationParams { settings }: DidChangeConfigurationParams,
+) {
     tracing::info!("handle_did_change_configuration");
+
+    // NOTE: The clients using `workspace/configuration` send `null` here,
+    //       so only the settings under the `tombi` section are applied.
+    let Some(settings) = settings.get("tombi") else {
+        return;
+    };
+
+    let settings = match serde_json::from_value::<Settings>(settings.to_owned()) {
+        Ok(settings) => settings,
+        Err(err) => {
+            tracing::warn!("invalid settings: {}", err);
+            return;
+        }
+    };
+
+    if settings.toml_version != backend.editor_toml_version() {
+        backend.set_toml_version(settings.toml_version);
+        backend.refresh_diagnostics().await;
+    }
 }
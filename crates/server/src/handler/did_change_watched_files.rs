@@ -0,0 +1,32 @@
+use tower_lsp::lsp_types::{DidChangeWatchedFilesParams, MessageType};
+
+use crate::backend::Backend;
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_did_change_watched_files(
+    backend: &Backend,
+    DidChangeWatchedFilesParams { changes }: DidChangeWatchedFilesParams,
+) {
+    tracing::info!("handle_did_change_watched_files");
+
+    let config_changed = changes.iter().any(|change| {
+        change
+            .uri
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .is_some_and(|filename| {
+                filename == config::CONFIG_FILENAME || filename == config::PYPROJECT_FILENAME
+            })
+    });
+    if !config_changed {
+        return;
+    }
+
+    match backend.reload_config() {
+        Ok(()) => backend.refresh_diagnostics().await,
+        Err(err) => {
+            tracing::error!("{}", err);
+            backend.client.show_message(MessageType::WARNING, err).await;
+        }
+    }
+}
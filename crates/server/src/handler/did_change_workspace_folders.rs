@@ -0,0 +1,32 @@
+use tower_lsp::lsp_types::DidChangeWorkspaceFoldersParams;
+
+use crate::backend::Backend;
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_did_change_workspace_folders(
+    backend: &Backend,
+    DidChangeWorkspaceFoldersParams { event }: DidChangeWorkspaceFoldersParams,
+) {
+    tracing::info!("handle_did_change_workspace_folders");
+
+    for folder in &event.added {
+        tracing::debug!("workspace folder added: {}", folder.uri);
+    }
+    for folder in &event.removed {
+        tracing::debug!("workspace folder removed: {}", folder.uri);
+    }
+
+    backend.update_workspace_folders(
+        &event
+            .added
+            .iter()
+            .map(|folder| folder.uri.clone())
+            .collect::<Vec<_>>(),
+        &event
+            .removed
+            .iter()
+            .map(|folder| folder.uri.clone())
+            .collect::<Vec<_>>(),
+    );
+    backend.refresh_diagnostics().await;
+}
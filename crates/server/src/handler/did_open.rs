@@ -7,10 +7,11 @@ use crate::document::DocumentSource;
 pub async fn handle_did_open(backend: &Backend, params: DidOpenTextDocumentParams) {
     tracing::info!("handle_did_open");
 
-    let uri = params.text_document.uri.clone();
+    let uri = params.text_document.uri;
     let source = params.text_document.text;
+    let version = params.text_document.version;
 
     backend
         .document_sources
-        .insert(uri, DocumentSource::new(source));
+        .insert(uri, DocumentSource::new(source, Some(version)));
 }
@@ -14,7 +14,7 @@ pub async fn handle_did_save(
 
     if let Some(text) = text {
         if let Some(mut document_info) = backend.document_sources.get_mut(&text_document.uri) {
-            document_info.source = text;
+            document_info.set_source(text);
         }
     }
 }
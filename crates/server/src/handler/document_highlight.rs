@@ -0,0 +1,42 @@
+use tower_lsp::lsp_types::{
+    DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams, TextDocumentPositionParams,
+};
+
+use crate::{backend, key_reference::find_key_references};
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_document_highlight(
+    backend: &backend::Backend,
+    DocumentHighlightParams {
+        text_document_position_params:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    }: DocumentHighlightParams,
+) -> Result<Option<Vec<DocumentHighlight>>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_document_highlight");
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(&text_document.uri) else {
+        return Ok(None);
+    };
+
+    let Some(references) = find_key_references(
+        &parsed.ast(),
+        text::Offset::from_source(&source, position),
+        backend.toml_version(&text_document.uri),
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(Some(
+        references
+            .iter()
+            .map(|reference| DocumentHighlight {
+                range: reference.range().to_lsp_range(&source),
+                kind: Some(DocumentHighlightKind::TEXT),
+            })
+            .collect(),
+    ))
+}
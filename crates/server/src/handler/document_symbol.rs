@@ -1,5 +1,4 @@
 use crate::backend::Backend;
-use document_tree::TryIntoDocumentTree;
 use tower_lsp::lsp_types::{
     DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, SymbolKind,
 };
@@ -11,15 +10,14 @@ pub async fn handle_document_symbol(
 ) -> Result<Option<DocumentSymbolResponse>, tower_lsp::jsonrpc::Error> {
     tracing::info!("handle_document_symbol");
 
-    let Some(root) = backend.get_ast(&text_document.uri) else {
+    let Some(parsed) = backend.get_parsed(&text_document.uri) else {
         return Ok(None);
     };
-
-    let Ok(root) = root.try_into_document_tree(backend.toml_version()) else {
+    let Some(root) = parsed.document_tree() else {
         return Ok(None);
     };
 
-    let symbols = create_symbols(&root);
+    let symbols = create_symbols(root);
 
     tracing::trace!("DocumentSymbols: {symbols:#?}");
 
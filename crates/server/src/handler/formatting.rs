@@ -1,5 +1,3 @@
-use config::FormatOptions;
-use dashmap::try_result::TryResult;
 use tower_lsp::lsp_types::{DocumentFormattingParams, TextEdit};
 
 use crate::backend::Backend;
@@ -12,36 +10,26 @@ pub async fn handle_formatting(
     tracing::info!("handle_formatting: {}", text_document.uri);
 
     let uri = &text_document.uri;
-    let mut document_info = match backend.document_sources.try_get_mut(uri) {
-        TryResult::Present(document_info) => document_info,
-        TryResult::Absent => {
-            tracing::warn!("document not found: {}", uri);
-            return Ok(None);
-        }
-        TryResult::Locked => {
-            tracing::warn!("document is locked: {}", uri);
-            return Ok(None);
-        }
+    let format_options = backend.config(uri).format.unwrap_or_default();
+    let document_schema = match format_options.key_order() {
+        config::KeyOrder::Schema => backend.get_document_schema(uri).await,
+        _ => None,
+    };
+
+    // NOTE: The buffer is updated by the didChange of the returned edits,
+    //       so the formatted text must not be written to it here.
+    let Some(document_source) = backend.document_sources.get(uri) else {
+        tracing::warn!("document not found: {}", uri);
+        return Ok(None);
     };
 
-    match formatter::Formatter::new(
-        backend.toml_version(),
-        backend
-            .config
-            .format
-            .as_ref()
-            .unwrap_or(&FormatOptions::default()),
-    )
-    .format(&document_info.source)
+    match formatter::Formatter::new(backend.toml_version(uri), &format_options)
+        .with_schema(document_schema.as_ref())
+        .format(document_source.source())
     {
         Ok(new_text) => {
-            if new_text != document_info.source {
-                document_info.source = new_text.clone();
-
-                return Ok(Some(vec![TextEdit {
-                    range: text::Range::new(text::Position::MIN, text::Position::MAX).into(),
-                    new_text,
-                }]));
+            if new_text != document_source.source() {
+                return Ok(Some(text_edits(document_source.source(), &new_text)));
             } else {
                 tracing::info!("no change");
             }
@@ -53,3 +41,47 @@ pub async fn handle_formatting(
 
     Ok(None)
 }
+
+/// The minimal edits from the source to the formatted text.
+fn text_edits(source: &str, new_text: &str) -> Vec<TextEdit> {
+    formatter::text_edits(source, new_text)
+        .into_iter()
+        .map(|edit| TextEdit {
+            range: edit.range().to_lsp_range(source),
+            new_text: edit.new_text().to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+    use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
+
+    use super::*;
+    use crate::document::DocumentSource;
+
+    #[rstest]
+    #[case("a=1\nb  =  2\n")]
+    #[case("[t]\nx=[1,2,3]  # comment\n\n\n[u]\ny={a=1}")]
+    #[case("s = \"🦀\"\nt=\"あ\"\n")]
+    fn edits_applied_as_changes(#[case] source: &str) {
+        let options = config::FormatOptions::default();
+        let formatted = formatter::Formatter::new(config::TomlVersion::default(), &options)
+            .format(source)
+            .unwrap();
+
+        // NOTE: The client sends the edits as the changes from the end of the document,
+        //       so that the ranges of the other edits are still valid.
+        let mut document_source = DocumentSource::new(source, Some(0));
+        for edit in text_edits(source, &formatted).into_iter().rev() {
+            document_source.apply_change(TextDocumentContentChangeEvent {
+                range: Some(edit.range),
+                range_length: None,
+                text: edit.new_text,
+            });
+        }
+
+        assert_eq!(document_source.source(), formatted);
+    }
+}
@@ -0,0 +1,48 @@
+use tower_lsp::lsp_types::{
+    GotoDefinitionParams, GotoDefinitionResponse, Location, TextDocumentPositionParams,
+};
+
+use crate::{backend, key_reference::find_key_references};
+
+/// Go to all the keys defining the same table or value as the key under the cursor.
+///
+/// A table of TOML can be defined in several places, e.g. `[a.b]`, `a.b.c = 1` and `[[a.b.d]]`,
+/// so every place is a definition.
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_goto_definition(
+    backend: &backend::Backend,
+    GotoDefinitionParams {
+        text_document_position_params:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    }: GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_goto_definition");
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(&text_document.uri) else {
+        return Ok(None);
+    };
+
+    let Some(references) = find_key_references(
+        &parsed.ast(),
+        text::Offset::from_source(&source, position),
+        backend.toml_version(&text_document.uri),
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(Some(GotoDefinitionResponse::Array(
+        references
+            .iter()
+            .map(|reference| {
+                Location::new(
+                    text_document.uri.clone(),
+                    reference.range().to_lsp_range(&source),
+                )
+            })
+            .collect(),
+    )))
+}
@@ -1,4 +1,4 @@
-use crate::{backend, hover::HoverContent, toml};
+use crate::{backend, hover::HoverContent};
 use ast::{algo::ancestors_at_position, AstNode};
 use document_tree::TryIntoDocumentTree;
 use itertools::Itertools;
@@ -19,14 +19,14 @@ pub async fn handle_hover(
 ) -> Result<Option<Hover>, tower_lsp::jsonrpc::Error> {
     tracing::info!("handle_hover");
 
-    let source = toml::try_load(&text_document.uri)?;
-    let position = position.into();
-    let toml_version = backend.toml_version();
+    let document_schema = backend.get_document_schema(&text_document.uri).await;
 
-    let Some(root) = ast::Root::cast(parser::parse(&source, toml_version).into_syntax_node())
-    else {
+    let Some(parsed) = backend.get_parsed(&text_document.uri) else {
         return Ok(None);
     };
+    let root = parsed.ast();
+    let position = position.into();
+    let toml_version = backend.toml_version(&text_document.uri);
 
     let Some((keys, range)) = get_hover_range(&root, position, toml_version) else {
         return Ok(None);
@@ -36,22 +36,38 @@ pub async fn handle_hover(
         return Ok(None);
     }
 
-    let Ok(root) = root.try_into_document_tree(toml_version) else {
+    let Some(root) = parsed.document_tree() else {
         return Ok(None);
     };
 
-    let Some(keys_value_info) = get_keys_value_info(root, &keys, position) else {
+    let Some(keys_value_info) = get_keys_value_info(root.clone(), &keys, position) else {
         return Ok(None);
     };
 
-    return Ok(Some(
-        HoverContent {
-            keys_value_info: Some(keys_value_info),
-            range,
-            ..Default::default()
+    let mut hover_content = HoverContent {
+        range,
+        ..Default::default()
+    };
+
+    if let Some(document_schema) = &document_schema {
+        if let Some(value_schema) =
+            document_schema.find_schema(keys_value_info.accessors().as_slice())
+        {
+            hover_content.title = value_schema.title.clone();
+            hover_content.description = value_schema.description.clone();
+            hover_content.enumerated_values = document_schema
+                .flatten(value_schema)
+                .into_iter()
+                .flat_map(|schema| &schema.enumerated_values)
+                .map(ToString::to_string)
+                .unique()
+                .collect();
         }
-        .into(),
-    ));
+        hover_content.schema_url = document_schema.schema_url.clone();
+    }
+    hover_content.keys_value_info = Some(keys_value_info);
+
+    return Ok(Some(hover_content.into()));
 }
 
 fn get_hover_range(
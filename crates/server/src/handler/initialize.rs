@@ -1,9 +1,12 @@
 use tower_lsp::lsp_types::{
-    ClientCapabilities, ClientInfo, DiagnosticOptions, DiagnosticServerCapabilities,
+    ClientCapabilities, ClientInfo, CodeActionKind, CodeActionOptions,
+    CodeActionProviderCapability, CompletionOptions, CompletionOptionsCompletionItem,
+    DiagnosticOptions, DiagnosticServerCapabilities, DocumentOnTypeFormattingOptions,
     FoldingRangeProviderCapability, HoverProviderCapability, InitializeParams, InitializeResult,
-    OneOf, PositionEncodingKind, SaveOptions, SemanticTokenModifier, SemanticTokensFullOptions,
-    SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities, ServerInfo,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    OneOf, PositionEncodingKind, RenameOptions, SaveOptions, SemanticTokenModifier,
+    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities,
+    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
 use crate::semantic_tokens::SUPPORTED_TOKEN_TYPES;
@@ -32,68 +35,78 @@ pub async fn handle_initialize(
     })
 }
 
-pub fn server_capabilities(_client_capabilities: &ClientCapabilities) -> ServerCapabilities {
+pub fn server_capabilities(client_capabilities: &ClientCapabilities) -> ServerCapabilities {
     ServerCapabilities {
         position_encoding: Some(PositionEncodingKind::UTF16),
         text_document_sync: Some(TextDocumentSyncCapability::Options(
             TextDocumentSyncOptions {
                 open_close: Some(true),
-                change: Some(TextDocumentSyncKind::FULL),
+                change: Some(TextDocumentSyncKind::INCREMENTAL),
                 save: Some(SaveOptions::default().into()),
                 ..Default::default()
             },
         )),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
-        // completion_provider: Some(CompletionOptions {
-        //     trigger_characters: Some(vec![
-        //         ".".into(),
-        //         "=".into(),
-        //         "[".into(),
-        //         "{".into(),
-        //         ",".into(),
-        //         "'".into(),
-        //         "\"".into(),
-        //     ]),
-        //     completion_item: Some(CompletionOptionsCompletionItem {
-        //         label_details_support: (|| -> _ {
-        //             client_capabilities
-        //                 .text_document
-        //                 .as_ref()?
-        //                 .completion
-        //                 .as_ref()?
-        //                 .completion_item
-        //                 .as_ref()?
-        //                 .label_details_support
-        //         })(),
-        //     }),
-        //     ..Default::default()
-        // }),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![
+                ".".into(),
+                "=".into(),
+                "[".into(),
+                "{".into(),
+                ",".into(),
+                "'".into(),
+                "\"".into(),
+            ]),
+            completion_item: Some(CompletionOptionsCompletionItem {
+                label_details_support: (|| -> _ {
+                    client_capabilities
+                        .text_document
+                        .as_ref()?
+                        .completion
+                        .as_ref()?
+                        .completion_item
+                        .as_ref()?
+                        .label_details_support
+                })(),
+            }),
+            ..Default::default()
+        }),
         // declaration_provider: Some(DeclarationCapability::Simple(true)),
-        // definition_provider: Some(OneOf::Left(true)),
+        definition_provider: Some(OneOf::Left(true)),
         // type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
         // implementation_provider: Some(ImplementationProviderCapability::Simple(true)),
-        // references_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        document_highlight_provider: Some(OneOf::Left(true)),
         document_symbol_provider: Some(OneOf::Left(true)),
         // workspace_symbol_provider: Some(OneOf::Left(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            ..Default::default()
+        })),
         // code_lens_provider: Some(CodeLensOptions {
         //     resolve_provider: Some(true),
         // }),
         document_formatting_provider: Some(OneOf::Left(true)),
+        document_range_formatting_provider: Some(OneOf::Left(true)),
+        document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+            first_trigger_character: "]".into(),
+            more_trigger_character: Some(vec!["}".into(), "\n".into()]),
+        }),
         // selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
         folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
-        // rename_provider: Some(OneOf::Right(RenameOptions {
-        //     prepare_provider: Some(true),
-        //     work_done_progress_options: WorkDoneProgressOptions {
-        //         work_done_progress: None,
-        //     },
-        // })),
-        // workspace: Some(WorkspaceServerCapabilities {
-        //     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
-        //         supported: Some(true),
-        //         change_notifications: Some(OneOf::Left(true)),
-        //     }),
-        //     file_operations: None,
-        // }),
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        })),
+        workspace: Some(WorkspaceServerCapabilities {
+            workspace_folders: Some(WorkspaceFoldersServerCapabilities {
+                supported: Some(true),
+                change_notifications: Some(OneOf::Left(true)),
+            }),
+            file_operations: None,
+        }),
         // call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
         semantic_tokens_provider: Some(
             SemanticTokensOptions {
@@ -0,0 +1,36 @@
+use tower_lsp::lsp_types::{
+    DidChangeWatchedFilesRegistrationOptions, FileSystemWatcher, GlobPattern, InitializedParams,
+    Registration,
+};
+
+use crate::backend::Backend;
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_initialized(backend: &Backend, _params: InitializedParams) {
+    tracing::info!("handle_initialized");
+
+    if !backend.dynamic_watched_files_registration() {
+        return;
+    }
+
+    let watchers = [config::CONFIG_FILENAME, config::PYPROJECT_FILENAME]
+        .into_iter()
+        .map(|filename| FileSystemWatcher {
+            glob_pattern: GlobPattern::String(format!("**/{filename}")),
+            kind: None,
+        })
+        .collect();
+
+    let registration = Registration {
+        id: "tombi/didChangeWatchedFiles".to_string(),
+        method: "workspace/didChangeWatchedFiles".to_string(),
+        register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
+            watchers,
+        })
+        .ok(),
+    };
+
+    if let Err(err) = backend.client.register_capability(vec![registration]).await {
+        tracing::warn!("failed to register file watchers: {}", err);
+    }
+}
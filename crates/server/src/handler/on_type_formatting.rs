@@ -0,0 +1,37 @@
+use tower_lsp::lsp_types::{
+    DocumentOnTypeFormattingParams, Position, Range, TextDocumentPositionParams, TextEdit,
+};
+
+use super::range_formatting::format_range;
+use crate::backend::Backend;
+
+/// Format the top-level item just typed, when `]`, `}` or a line break is typed.
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_on_type_formatting(
+    backend: &Backend,
+    DocumentOnTypeFormattingParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ch,
+        ..
+    }: DocumentOnTypeFormattingParams,
+) -> Result<Option<Vec<TextEdit>>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_on_type_formatting: {}", text_document.uri);
+
+    // NOTE: After a line break, the cursor is on the new line,
+    //       so the item of the previous line is formatted.
+    let start = if ch == "\n" {
+        Position::new(position.line.saturating_sub(1), 0)
+    } else {
+        position
+    };
+
+    Ok(format_range(
+        backend,
+        &text_document.uri,
+        Range::new(start, position),
+    ))
+}
@@ -0,0 +1,39 @@
+use tower_lsp::lsp_types::{PrepareRenameResponse, TextDocumentPositionParams};
+
+use crate::{backend, key_reference::find_key_references};
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_prepare_rename(
+    backend: &backend::Backend,
+    TextDocumentPositionParams {
+        text_document,
+        position,
+    }: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_prepare_rename");
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(&text_document.uri) else {
+        return Ok(None);
+    };
+    let offset = text::Offset::from_source(&source, position);
+    let toml_version = backend.toml_version(&text_document.uri);
+
+    let Some(reference) =
+        find_key_references(&parsed.ast(), offset, toml_version).and_then(|references| {
+            references
+                .into_iter()
+                .find(|reference| reference.span().contains(offset))
+        })
+    else {
+        return Ok(None);
+    };
+
+    let Ok(placeholder) = reference.key.try_to_raw_text(toml_version) else {
+        return Ok(None);
+    };
+
+    Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range: reference.range().to_lsp_range(&source),
+        placeholder,
+    }))
+}
@@ -0,0 +1,55 @@
+use tower_lsp::lsp_types::{DocumentRangeFormattingParams, TextEdit};
+
+use crate::backend::Backend;
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_range_formatting(
+    backend: &Backend,
+    DocumentRangeFormattingParams {
+        text_document,
+        range,
+        ..
+    }: DocumentRangeFormattingParams,
+) -> Result<Option<Vec<TextEdit>>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_range_formatting: {}", text_document.uri);
+
+    Ok(format_range(backend, &text_document.uri, range))
+}
+
+/// Format the top-level items of the document overlapping the range.
+pub(crate) fn format_range(
+    backend: &Backend,
+    uri: &tower_lsp::lsp_types::Url,
+    range: tower_lsp::lsp_types::Range,
+) -> Option<Vec<TextEdit>> {
+    let Some(document_source) = backend.document_sources.get(uri) else {
+        tracing::warn!("document not found: {}", uri);
+        return None;
+    };
+    let span = text::Span::new(
+        text::Offset::from_source(document_source.source(), range.start),
+        text::Offset::from_source(document_source.source(), range.end),
+    );
+
+    match formatter::Formatter::new(
+        backend.toml_version(uri),
+        &backend.config(uri).format.unwrap_or_default(),
+    )
+    .format_range(document_source.source(), span)
+    {
+        Ok(Some(edit)) => {
+            return Some(vec![TextEdit {
+                range: edit.range().to_lsp_range(document_source.source()),
+                new_text: edit.new_text().to_string(),
+            }]);
+        }
+        Ok(None) => {
+            tracing::info!("no change");
+        }
+        Err(_) => {
+            tracing::error!("failed to format");
+        }
+    }
+
+    None
+}
@@ -0,0 +1,44 @@
+use tower_lsp::lsp_types::{Location, ReferenceParams, TextDocumentPositionParams};
+
+use crate::{backend, key_reference::find_key_references};
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_references(
+    backend: &backend::Backend,
+    ReferenceParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    }: ReferenceParams,
+) -> Result<Option<Vec<Location>>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_references");
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(&text_document.uri) else {
+        return Ok(None);
+    };
+
+    // NOTE: Every key is both a definition and a reference of the table or value,
+    //       so the declarations are always included.
+    let Some(references) = find_key_references(
+        &parsed.ast(),
+        text::Offset::from_source(&source, position),
+        backend.toml_version(&text_document.uri),
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(Some(
+        references
+            .iter()
+            .map(|reference| {
+                Location::new(
+                    text_document.uri.clone(),
+                    reference.range().to_lsp_range(&source),
+                )
+            })
+            .collect(),
+    ))
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use ast::AstNode;
+use config::TomlVersion;
+use tower_lsp::lsp_types::{RenameParams, TextDocumentPositionParams, TextEdit, WorkspaceEdit};
+
+use crate::{backend, key_reference::find_key_references};
+
+/// Rename the key under the cursor and all the keys referring to the same table or value.
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn handle_rename(
+    backend: &backend::Backend,
+    RenameParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        new_name,
+        ..
+    }: RenameParams,
+) -> Result<Option<WorkspaceEdit>, tower_lsp::jsonrpc::Error> {
+    tracing::info!("handle_rename");
+
+    let Some((source, parsed)) = backend.get_source_and_parsed(&text_document.uri) else {
+        return Ok(None);
+    };
+    let toml_version = backend.toml_version(&text_document.uri);
+
+    let Some(edits) = rename_edits(&source, &parsed.ast(), position, &new_name, toml_version)
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(HashMap::from([(text_document.uri, edits)])),
+        ..Default::default()
+    }))
+}
+
+fn rename_edits(
+    source: &str,
+    root: &ast::Root,
+    position: tower_lsp::lsp_types::Position,
+    new_name: &str,
+    toml_version: TomlVersion,
+) -> Option<Vec<TextEdit>> {
+    let references = find_key_references(
+        root,
+        text::Offset::from_source(source, position),
+        toml_version,
+    )?;
+
+    Some(
+        references
+            .iter()
+            .map(|reference| TextEdit {
+                range: reference.range().to_lsp_range(source),
+                new_text: rename_key(&reference.key, new_name, toml_version),
+            })
+            .collect(),
+    )
+}
+
+/// Get the text of the renamed key, keeping the quotes of the key.
+///
+/// The bare key is quoted if the new name is not a valid bare key in the TOML version,
+/// and the literal string is changed to the basic string if the new name cannot be written in it.
+fn rename_key(key: &ast::Key, new_name: &str, toml_version: TomlVersion) -> String {
+    match key {
+        ast::Key::BareKey(_) if is_bare_key(new_name, toml_version) => new_name.to_string(),
+        ast::Key::LiteralString(_)
+            if !new_name
+                .chars()
+                .any(|c| c == '\'' || (c.is_control() && c != '\t')) =>
+        {
+            format!("'{new_name}'")
+        }
+        _ => to_basic_string(new_name),
+    }
+}
+
+fn is_bare_key(name: &str, toml_version: TomlVersion) -> bool {
+    let Some(root) =
+        ast::Root::cast(parser::parse(&format!("{name} = 0"), toml_version).into_syntax_node())
+    else {
+        return false;
+    };
+    let Some(ast::RootItem::KeyValue(key_value)) = root.items().next() else {
+        return false;
+    };
+
+    matches!(
+        key_value
+            .keys()
+            .map(|keys| keys.keys().collect::<Vec<_>>())
+            .as_deref(),
+        Some([key @ ast::Key::BareKey(_)]) if key.syntax().text() == name
+    ) && document_tree::support::string::try_from_bare_key(name, toml_version).is_ok()
+}
+
+fn to_basic_string(value: &str) -> String {
+    let mut basic_string = String::with_capacity(value.len() + 2);
+    basic_string.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => basic_string.push_str("\\\""),
+            '\\' => basic_string.push_str("\\\\"),
+            '\u{8}' => basic_string.push_str("\\b"),
+            '\t' => basic_string.push_str("\\t"),
+            '\n' => basic_string.push_str("\\n"),
+            '\u{c}' => basic_string.push_str("\\f"),
+            '\r' => basic_string.push_str("\\r"),
+            c if c.is_control() => basic_string.push_str(&format!("\\u{:04X}", c as u32)),
+            c => basic_string.push(c),
+        }
+    }
+    basic_string.push('"');
+    basic_string
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::document::DocumentSource;
+    use rstest::rstest;
+    use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
+
+    #[rstest]
+    #[case("a", "b", TomlVersion::V1_0_0, "b")]
+    #[case("a", "b-c_1", TomlVersion::V1_0_0, "b-c_1")]
+    #[case("a", "b.c", TomlVersion::V1_0_0, r#""b.c""#)]
+    #[case("a", "b c", TomlVersion::V1_0_0, r#""b c""#)]
+    #[case("a", "", TomlVersion::V1_0_0, r#""""#)]
+    #[case("a", "ключ", TomlVersion::V1_0_0, r#""ключ""#)]
+    #[case("a", "ключ", TomlVersion::V1_1_0_Preview, "ключ")]
+    #[case("\"a\"", "b", TomlVersion::V1_0_0, r#""b""#)]
+    #[case("\"a\"", "b\"c\\", TomlVersion::V1_0_0, r#""b\"c\\""#)]
+    #[case("'a'", "b c", TomlVersion::V1_0_0, "'b c'")]
+    #[case("'a'", "it's", TomlVersion::V1_0_0, r#""it's""#)]
+    #[case("'a'", "b\nc", TomlVersion::V1_0_0, r#""b\nc""#)]
+    fn renamed_key(
+        #[case] key: &str,
+        #[case] new_name: &str,
+        #[case] toml_version: TomlVersion,
+        #[case] expected: &str,
+    ) {
+        let root =
+            ast::Root::cast(parser::parse(&format!("{key} = 0"), toml_version).into_syntax_node())
+                .unwrap();
+        let Some(ast::RootItem::KeyValue(key_value)) = root.items().next() else {
+            unreachable!()
+        };
+        let key = key_value.keys().unwrap().keys().next().unwrap();
+
+        assert_eq!(rename_key(&key, new_name, toml_version), expected);
+    }
+
+    #[rstest]
+    #[case("x = { s = \"🦀\", name = 1 }", (0, 16), "id", "x = { s = \"🦀\", id = 1 }")]
+    #[case(
+        "[\"🦀\".a]\nb = 1\n\n[\"🦀\"]\na.c = 2",
+        (4, 0),
+        "d",
+        "[\"🦀\".d]\nb = 1\n\n[\"🦀\"]\nd.c = 2"
+    )]
+    fn rename_edits_applied_as_changes(
+        #[case] source: &str,
+        #[case] position: (u32, u32),
+        #[case] new_name: &str,
+        #[case] expected: &str,
+    ) {
+        let root =
+            ast::Root::cast(parser::parse(source, TomlVersion::default()).into_syntax_node())
+                .unwrap();
+        let edits = rename_edits(
+            source,
+            &root,
+            tower_lsp::lsp_types::Position::new(position.0, position.1),
+            new_name,
+            TomlVersion::default(),
+        )
+        .unwrap();
+
+        let mut document_source = DocumentSource::new(source, Some(0));
+        for edit in edits.into_iter().rev() {
+            document_source.apply_change(TextDocumentContentChangeEvent {
+                range: Some(edit.range),
+                range_length: None,
+                text: edit.new_text,
+            });
+        }
+
+        assert_eq!(document_source.source(), expected);
+    }
+}
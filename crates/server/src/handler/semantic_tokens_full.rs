@@ -1,23 +1,18 @@
 use crate::semantic_tokens::AppendSemanticTokens;
-use crate::toml;
 use crate::{backend::Backend, semantic_tokens::SemanticTokensBuilder};
-use ast::AstNode;
-use config::TomlVersion;
 use tower_lsp::lsp_types::{SemanticTokens, SemanticTokensParams, SemanticTokensResult};
 
 #[tracing::instrument(level = "debug", skip_all)]
 pub async fn handle_semantic_tokens_full(
-    _backend: &Backend,
+    backend: &Backend,
     SemanticTokensParams { text_document, .. }: SemanticTokensParams,
 ) -> Result<Option<SemanticTokensResult>, tower_lsp::jsonrpc::Error> {
     tracing::info!("semantic_tokens_full");
 
-    let source = toml::try_load(&text_document.uri)?;
-
-    let p = parser::parse(&source, TomlVersion::default());
-    let Some(ast) = ast::Root::cast(p.into_syntax_node()) else {
+    let Some(parsed) = backend.get_parsed(&text_document.uri) else {
         return Ok(None);
     };
+    let ast = parsed.ast();
 
     let mut tokens_builder = SemanticTokensBuilder::new();
     ast.append_semantic_tokens(&mut tokens_builder);
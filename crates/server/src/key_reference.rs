@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use config::TomlVersion;
+use schema_store::Accessor;
+
+/// A key of the document, with the accessors of the value it refers to.
+///
+/// The keys referring to the same value, such as `a` of `[a.b]` and `a.c = 1`,
+/// have the same accessors, because TOML merges them into the same table.
+#[derive(Debug, Clone)]
+pub struct KeyReference {
+    pub accessors: Vec<Accessor>,
+    pub key: ast::Key,
+}
+
+impl KeyReference {
+    #[inline]
+    pub fn range(&self) -> text::Range {
+        self.key.token().unwrap().range()
+    }
+
+    #[inline]
+    pub fn span(&self) -> text::Span {
+        self.key.token().unwrap().span()
+    }
+}
+
+/// Get the keys referring to the same value as the key at the offset.
+pub fn find_key_references(
+    root: &ast::Root,
+    offset: text::Offset,
+    toml_version: TomlVersion,
+) -> Option<Vec<KeyReference>> {
+    let references = get_key_references(root, toml_version);
+    let target = references
+        .iter()
+        .find(|reference| reference.span().contains(offset))?;
+
+    Some(
+        references
+            .iter()
+            .filter(|reference| reference.accessors == target.accessors)
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Get all the keys of the document in the order of appearance.
+pub fn get_key_references(root: &ast::Root, toml_version: TomlVersion) -> Vec<KeyReference> {
+    let mut collector = KeyReferenceCollector {
+        toml_version,
+        array_of_tables_lengths: HashMap::new(),
+        references: vec![],
+    };
+
+    for item in root.items() {
+        match item {
+            ast::RootItem::Table(table) => {
+                let Some(accessors) = table
+                    .header()
+                    .and_then(|header| collector.collect_header(header, false))
+                else {
+                    continue;
+                };
+                for key_value in table.key_values() {
+                    collector.collect_key_value(&accessors, key_value);
+                }
+            }
+            ast::RootItem::ArrayOfTables(array_of_tables) => {
+                let Some(accessors) = array_of_tables
+                    .header()
+                    .and_then(|header| collector.collect_header(header, true))
+                else {
+                    continue;
+                };
+                for key_value in array_of_tables.key_values() {
+                    collector.collect_key_value(&accessors, key_value);
+                }
+            }
+            ast::RootItem::KeyValue(key_value) => collector.collect_key_value(&[], key_value),
+        }
+    }
+
+    collector.references
+}
+
+struct KeyReferenceCollector {
+    toml_version: TomlVersion,
+    /// The number of the tables of each array of tables defined so far.
+    array_of_tables_lengths: HashMap<Vec<Accessor>, usize>,
+    references: Vec<KeyReference>,
+}
+
+impl KeyReferenceCollector {
+    /// Collect the keys of the table header, and return the accessors of the table.
+    ///
+    /// The keys after an array of tables refer to its last table,
+    /// and the header of an array of tables adds a new table to it.
+    fn collect_header(
+        &mut self,
+        header: ast::Keys,
+        is_array_of_tables: bool,
+    ) -> Option<Vec<Accessor>> {
+        let keys = header.keys().collect::<Vec<_>>();
+        let mut accessors = vec![];
+
+        for (i, key) in keys.iter().enumerate() {
+            accessors.push(Accessor::Key(key.try_to_raw_text(self.toml_version).ok()?));
+            self.references.push(KeyReference {
+                accessors: accessors.clone(),
+                key: key.clone(),
+            });
+
+            if i + 1 == keys.len() && is_array_of_tables {
+                let length = self
+                    .array_of_tables_lengths
+                    .entry(accessors.clone())
+                    .or_default();
+                *length += 1;
+                accessors.push(Accessor::Index(*length - 1));
+            } else if let Some(length) = self.array_of_tables_lengths.get(&accessors) {
+                accessors.push(Accessor::Index(length - 1));
+            }
+        }
+
+        Some(accessors)
+    }
+
+    fn collect_key_value(&mut self, parent: &[Accessor], key_value: ast::KeyValue) {
+        let mut accessors = parent.to_vec();
+        for key in key_value.keys().iter().flat_map(|keys| keys.keys()) {
+            let Ok(key_text) = key.try_to_raw_text(self.toml_version) else {
+                return;
+            };
+            accessors.push(Accessor::Key(key_text));
+            self.references.push(KeyReference {
+                accessors: accessors.clone(),
+                key,
+            });
+        }
+
+        if let Some(value) = key_value.value() {
+            self.collect_value(&accessors, value);
+        }
+    }
+
+    fn collect_value(&mut self, parent: &[Accessor], value: ast::Value) {
+        match value {
+            ast::Value::InlineTable(inline_table) => {
+                for key_value in inline_table.key_values() {
+                    self.collect_key_value(parent, key_value);
+                }
+            }
+            ast::Value::Array(array) => {
+                for (index, value) in array.values().enumerate() {
+                    let mut accessors = parent.to_vec();
+                    accessors.push(Accessor::Index(index));
+                    self.collect_value(&accessors, value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ast::AstNode;
+    use rstest::rstest;
+
+    fn reference_ranges(source: &str, position: (u32, u32)) -> Vec<text::Range> {
+        let root =
+            ast::Root::cast(parser::parse(source, TomlVersion::default()).into_syntax_node())
+                .unwrap();
+
+        find_key_references(
+            &root,
+            text::Offset::from_position(source, position.into()),
+            TomlVersion::default(),
+        )
+        .unwrap_or_default()
+        .iter()
+        .map(KeyReference::range)
+        .collect()
+    }
+
+    #[rstest]
+    #[case::table_and_dotted_key(
+        "[a.b]\nx = 1\n\n[c]\n\n[a]\nb.y = 2",
+        (0, 3),
+        vec![((0, 3), (0, 4)), ((6, 0), (6, 1))]
+    )]
+    #[case::root_dotted_key(
+        "a.b.c = 1\n\n[a.b]\nd = 2\n\n[[a.b.e]]",
+        (0, 0),
+        vec![((0, 0), (0, 1)), ((2, 1), (2, 2)), ((5, 2), (5, 3))]
+    )]
+    #[case::quoted_key(
+        "\"a\".b = 1\n\n['a']\nc = 2",
+        (2, 2),
+        vec![((0, 0), (0, 3)), ((2, 1), (2, 4))]
+    )]
+    #[case::array_of_tables_header(
+        "[[bin]]\nname = \"a\"\n\n[[bin]]\nname = \"b\"",
+        (3, 3),
+        vec![((0, 2), (0, 5)), ((3, 2), (3, 5))]
+    )]
+    #[case::array_of_tables_element(
+        "[[bin]]\nname = \"a\"\n\n[[bin]]\nname = \"b\"",
+        (4, 0),
+        vec![((4, 0), (4, 4))]
+    )]
+    #[case::subtable_of_last_element(
+        "[[bin]]\n[bin.x]\na = 1\n\n[[bin]]\n[bin.x]\na = 2\n[bin.x.y]",
+        (7, 5),
+        vec![((5, 5), (5, 6)), ((7, 5), (7, 6))]
+    )]
+    #[case::inline_table(
+        "a = { b = 1 }\n\n[c]\nd = [{ b = 1 }, { b = 2 }]",
+        (0, 6),
+        vec![((0, 6), (0, 7))]
+    )]
+    #[case::after_multibyte_characters(
+        "x = { s = \"🦀\", name = 1 }",
+        (0, 15),
+        vec![((0, 15), (0, 19))]
+    )]
+    fn key_references(
+        #[case] source: &str,
+        #[case] position: (u32, u32),
+        #[case] expected: Vec<((u32, u32), (u32, u32))>,
+    ) {
+        assert_eq!(
+            reference_ranges(source, position),
+            expected
+                .into_iter()
+                .map(text::Range::from)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,9 +1,10 @@
 mod backend;
+mod completion;
 mod document;
 mod handler;
 mod hover;
+mod key_reference;
 mod semantic_tokens;
-mod toml;
 
 use config::TomlVersion;
 
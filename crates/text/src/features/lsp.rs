@@ -29,22 +29,90 @@ impl From<tower_lsp::lsp_types::Range> for crate::Range {
 }
 
 impl crate::Offset {
+    /// Convert an LSP position to the UTF-8 offset in the source.
+    ///
+    /// The character of the LSP position is counted in UTF-16 code units,
+    /// and a position beyond the end of the line is clamped to the line end.
     pub fn from_source(source: &str, position: tower_lsp::lsp_types::Position) -> Self {
-        let mut line = 0;
-        let mut column = 0;
-        let mut offset = 0;
-        for (i, c) in source.char_indices() {
-            if line == position.line && column == position.character {
-                return Self::new(offset as u32);
-            }
-            if c == '\n' {
-                line += 1;
-                column = 0;
-            } else {
-                column += 1;
-            }
-            offset = i + c.len_utf8();
-        }
-        Self::new(offset as u32)
+        Self::from_line_column(source, position.line, position.character, |c| {
+            c.len_utf16() as u32
+        })
+    }
+}
+
+impl crate::Position {
+    /// Convert the position to an LSP position in the source.
+    ///
+    /// The column of the position is counted in characters,
+    /// while the character of the LSP position is counted in UTF-16 code units.
+    pub fn to_lsp_position(self, source: &str) -> tower_lsp::lsp_types::Position {
+        let character = source
+            .split('\n')
+            .nth(self.line() as usize)
+            .map(|line| {
+                line.chars()
+                    .take(self.column() as usize)
+                    .map(|c| c.len_utf16() as u32)
+                    .sum()
+            })
+            .unwrap_or(self.column());
+
+        tower_lsp::lsp_types::Position::new(self.line(), character)
+    }
+}
+
+impl crate::Range {
+    /// Convert the range to an LSP range in the source.
+    ///
+    /// See [`crate::Position::to_lsp_position`].
+    pub fn to_lsp_range(self, source: &str) -> tower_lsp::lsp_types::Range {
+        tower_lsp::lsp_types::Range::new(
+            self.start().to_lsp_position(source),
+            self.end().to_lsp_position(source),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+    use tower_lsp::lsp_types::Position;
+
+    use crate::Offset;
+
+    #[rstest]
+    #[case("key = 1", (0, 3), 3)]
+    #[case("a = 1\nb = 2", (1, 0), 6)]
+    #[case("a = 1\nb = 2", (1, 100), 11)]
+    #[case("a = 1\nb = 2", (5, 0), 11)]
+    #[case("a = 1\r\nb = 2", (1, 1), 8)]
+    #[case("a = \"🦀\"\nb = 2", (0, 8), 10)]
+    #[case("a = \"あ\"", (0, 6), 8)]
+    fn offset_from_source(
+        #[case] source: &str,
+        #[case] position: (u32, u32),
+        #[case] expected: u32,
+    ) {
+        assert_eq!(
+            Offset::from_source(source, Position::new(position.0, position.1)),
+            Offset::new(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("key = 1", (0, 3), (0, 3))]
+    #[case("a = \"🦀\"\nb = 2", (0, 7), (0, 8))]
+    #[case("a = \"🦀\"\nb = 2", (1, 3), (1, 3))]
+    #[case("a = \"あ\"", (0, 6), (0, 6))]
+    #[case("a = 1", (3, 2), (3, 2))]
+    fn position_to_lsp_position(
+        #[case] source: &str,
+        #[case] position: (u32, u32),
+        #[case] expected: (u32, u32),
+    ) {
+        assert_eq!(
+            crate::Position::from(position).to_lsp_position(source),
+            Position::new(expected.0, expected.1)
+        );
     }
 }
@@ -62,6 +62,42 @@ impl Offset {
     pub fn of(text: &str) -> Offset {
         Self::new(text.len() as RawTextSize)
     }
+
+    /// Convert the position to the UTF-8 offset in the source.
+    ///
+    /// The column of the position is counted in characters,
+    /// and a position beyond the end of the line is clamped to the line end.
+    pub fn from_position(source: &str, position: crate::Position) -> Offset {
+        Self::from_line_column(source, position.line(), position.column(), |_| 1)
+    }
+
+    /// Walk to the line of the source, and then to the column on the line,
+    /// where the column is measured by `len` of each character.
+    pub(crate) fn from_line_column(
+        source: &str,
+        line: crate::Line,
+        column: crate::Column,
+        len: impl Fn(char) -> crate::Column,
+    ) -> Offset {
+        let mut offset = 0;
+        for _ in 0..line {
+            match source[offset..].find('\n') {
+                Some(index) => offset += index + 1,
+                None => return Self::of(source),
+            }
+        }
+
+        let mut current = 0;
+        for c in source[offset..].chars() {
+            if c == '\n' || current >= column {
+                break;
+            }
+            current += len(c);
+            offset += c.len_utf8();
+        }
+
+        Self::new(offset as RawTextSize)
+    }
 }
 
 /// Methods to act like a primitive integer type, where reasonably applicable.
@@ -180,3 +216,28 @@ where
         iter.fold(0.into(), Add::add)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::{Offset, Position};
+
+    #[rstest]
+    #[case("key = 1", (0, 3), 3)]
+    #[case("a = 1\nb = 2", (1, 0), 6)]
+    #[case("a = 1\nb = 2", (1, 100), 11)]
+    #[case("a = 1\nb = 2", (5, 0), 11)]
+    #[case("a = \"あ\"\nb = 2", (0, 6), 8)]
+    #[case("a = \"🦀\"\nb = 2", (0, 7), 10)]
+    fn offset_from_position(
+        #[case] source: &str,
+        #[case] position: (u32, u32),
+        #[case] expected: u32,
+    ) {
+        assert_eq!(
+            Offset::from_position(source, Position::from(position)),
+            Offset::new(expected)
+        );
+    }
+}
@@ -54,6 +54,7 @@ pub fn run(args: impl Into<Args>) -> Result<(), crate::Error> {
         command::TomlCommand::Format(args) => command::format::run(args),
         command::TomlCommand::Lint(args) => command::lint::run(args),
         command::TomlCommand::Serve(args) => command::serve::run(args),
+        command::TomlCommand::Explain(args) => command::explain::run(args),
     }
 }
 
@@ -1,3 +1,5 @@
 pub mod file;
+pub mod output_format;
 
 pub use file::FileInput;
+pub use output_format::OutputFormat;
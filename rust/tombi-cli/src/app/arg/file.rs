@@ -1,5 +1,6 @@
+use config::FilesOptions;
 use itertools::Itertools;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Input source for TOML files.
 ///
@@ -11,6 +12,27 @@ pub enum FileInput {
 }
 
 impl FileInput {
+    /// Get the input from the command line arguments.
+    ///
+    /// If no file is given, the TOML files of the project are searched
+    /// according to `[files]` of the config.
+    pub fn new<T: AsRef<str>>(
+        files: &[T],
+        config_path: Option<&Path>,
+        files_options: &FilesOptions,
+    ) -> Self {
+        if files.is_empty() {
+            FileInput::Files(search_project_files(config_path, files_options))
+        } else {
+            FileInput::from(files)
+        }
+    }
+
+    /// Whether the command line arguments select the standard input.
+    pub fn is_stdin<T: AsRef<str>>(files: &[T]) -> bool {
+        files.len() == 1 && files[0].as_ref() == "-"
+    }
+
     pub fn len(&self) -> usize {
         match self {
             FileInput::Stdin => 1,
@@ -25,17 +47,8 @@ where
 {
     fn from(files: &[T]) -> Self {
         match files.len() {
-            0 => {
-                tracing::debug!("Searching for all TOML files in the current directory...");
-
-                FileInput::Files(
-                    glob::glob("**/*.toml")
-                        .unwrap() // No Probrem. grob pattern is const.
-                        .filter_map(|x| Result::<_, crate::Error>::Ok(x.ok()).transpose())
-                        .collect_vec(),
-                )
-            }
-            1 if files[0].as_ref() == "-" => FileInput::Stdin,
+            0 => FileInput::Files(search_project_files(None, &FilesOptions::default())),
+            _ if FileInput::is_stdin(files) => FileInput::Stdin,
             _ => {
                 let mut results: Vec<Result<PathBuf, crate::Error>> = vec![];
                 for file in files {
@@ -67,6 +80,61 @@ where
     }
 }
 
+/// Search the TOML files under the directory of the config file,
+/// or the current directory if no config file is found.
+///
+/// The files ignored by `.gitignore` are skipped.
+fn search_project_files(
+    config_path: Option<&Path>,
+    files_options: &FilesOptions,
+) -> Vec<Result<PathBuf, crate::Error>> {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let root_dir = config_path
+        .and_then(Path::parent)
+        .filter(|config_dir| !config_dir.as_os_str().is_empty())
+        .unwrap_or(&current_dir);
+
+    tracing::debug!("Searching for all TOML files in {:?}...", root_dir);
+
+    let mut overrides = ignore::overrides::OverrideBuilder::new(root_dir);
+    for include in files_options.include() {
+        if overrides.add(&include).is_err() {
+            return vec![Err(crate::Error::GlobPatternInvalid(include))];
+        }
+    }
+    for exclude in files_options.exclude() {
+        if overrides.add(&format!("!{exclude}")).is_err() {
+            return vec![Err(crate::Error::GlobPatternInvalid(exclude))];
+        }
+    }
+    let overrides = match overrides.build() {
+        Ok(overrides) => overrides,
+        Err(err) => return vec![Err(crate::Error::GlobPatternInvalid(err.to_string()))],
+    };
+
+    ignore::WalkBuilder::new(root_dir)
+        .hidden(false)
+        .require_git(false)
+        .overrides(overrides)
+        .build()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_type()?.is_file() {
+                return None;
+            }
+            let path = entry.into_path();
+            Some(Ok(match path.strip_prefix(&current_dir) {
+                Ok(relative_path) => relative_path.to_owned(),
+                Err(_) => path,
+            }))
+        })
+        .sorted_by(|a, b| match (a, b) {
+            (Ok(a), Ok(b)) => a.cmp(b),
+            _ => std::cmp::Ordering::Equal,
+        })
+        .collect_vec()
+}
+
 fn is_glob_pattern(value: &str) -> bool {
     value.contains('*') || value.contains('?') || value.contains('[') || value.contains(']')
 }
@@ -89,6 +157,32 @@ mod test {
         assert!(matches!(file_input, FileInput::Files(_)));
     }
 
+    #[test]
+    fn project_files() {
+        let dir = std::env::temp_dir().join(format!("tombi-cli-test-{}", std::process::id()));
+        for sub_dir in ["crates/a", "target/debug", "node_modules/a", "ignored"] {
+            std::fs::create_dir_all(dir.join(sub_dir)).unwrap();
+            std::fs::write(dir.join(sub_dir).join("Cargo.toml"), "").unwrap();
+        }
+        std::fs::write(dir.join("tombi.toml"), "").unwrap();
+        std::fs::write(dir.join("README.md"), "").unwrap();
+        std::fs::write(dir.join(".gitignore"), "ignored/\n").unwrap();
+
+        let files = search_project_files(Some(&dir.join("tombi.toml")), &FilesOptions::default())
+            .into_iter()
+            .map(|file| file.unwrap().strip_prefix(&dir).unwrap().to_owned())
+            .collect_vec();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("crates/a/Cargo.toml"),
+                PathBuf::from("tombi.toml")
+            ]
+        );
+    }
+
     #[test]
     fn glob_file() {
         let input = vec!["**/Cargo.toml"];
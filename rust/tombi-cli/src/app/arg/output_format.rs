@@ -0,0 +1,42 @@
+/// The format of the diagnostics printed to the standard output.
+#[derive(clap::ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text with colors.
+    #[default]
+    Pretty,
+
+    /// JSON array of the diagnostics.
+    Json,
+
+    /// SARIF 2.1.0 log.
+    Sarif,
+
+    /// GitHub Actions workflow commands, shown as annotations.
+    Github,
+
+    /// GitLab Code Quality report.
+    Gitlab,
+
+    /// Checkstyle XML report.
+    Checkstyle,
+}
+
+impl OutputFormat {
+    /// Whether the diagnostics are printed for tools rather than humans,
+    /// so that nothing else can be printed to the standard output with them.
+    #[inline]
+    pub fn is_machine_readable(self) -> bool {
+        self != Self::Pretty
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use clap::ValueEnum;
+
+        match self.to_possible_value() {
+            Some(value) => f.write_str(value.get_name()),
+            None => unreachable!("OutputFormat has no skipped values"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod explain;
 pub mod format;
 pub mod lint;
 pub mod serve;
@@ -12,4 +13,6 @@ pub enum TomlCommand {
 
     #[command(alias = "lsp")]
     Serve(serve::Args),
+
+    Explain(explain::Args),
 }
@@ -0,0 +1,29 @@
+use nu_ansi_term::Style;
+
+/// Explain a diagnostic code.
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The diagnostic code, such as "T0101", or its name, such as "duplicate-key".
+    code: String,
+}
+
+pub fn run(args: Args) -> Result<(), crate::Error> {
+    let Some(code) = linter::diagnostic_codes()
+        .find(|code| code.code().eq_ignore_ascii_case(&args.code) || code.name() == args.code)
+    else {
+        return Err(crate::Error::DiagnosticCodeNotFound(args.code));
+    };
+
+    println!(
+        "{}",
+        Style::new()
+            .bold()
+            .paint(format!("{} {}", code.code(), code.name()))
+    );
+    println!();
+    print!("{}", code.explanation());
+    println!();
+    println!("See {}", code.url());
+
+    Ok(())
+}
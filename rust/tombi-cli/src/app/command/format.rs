@@ -1,6 +1,9 @@
 use crate::app::arg;
-use config::{FormatOptions, TomlVersion};
-use diagnostic::{printer::Pretty, Diagnostic, Print};
+use config::{FormatOptions, KeyOrder, TomlVersion};
+use diagnostic::{
+    printer::{Checkstyle, Github, Gitlab, Json, Pretty, Sarif},
+    Diagnostic, Output, Print,
+};
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 /// Format TOML files.
@@ -9,32 +12,74 @@ pub struct Args {
     /// Paths or glob patterns to TOML documents.
     ///
     /// If the only argument is "-", the standard input will be used.
+    /// If no argument is given, the TOML files of the project are used,
+    /// according to `[files]` of the config and `.gitignore`.
     files: Vec<String>,
 
     /// TOML version.
     #[arg(long, value_enum, default_value = None)]
     toml_version: Option<TomlVersion>,
 
+    /// The format of the diagnostics.
+    #[arg(long, value_enum, default_value_t)]
+    output_format: arg::OutputFormat,
+
     /// Check only and don't overwrite files.
     #[arg(long, default_value_t = false)]
     check: bool,
+
+    /// Print a unified diff of the changes instead of overwriting files.
+    ///
+    /// Only the pretty output format can be used with the diff.
+    #[arg(long, default_value_t = false)]
+    diff: bool,
 }
 
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn run(args: Args) -> Result<(), crate::Error> {
-    let (success_num, not_needed_num, error_num) = inner_run(args, Pretty);
+    // NOTE: The diff is not a part of the document of the machine-readable diagnostics.
+    if args.diff && args.output_format.is_machine_readable() {
+        return Err(crate::Error::DiffConflictsWithOutputFormat(
+            args.output_format.to_string(),
+        ));
+    }
+
+    // NOTE: The formatted standard input is written to the standard output,
+    //       where it would break the document of the machine-readable diagnostics.
+    if arg::FileInput::is_stdin(&args.files)
+        && !args.check
+        && args.output_format.is_machine_readable()
+    {
+        return Err(crate::Error::OutputFormatConflictsWithStdout(
+            args.output_format.to_string(),
+        ));
+    }
+
+    let formatted = if args.diff {
+        "would be formatted"
+    } else {
+        "formatted"
+    };
+    let (success_num, not_needed_num, error_num) = match args.output_format {
+        arg::OutputFormat::Pretty => inner_run(args, Pretty),
+        arg::OutputFormat::Json => inner_run(args, Json),
+        arg::OutputFormat::Sarif => inner_run(args, Sarif),
+        arg::OutputFormat::Github => inner_run(args, Github),
+        arg::OutputFormat::Gitlab => inner_run(args, Gitlab),
+        arg::OutputFormat::Checkstyle => inner_run(args, Checkstyle),
+    };
 
     match (success_num, not_needed_num) {
         (0, 0) => {
             if error_num == 0 {
-                eprintln!("No files formatted")
+                eprintln!("No files {formatted}")
             }
         }
         (success_num, not_needed_num) => {
             match success_num {
                 0 => {}
-                1 => eprintln!("1 file formatted"),
-                _ => eprintln!("{success_num} files formatted"),
+                1 => eprintln!("1 file {formatted}"),
+                _ => eprintln!("{success_num} files {formatted}"),
             };
             match not_needed_num {
                 0 => {}
@@ -60,7 +105,7 @@ fn inner_run<P>(args: Args, printer: P) -> (usize, usize, usize)
 where
     Diagnostic: Print<P>,
     crate::Error: Print<P>,
-    P: Copy + Send + 'static,
+    P: Output + Send + 'static,
 {
     let Ok(runtime) = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -71,17 +116,44 @@ where
     };
 
     runtime.block_on(async {
-        let input = arg::FileInput::from(args.files.as_ref());
+        printer.begin();
+
+        let (config, config_path) = config::load_with_path();
+        let input = arg::FileInput::new(
+            &args.files,
+            config_path.as_deref(),
+            &config.files.clone().unwrap_or_default(),
+        );
         let total_num = input.len();
         let mut success_num = 0;
         let mut not_needed_num = 0;
         let mut error_num = 0;
 
-        let config = config::load();
         let toml_version = args
             .toml_version
             .unwrap_or(config.toml_version.unwrap_or_default());
-        let options = config.format.unwrap_or_default();
+        let options = config.format.clone().unwrap_or_default();
+
+        // NOTE: The schemas are used only to sort the keys in the schema order.
+        let schema_store = schema_store::SchemaStore::default();
+        schema_store.load_config_schema(
+            config_path.clone(),
+            config.schemas.clone().unwrap_or_default(),
+        );
+        if options.key_order() == KeyOrder::Schema
+            && config
+                .schema
+                .as_ref()
+                .and_then(|schema| schema.catalog.as_ref())
+                .and_then(|catalog| catalog.enabled)
+                .unwrap_or_default()
+                .value()
+        {
+            let catalog_url = schema_store::DEFAULT_CATALOG_URL.parse().unwrap();
+            if let Err(err) = schema_store.load_catalog(&catalog_url).await {
+                tracing::warn!("{err}");
+            }
+        }
 
         match input {
             arg::FileInput::Stdin => {
@@ -89,10 +161,11 @@ where
                 match format_file(
                     FormatFile::from_stdin(),
                     printer,
-                    None,
                     toml_version,
                     args.check,
+                    args.diff,
                     &options,
+                    &schema_store,
                 )
                 .await
                 {
@@ -110,15 +183,22 @@ where
                             tracing::debug!("formatting... {:?}", &source_path);
                             match FormatFile::from_file(&source_path).await {
                                 Ok(file) => {
-                                    let options = options.clone();
+                                    let file_config =
+                                        config.for_source(&source_path, config_path.as_deref());
+                                    let toml_version = args
+                                        .toml_version
+                                        .unwrap_or(file_config.toml_version.unwrap_or_default());
+                                    let options = file_config.format.unwrap_or_default();
+                                    let schema_store = schema_store.clone();
                                     tasks.spawn(async move {
                                         format_file(
                                             file,
                                             printer,
-                                            Some(&source_path),
                                             toml_version,
                                             args.check,
+                                            args.diff,
                                             &options,
+                                            &schema_store,
                                         )
                                         .await
                                     });
@@ -163,6 +243,8 @@ where
 
         assert_eq!(success_num + not_needed_num + error_num, total_num);
 
+        printer.end();
+
         (success_num, not_needed_num, error_num)
     })
 }
@@ -170,21 +252,52 @@ where
 async fn format_file<P>(
     mut file: FormatFile,
     printer: P,
-    source_path: Option<&std::path::Path>,
     toml_version: TomlVersion,
     check: bool,
+    diff: bool,
     options: &FormatOptions,
+    schema_store: &schema_store::SchemaStore,
 ) -> Result<bool, ()>
 where
     Diagnostic: Print<P>,
     crate::Error: Print<P>,
     P: Copy,
 {
+    let source_path = file.source().map(ToOwned::to_owned);
+    let source_path = source_path.as_deref();
+
     let mut source = String::new();
     if file.read_to_string(&mut source).await.is_ok() {
-        match formatter::Formatter::new(toml_version, options).format(&source) {
+        let document_schema = match (options.key_order(), source_path) {
+            (KeyOrder::Schema, Some(source_path)) => {
+                schema_store.get_schema_from_source(source_path).await
+            }
+            _ => None,
+        };
+
+        match formatter::Formatter::new(toml_version, options)
+            .with_schema(document_schema.as_ref())
+            .format(&source)
+        {
             Ok(formatted) => {
                 if source != formatted {
+                    if diff {
+                        let name = source_path
+                            .map(|path| path.display().to_string())
+                            .unwrap_or_else(|| "stdin".to_string());
+                        print!(
+                            "{}",
+                            formatter::unified_diff(
+                                &source,
+                                &formatted,
+                                &format!("a/{name}"),
+                                &format!("b/{name}"),
+                            )
+                        );
+                        if !check {
+                            return Ok(true);
+                        }
+                    }
                     if check {
                         crate::error::NotFormattedError::from(file.source())
                             .into_error()
@@ -213,7 +326,7 @@ where
             } else {
                 diagnostics
             }
-            .print(printer),
+            .print_with_source(printer, &source),
         }
     }
     Err(())
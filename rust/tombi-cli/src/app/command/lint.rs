@@ -1,7 +1,10 @@
 use crate::app::arg;
 use config::{LintOptions, TomlVersion};
-use diagnostic::{printer::Pretty, Diagnostic, Print};
-use tokio::io::AsyncReadExt;
+use diagnostic::{
+    printer::{Checkstyle, Github, Gitlab, Json, Pretty, Sarif},
+    Diagnostic, Output, Print,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 /// Lint TOML files.
 #[derive(clap::Args, Debug)]
@@ -9,6 +12,8 @@ pub struct Args {
     /// Paths or glob patterns to TOML documents.
     ///
     /// If the only argument is "-", the standard input is used.
+    /// If no argument is given, the TOML files of the project are used,
+    /// according to `[files]` of the config and `.gitignore`.
     files: Vec<String>,
 
     /// TOML version.
@@ -19,14 +24,52 @@ pub struct Args {
     #[arg(long, value_enum, default_value = None)]
     toml_version: Option<TomlVersion>,
 
+    /// The format of the diagnostics.
+    #[arg(long, value_enum, default_value_t)]
+    output_format: arg::OutputFormat,
+
     /// Enable or disable the schema catalog.
     #[arg(long, action = clap::ArgAction::Set, default_value = "true")]
     schema_catalog_enabled: Option<bool>,
+
+    /// Fix the problems that have automatic fixes, and overwrite the files.
+    ///
+    /// If the input is the standard input, the fixed source is written to the standard output,
+    /// and the remaining problems are not printed.
+    #[arg(long, default_value_t = false)]
+    fix: bool,
+
+    /// List the lint rules and exit.
+    #[arg(long, default_value_t = false)]
+    list_rules: bool,
 }
 
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn run(args: Args) -> Result<(), crate::Error> {
-    let (success_num, error_num) = match inner_run(args, Pretty) {
+    if args.list_rules {
+        print_rules();
+        return Ok(());
+    }
+
+    // NOTE: The fixed standard input is written to the standard output,
+    //       where it would break the document of the machine-readable diagnostics.
+    if args.fix && arg::FileInput::is_stdin(&args.files) && args.output_format.is_machine_readable()
+    {
+        return Err(crate::Error::OutputFormatConflictsWithStdout(
+            args.output_format.to_string(),
+        ));
+    }
+
+    let result = match args.output_format {
+        arg::OutputFormat::Pretty => inner_run(args, Pretty),
+        arg::OutputFormat::Json => inner_run(args, Json),
+        arg::OutputFormat::Sarif => inner_run(args, Sarif),
+        arg::OutputFormat::Github => inner_run(args, Github),
+        arg::OutputFormat::Gitlab => inner_run(args, Gitlab),
+        arg::OutputFormat::Checkstyle => inner_run(args, Checkstyle),
+    };
+
+    let (success_num, error_num) = match result {
         Ok((success_num, error_num)) => (success_num, error_num),
         Err(error) => {
             tracing::error!("{}", error);
@@ -57,22 +100,41 @@ pub fn run(args: Args) -> Result<(), crate::Error> {
     Ok(())
 }
 
+/// Print the lint rules with their default severity levels.
+fn print_rules() {
+    let name_width = linter::RULES
+        .iter()
+        .map(|rule| rule.name.len())
+        .max()
+        .unwrap_or_default();
+
+    for rule in linter::RULES {
+        println!(
+            "{:<name_width$}  {:<5}  {}",
+            rule.name, rule.default_level, rule.description
+        );
+    }
+}
+
 fn inner_run<P>(args: Args, printer: P) -> Result<(usize, usize), schema_store::Error>
 where
     Diagnostic: Print<P>,
     crate::Error: Print<P>,
-    P: Copy + Clone + Send + 'static,
+    P: Output + Send + 'static,
 {
     let (config, config_path) = config::load_with_path();
     let toml_version = args
         .toml_version
         .unwrap_or(config.toml_version.unwrap_or_default());
 
-    let lint_options = config.lint.unwrap_or_default();
-    let schema_options = config.schema.unwrap_or_default();
+    let lint_options = config.lint.clone().unwrap_or_default();
+    let schema_options = config.schema.clone().unwrap_or_default();
     let schema_store = schema_store::SchemaStore::default();
 
-    schema_store.load_config_schema(config_path, config.schemas.unwrap_or_default());
+    schema_store.load_config_schema(
+        config_path.clone(),
+        config.schemas.clone().unwrap_or_default(),
+    );
 
     let Ok(runtime) = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -94,7 +156,13 @@ where
             schema_store.load_catalog(&catalog_url).await?
         }
 
-        let input = arg::FileInput::from(args.files.as_ref());
+        printer.begin();
+
+        let input = arg::FileInput::new(
+            &args.files,
+            config_path.as_deref(),
+            &config.files.clone().unwrap_or_default(),
+        );
         let total_num = input.len();
         let mut success_num = 0;
         let mut error_num = 0;
@@ -109,6 +177,7 @@ where
                     toml_version,
                     &lint_options,
                     &schema_store,
+                    args.fix,
                 )
                 .await
                 {
@@ -126,8 +195,14 @@ where
                             tracing::debug!("linting... {:?}", source_path);
                             match tokio::fs::File::open(&source_path).await {
                                 Ok(file) => {
-                                    let options = lint_options.clone();
+                                    let file_config =
+                                        config.for_source(&source_path, config_path.as_deref());
+                                    let toml_version = args
+                                        .toml_version
+                                        .unwrap_or(file_config.toml_version.unwrap_or_default());
+                                    let options = file_config.lint.unwrap_or_default();
                                     let schema_store = schema_store.clone();
+                                    let fix = args.fix;
 
                                     tasks.spawn(async move {
                                         lint_file(
@@ -137,6 +212,7 @@ where
                                             toml_version,
                                             &options,
                                             &schema_store,
+                                            fix,
                                         )
                                         .await
                                     });
@@ -178,6 +254,8 @@ where
 
         assert_eq!(success_num + error_num, total_num);
 
+        printer.end();
+
         Ok((success_num, error_num))
     })
 }
@@ -189,6 +267,7 @@ async fn lint_file<R, P>(
     toml_version: TomlVersion,
     options: &LintOptions,
     schema_store: &schema_store::SchemaStore,
+    fix: bool,
 ) -> bool
 where
     Diagnostic: Print<P>,
@@ -198,13 +277,47 @@ where
 {
     let mut source = String::new();
     if reader.read_to_string(&mut source).await.is_ok() {
-        match linter::Linter::new(toml_version, options, source_path, None, schema_store)
-            .lint(&source)
-            .await
-        {
+        let linter = || linter::Linter::new(toml_version, options, source_path, None, schema_store);
+
+        let mut result = linter().lint(&source).await;
+
+        if fix {
+            let fixed = match &result {
+                Ok(()) => source.clone(),
+                Err(diagnostics) => linter::apply_fixes(&source, diagnostics),
+            };
+            if fixed != source {
+                result = linter().lint(&fixed).await;
+            }
+
+            let written = match source_path {
+                Some(source_path) if fixed != source => tokio::fs::write(source_path, &fixed).await,
+                Some(_) => Ok(()),
+                None => {
+                    let mut stdout = tokio::io::stdout();
+                    match stdout.write_all(fixed.as_bytes()).await {
+                        Ok(()) => stdout.flush().await,
+                        Err(err) => Err(err),
+                    }
+                }
+            };
+            if let Err(err) = written {
+                crate::Error::Io(err).print(printer);
+                return false;
+            }
+
+            // NOTE: The remaining diagnostics are found in the fixed source.
+            source = fixed;
+        }
+
+        match result {
             Ok(()) => {
                 return true;
             }
+            // NOTE: The fixed source is written to the standard output,
+            //       so the remaining diagnostics are not printed into it.
+            //       The input still fails to be linted.
+            Err(_) if fix && source_path.is_none() => {}
             Err(diagnostics) => if let Some(source_path) = source_path {
                 diagnostics
                     .into_iter()
@@ -213,7 +326,7 @@ where
             } else {
                 diagnostics
             }
-            .print(printer),
+            .print_with_source(printer, &source),
         }
     }
     false
@@ -1,8 +1,8 @@
 use std::path::PathBuf;
 
 use diagnostic::{
-    printer::{Pretty, Simple},
-    Level, Print,
+    printer::{Checkstyle, Github, Gitlab, Json, Pretty, Sarif, Simple},
+    Diagnostic, Level, Print,
 };
 use nu_ansi_term::Style;
 
@@ -14,6 +14,14 @@ pub enum Error {
     FileNotFound(PathBuf),
     #[error("{0:?} is invalid glob pattern")]
     GlobPatternInvalid(String),
+    #[error("{0:?} is not a diagnostic code")]
+    DiagnosticCodeNotFound(String),
+    #[error(
+        "--output-format {0} cannot be used when the source is written to the standard output"
+    )]
+    OutputFormatConflictsWithStdout(String),
+    #[error("--diff cannot be used with --output-format {0}")]
+    DiffConflictsWithOutputFormat(String),
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
@@ -61,6 +69,27 @@ impl std::fmt::Display for NotFormattedError {
     }
 }
 
+impl Error {
+    /// The diagnostic of the error, printed by the machine-readable printers.
+    fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::new_error(self.to_string(), ((0, 0), (0, 0)));
+        let source_path = match self {
+            Self::NotFormatted(NotFormattedError { source_path }) => source_path.as_deref(),
+            Self::FileNotFound(source_path) => Some(source_path.as_path()),
+            Self::GlobPatternInvalid(_)
+            | Self::DiagnosticCodeNotFound(_)
+            | Self::OutputFormatConflictsWithStdout(_)
+            | Self::DiffConflictsWithOutputFormat(_)
+            | Self::Io(_) => None,
+        };
+
+        match source_path {
+            Some(source_path) => diagnostic.with_source_file(source_path),
+            None => diagnostic,
+        }
+    }
+}
+
 impl Print<Pretty> for Error {
     fn print(&self, _printer: Pretty) {
         self.print(Simple);
@@ -73,3 +102,17 @@ impl Print<Simple> for Error {
         println!(": {}", Style::new().bold().paint(self.to_string()));
     }
 }
+
+macro_rules! impl_print_as_diagnostic {
+    ($($printer:ty),*) => {
+        $(
+            impl Print<$printer> for Error {
+                fn print(&self, printer: $printer) {
+                    self.to_diagnostic().print(printer);
+                }
+            }
+        )*
+    };
+}
+
+impl_print_as_diagnostic!(Json, Sarif, Github, Gitlab, Checkstyle);
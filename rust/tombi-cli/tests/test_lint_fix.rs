@@ -0,0 +1,38 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+/// Run `tombi lint --fix -` with the source as the standard input,
+/// in an empty directory so that no config of this repository is used.
+fn lint_fix(source: &str) -> Output {
+    let dir = std::env::temp_dir().join(format!("tombi-cli-lint-fix-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tombi"))
+        .args(["lint", "--fix", "--schema-catalog-enabled", "false", "-"])
+        .current_dir(&dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn fixed_source_is_the_only_stdout() {
+    let output = lint_fix("\"\" = 3\na = 1\na = 2\n");
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "\"\" = 3\na = 1\n"
+    );
+}
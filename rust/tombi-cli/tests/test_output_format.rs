@@ -0,0 +1,77 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+use rstest::rstest;
+
+/// Run tombi with the source as the standard input,
+/// in an empty directory so that no config of this repository is used.
+fn tombi(args: &[&str], source: &str) -> Output {
+    let dir = std::env::temp_dir().join(format!("tombi-cli-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tombi"))
+        .args(args)
+        .current_dir(&dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[rstest]
+#[case(&["format", "--check", "--output-format", "json", "-"])]
+#[case(&["format", "--check", "--output-format", "gitlab", "-"])]
+#[case(&["format", "--check", "--output-format", "sarif", "-"])]
+#[case(&["lint", "--schema-catalog-enabled", "false", "--output-format", "json", "-"])]
+fn machine_readable_output_is_valid_json(#[case] args: &[&str]) {
+    let output = tombi(args, "a=1\na=2\n");
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        serde_json::from_str::<serde_json::Value>(&stdout).is_ok(),
+        "{stdout}"
+    );
+}
+
+#[rstest]
+#[case(&["format", "--output-format", "json", "-"])]
+#[case(&["format", "--output-format", "checkstyle", "-"])]
+#[case(&["format", "--check", "--diff", "--output-format", "json", "-"])]
+#[case(&["lint", "--fix", "--schema-catalog-enabled", "false", "--output-format", "json", "-"])]
+fn machine_readable_output_conflicts_with_stdout(#[case] args: &[&str]) {
+    let output = tombi(args, "a=1\n");
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("cannot be used"));
+}
+
+#[test]
+fn sarif_columns_counted_in_code_points() {
+    let output = tombi(
+        &[
+            "lint",
+            "--schema-catalog-enabled",
+            "false",
+            "--output-format",
+            "sarif",
+            "-",
+        ],
+        "'' = 1\n",
+    );
+
+    let sarif = serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap();
+    assert_eq!(sarif["runs"][0]["columnKind"], "unicodeCodePoints");
+}
@@ -1,4 +1,5 @@
 pub mod codegen;
+pub mod codegen_diagnostics;
 pub mod codegen_grammar;
 pub mod codegen_jsonschema;
 pub mod dist;
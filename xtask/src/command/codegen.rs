@@ -4,4 +4,5 @@ pub enum CodeGenCommand {
     All,
     Grammar,
     Jsonschema,
+    Diagnostics,
 }
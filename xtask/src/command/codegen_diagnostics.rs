@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+use crate::utils::{project_root, PREAMBLE};
+
+pub fn run() -> Result<(), anyhow::Error> {
+    let mut content = format!(
+        "<!-- {PREAMBLE} -->\n\n\
+         # Diagnostics\n\n\
+         Each diagnostic of Tombi has a stable code, which is explained by `tombi explain <CODE>`.\n"
+    );
+
+    for code in linter::diagnostic_codes() {
+        write!(
+            content,
+            "\n## {} {}\n\n{}",
+            code.code(),
+            code.name(),
+            code.explanation()
+        )?;
+    }
+
+    std::fs::write(project_root().join("docs").join("diagnostics.md"), content)?;
+    Ok(())
+}